@@ -0,0 +1,197 @@
+mod field;
+
+use crate::context::collect_context;
+use crate::error::{new_type_errors_tokens, object_errors_tokens};
+use crate::rule::{collect_async_rules_from_named_struct, collect_async_rules_from_unnamed_struct};
+use crate::serde::rename::collect_serde_rename_map;
+use crate::types::{Field, NamedField, UnnamedField};
+use field::collect_field_async_validators;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+pub fn expand_async_validate_derive(
+    input: &syn::DeriveInput,
+) -> Result<TokenStream, crate::Errors> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    if collect_context(&input.attrs)?.is_some() {
+        return Err(vec![crate::Error::async_validate_context_not_support(
+            input,
+        )]);
+    }
+
+    let validations = match &input.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
+            syn::Fields::Named(fields) => {
+                let fields_validations = expand_named_fields(&input.attrs, fields)?;
+                let fields_idents = fields.named.iter().map(|field| &field.ident);
+                quote!(
+                    let Self { #(#fields_idents),* } = self;
+                    #fields_validations
+                )
+            }
+            syn::Fields::Unnamed(fields) => {
+                let fields_validations = expand_unnamed_fields(&input.attrs, fields)?;
+                let fields_idents = unnamed_fields_idents(fields);
+                quote!(
+                    let Self ( #(#fields_idents),* ) = self;
+                    #fields_validations
+                )
+            }
+            syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)])?,
+        },
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let mut errors = vec![];
+            let arms = TokenStream::from_iter(variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                match &variant.fields {
+                    syn::Fields::Named(fields) => {
+                        match expand_named_fields(&variant.attrs, fields) {
+                            Ok(fields_validations) => {
+                                let fields_idents = fields.named.iter().map(|field| &field.ident);
+                                quote!(
+                                    Self::#variant_ident { #(#fields_idents),* } => {
+                                        #fields_validations
+                                    }
+                                )
+                            }
+                            Err(variant_errors) => {
+                                errors.extend(variant_errors);
+                                quote!()
+                            }
+                        }
+                    }
+                    syn::Fields::Unnamed(fields) => {
+                        match expand_unnamed_fields(&variant.attrs, fields) {
+                            Ok(fields_validations) => {
+                                let fields_idents = unnamed_fields_idents(fields);
+                                quote!(
+                                    Self::#variant_ident ( #(#fields_idents),* ) => {
+                                        #fields_validations
+                                    }
+                                )
+                            }
+                            Err(variant_errors) => {
+                                errors.extend(variant_errors);
+                                quote!()
+                            }
+                        }
+                    }
+                    syn::Fields::Unit => quote!(Self::#variant_ident => {}),
+                }
+            }));
+
+            if !errors.is_empty() {
+                return Err(errors);
+            }
+
+            quote!(
+                match self {
+                    #arms
+                }
+            )
+        }
+        syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)])?,
+    };
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::AsyncValidate for #ident #type_generics #where_clause {
+            #[allow(unused_variables)]
+            async fn validate_async_validators(
+                &self,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                use ::serde_valid::AsyncValidate as _;
+
+                let __group = ::serde_valid::validation::Group::DEFAULT;
+                #validations
+
+                Ok(())
+            }
+        }
+    ))
+}
+
+fn expand_named_fields(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+) -> Result<TokenStream, crate::Errors> {
+    let rename_map = collect_serde_rename_map(fields);
+    let fields_validators = fields
+        .named
+        .iter()
+        .map(|field| collect_field_async_validators(&NamedField::new(field), &rename_map));
+
+    expand_fields(
+        fields_validators,
+        collect_async_rules_from_named_struct(attributes, fields, &rename_map),
+        object_errors_tokens(),
+    )
+}
+
+fn expand_unnamed_fields(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsUnnamed,
+) -> Result<TokenStream, crate::Errors> {
+    let fields_validators = fields.unnamed.iter().enumerate().map(|(index, field)| {
+        collect_field_async_validators(&UnnamedField::new(index, field), &HashMap::new())
+    });
+    let fields_errors = if fields.unnamed.len() != 1 {
+        object_errors_tokens()
+    } else {
+        new_type_errors_tokens()
+    };
+
+    expand_fields(
+        fields_validators,
+        collect_async_rules_from_unnamed_struct(attributes, fields),
+        fields_errors,
+    )
+}
+
+fn expand_fields(
+    fields_validators: impl Iterator<Item = Result<TokenStream, crate::Errors>>,
+    rules: Result<(std::collections::HashSet<syn::Ident>, TokenStream), crate::Errors>,
+    fields_errors: TokenStream,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+
+    let validates = TokenStream::from_iter(fields_validators.filter_map(|validators| {
+        validators
+            .map_err(|field_errors| errors.extend(field_errors))
+            .ok()
+    }));
+    let rules = match rules {
+        Ok((_, rules)) => rules,
+        Err(rule_errors) => {
+            errors.extend(rule_errors);
+            quote!()
+        }
+    };
+
+    if errors.is_empty() {
+        Ok(quote!(
+            let mut __errors = ::serde_valid::validation::VecErrors::new();
+            let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+
+            #validates
+            #rules
+
+            if !(__errors.is_empty() && __properties_errors.is_empty()) {
+                return Err(#fields_errors);
+            }
+        ))
+    } else {
+        Err(errors)
+    }
+}
+
+fn unnamed_fields_idents(fields: &syn::FieldsUnnamed) -> impl Iterator<Item = syn::Ident> + '_ {
+    fields
+        .unnamed
+        .iter()
+        .enumerate()
+        .map(|(index, field)| UnnamedField::new(index, field).ident().clone())
+}
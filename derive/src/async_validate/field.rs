@@ -0,0 +1,114 @@
+use crate::types::Field;
+use crate::validate::{
+    extract_custom_fn_name_from_list, extract_groups, extract_when, group_validator_tokens,
+    is_groups_meta, is_when_meta, parse_validate_meta, push_inner_errors_tokens,
+    when_validator_tokens, MetaListValidation,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::str::FromStr;
+use syn::parse_quote;
+
+/// Async validators of the field.
+///
+/// The other validators and the attribute errors are handled by `#[derive(Validate)]`.
+pub fn collect_field_async_validators(
+    field: &impl Field,
+    rename_map: &HashMap<String, String>,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+    let field_ident = field.ident();
+    let field_name = field.name();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+
+    let validators = TokenStream::from_iter(
+        field
+            .attrs()
+            .iter()
+            .filter(|attribute| attribute.path == parse_quote!(validate))
+            .filter_map(|attribute| match parse_validate_meta(attribute) {
+                Ok(syn::Meta::Path(_)) => {
+                    let push_inner_errors = push_inner_errors_tokens(rename);
+                    Some(quote!(
+                        if let Err(__inner_errors) = #field_ident.validate_async_validators().await {
+                            #push_inner_errors
+                        }
+                    ))
+                }
+                Ok(syn::Meta::List(syn::MetaList { nested, .. })) => {
+                    match extract_custom_async_validator(field_ident, rename, &nested) {
+                        Ok(validator) => validator,
+                        Err(validator_errors) => {
+                            errors.extend(validator_errors);
+                            None
+                        }
+                    }
+                }
+                _ => None,
+            }),
+    );
+
+    if errors.is_empty() {
+        Ok(validators)
+    } else {
+        Err(errors)
+    }
+}
+
+fn extract_custom_async_validator(
+    field_ident: &syn::Ident,
+    rename: &str,
+    nested: &syn::punctuated::Punctuated<syn::NestedMeta, syn::token::Comma>,
+) -> Result<Option<TokenStream>, crate::Errors> {
+    let (path, custom_nested) = match nested.first() {
+        Some(syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. })))
+            if path
+                .get_ident()
+                .map(|ident| {
+                    matches!(
+                        MetaListValidation::from_str(&ident.to_string()),
+                        Ok(MetaListValidation::CustomAsync)
+                    )
+                })
+                .unwrap_or(false) =>
+        {
+            (path, nested)
+        }
+        _ => return Ok(None),
+    };
+
+    let custom_fn_name = extract_custom_fn_name_from_list(path, custom_nested)?;
+    let groups = match nested
+        .iter()
+        .skip(1)
+        .find(|nested_meta| is_groups_meta(nested_meta))
+    {
+        Some(groups_meta) => Some(extract_groups(groups_meta)?),
+        None => None,
+    };
+    let when = match nested
+        .iter()
+        .skip(1)
+        .find(|nested_meta| is_when_meta(nested_meta))
+    {
+        Some(when_meta) => Some(extract_when(when_meta, None)?),
+        None => None,
+    };
+
+    Ok(Some(group_validator_tokens(
+        when_validator_tokens(
+            quote!(
+                if let Err(__error) = #custom_fn_name(#field_ident).await {
+                    __properties_errors
+                        .entry(#rename)
+                        .or_default()
+                        .push(__error);
+                };
+            ),
+            when.as_ref(),
+        ),
+        groups.as_deref(),
+    )))
+}
@@ -0,0 +1,41 @@
+use syn::parse::ParseStream;
+use syn::parse_quote;
+
+/// Collect the context type of `#[validate(context = MyCtx)]` from the container attributes.
+pub fn collect_context(attributes: &[syn::Attribute]) -> Result<Option<syn::Type>, crate::Errors> {
+    let mut errors = vec![];
+    let mut context = None;
+
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(validate))
+    {
+        match attribute.parse_args_with(parse_context) {
+            Ok(context_type) => {
+                if context.is_some() {
+                    errors.push(crate::Error::validate_context_duplicated(attribute));
+                }
+                context = Some(context_type);
+            }
+            Err(error) => errors.push(crate::Error::validate_context_parse_error(
+                attribute, &error,
+            )),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(context)
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_context(input: ParseStream) -> syn::Result<syn::Type> {
+    let name: syn::Ident = input.parse()?;
+    if name != "context" {
+        return Err(syn::Error::new(name.span(), "expected `context = ...`"));
+    }
+    input.parse::<syn::Token![=]>()?;
+
+    input.parse()
+}
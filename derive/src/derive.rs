@@ -2,21 +2,129 @@ mod enum_derive;
 mod named_struct_derive;
 mod unnamed_struct_derive;
 
+pub use enum_derive::Variants;
+
+use crate::context::collect_context;
 use enum_derive::expand_enum_validate_derive;
 use named_struct_derive::expand_named_struct_derive;
 use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
 use unnamed_struct_derive::expand_unnamed_struct_derive;
 
 pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let context = collect_context(&input.attrs)?;
+    let context = context.as_ref();
+
     match &input.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
-            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields),
-            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields),
+            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields, context),
+            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields, context),
             syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)]),
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            expand_enum_validate_derive(input, variants)
+            expand_enum_validate_derive(input, variants, context)
         }
         syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)]),
     }
 }
+
+/// Implement `ValidateWith<Ctx>` if the context is given,
+/// otherwise implement `Validate` and `ValidateWith<Ctx>` for any context ignoring it.
+///
+/// `validity` is the body of `Validate::is_valid_group`, which checks the value without the errors.
+fn expand_validate_impl(
+    input: &syn::DeriveInput,
+    context: Option<&syn::Type>,
+    validations: TokenStream,
+    validity: TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    match context {
+        Some(context) => quote!(
+            impl #impl_generics ::serde_valid::ValidateWith<#context> for #ident #type_generics #where_clause {
+                fn validate_with(
+                    &self,
+                    __context: &#context,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    ::serde_valid::ValidateWith::<#context>::validate_group_with(
+                        self,
+                        __context,
+                        ::serde_valid::validation::Group::DEFAULT,
+                    )
+                }
+
+                #[allow(unused_labels)]
+                fn validate_group_with(
+                    &self,
+                    __context: &#context,
+                    __group: ::serde_valid::validation::Group,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    let __options = ::serde_valid::validation::ValidateOptions::default();
+                    #validations
+                }
+            }
+        ),
+        None => {
+            let mut generics = input.generics.clone();
+            generics.params.push(parse_quote!(__Ctx: ?Sized));
+            let (context_impl_generics, _, _) = generics.split_for_impl();
+
+            quote!(
+                impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
+                    fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::validate_group(
+                            self,
+                            ::serde_valid::validation::Group::DEFAULT,
+                        )
+                    }
+
+                    fn validate_group(
+                        &self,
+                        __group: ::serde_valid::validation::Group,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::validate_group_with_options(
+                            self,
+                            __group,
+                            ::serde_valid::validation::ValidateOptions::default(),
+                        )
+                    }
+
+                    #[allow(unused_labels)]
+                    fn validate_group_with_options(
+                        &self,
+                        __group: ::serde_valid::validation::Group,
+                        __options: ::serde_valid::validation::ValidateOptions,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        #validations
+                    }
+
+                    #[allow(unused_labels)]
+                    fn is_valid_group(&self, __group: ::serde_valid::validation::Group) -> bool {
+                        let __options = ::serde_valid::validation::ValidateOptions::fail_fast();
+                        #validity
+                    }
+                }
+
+                impl #context_impl_generics ::serde_valid::ValidateWith<__Ctx> for #ident #type_generics #where_clause {
+                    fn validate_with(
+                        &self,
+                        _context: &__Ctx,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::validate(self)
+                    }
+
+                    fn validate_group_with(
+                        &self,
+                        _context: &__Ctx,
+                        group: ::serde_valid::validation::Group,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::validate_group(self, group)
+                    }
+                }
+            )
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use super::expand_validate_impl;
 use super::named_struct_derive::collect_named_fields_validators_list;
 use super::unnamed_struct_derive::collect_unnamed_fields_validators_list;
 use crate::error::{new_type_errors_tokens, object_errors_tokens};
-use crate::rule::{collect_rules_from_named_struct, collect_rules_from_unnamed_struct};
+use crate::rule::{
+    collect_rules_from_enum, collect_rules_from_named_struct, collect_rules_from_unnamed_struct,
+};
 use crate::serde::rename::collect_serde_rename_map;
 use crate::types::CommaSeparatedTokenStreams;
 use proc_macro2::TokenStream;
@@ -14,51 +17,69 @@ pub type Variants = syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>
 pub fn expand_enum_validate_derive(
     input: &syn::DeriveInput,
     variants: &Variants,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
 
     let mut errors = vec![];
 
-    let validations_and_rules =
-        TokenStream::from_iter(variants.iter().enumerate().map(|(index, variant)| {
-            match &variant.fields {
-                syn::Fields::Named(named_fields) => {
-                    match expand_enum_variant_named_fields(index, ident, variant, named_fields) {
-                        Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
-                        Err(variant_errors) => {
-                            errors.extend(variant_errors);
-                            quote!()
-                        }
+    let (validations_and_rules, validities): (TokenStream, TokenStream) = variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| match &variant.fields {
+            syn::Fields::Named(named_fields) => {
+                match expand_enum_variant_named_fields(index, ident, variant, named_fields, context)
+                {
+                    Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
+                    Err(variant_errors) => {
+                        errors.extend(variant_errors);
+                        (quote!(), quote!())
                     }
                 }
-                syn::Fields::Unnamed(unnamed_fields) => {
-                    match expand_enum_variant_unnamed_fields_varidation(
-                        index,
-                        ident,
-                        variant,
-                        unnamed_fields,
-                    ) {
-                        Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
-                        Err(variant_errors) => {
-                            errors.extend(variant_errors);
-                            quote!()
-                        }
+            }
+            syn::Fields::Unnamed(unnamed_fields) => {
+                match expand_enum_variant_unnamed_fields_varidation(
+                    index,
+                    ident,
+                    variant,
+                    unnamed_fields,
+                    context,
+                ) {
+                    Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
+                    Err(variant_errors) => {
+                        errors.extend(variant_errors);
+                        (quote!(), quote!())
                     }
                 }
-                syn::Fields::Unit => quote!(),
             }
-        }));
+            syn::Fields::Unit => (quote!(), quote!()),
+        })
+        .unzip();
+
+    let (enum_rules, enum_rules_validity) = match collect_rules_from_enum(&input.attrs, context) {
+        Ok(rules) => expand_enum_rules(rules),
+        Err(rule_errors) => {
+            errors.extend(rule_errors);
+            (quote!(), quote!())
+        }
+    };
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    #validations_and_rules
+        Ok(expand_validate_impl(
+            input,
+            context,
+            quote!(
+                #validations_and_rules
+                #enum_rules
 
-                    Ok(())
-                }
-            }
+                Ok(())
+            ),
+            quote!(
+                #validities
+                #enum_rules_validity
+
+                true
+            ),
         ))
     } else {
         Err(errors)
@@ -70,7 +91,8 @@ fn expand_enum_variant_named_fields(
     ident: &syn::Ident,
     variant: &syn::Variant,
     named_fields: &syn::FieldsNamed,
-) -> Result<TokenStream, crate::Errors> {
+    context: Option<&syn::Type>,
+) -> Result<(TokenStream, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
@@ -78,16 +100,27 @@ fn expand_enum_variant_named_fields(
     let else_token = make_else_token(index);
     let rename_map = collect_serde_rename_map(named_fields);
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs) {
-        Ok(field_rules) => field_rules,
-        Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
-            (HashSet::new(), quote!())
-        }
-    };
+    let (rule_fields, rules) =
+        match collect_rules_from_named_struct(&variant.attrs, named_fields, &rename_map, context) {
+            Ok(field_rules) => field_rules,
+            Err(variant_errors) => {
+                errors.extend(variant_errors.into_iter());
+                (HashSet::new(), quote!())
+            }
+        };
 
-    let validates = match collect_named_fields_validators_list(named_fields, &rename_map) {
+    let validates = match collect_named_fields_validators_list(named_fields, &rename_map, context) {
         Ok(field_validators_list) => {
+            errors.extend(
+                field_validators_list
+                    .iter()
+                    .flat_map(|validators| validators.check_contradictions()),
+            );
+            let field_references = field_validators_list
+                .iter()
+                .flat_map(|validators| validators.field_references())
+                .collect::<HashSet<_>>();
+
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
 
@@ -95,7 +128,7 @@ fn expand_enum_variant_named_fields(
                     fields_idents.push(quote!(#field_ident));
                     quote!(#token)
                 } else {
-                    if rule_fields.contains(field_ident) {
+                    if rule_fields.contains(field_ident) || field_references.contains(field_ident) {
                         fields_idents.push(quote!(#field_ident));
                     } else {
                         fields_idents.push(quote!(#field_ident: _));
@@ -113,18 +146,10 @@ fn expand_enum_variant_named_fields(
     let variant_errors = object_errors_tokens();
 
     if errors.is_empty() {
-        Ok(quote!(
-            #else_token if let #ident::#variant_ident{#fields_idents} = &self {
-                let mut __errors = ::serde_valid::validation::VecErrors::new();
-                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
-
-                #validates
-                #rules
-
-                if !(__errors.is_empty() && __properties_errors.is_empty()) {
-                    Err(#variant_errors)?
-                }
-            }
+        let (validation, validity) = expand_checks(quote!(#validates #rules), variant_errors);
+        Ok((
+            quote!(#else_token if let #ident::#variant_ident{#fields_idents} = &self { #validation }),
+            quote!(#else_token if let #ident::#variant_ident{#fields_idents} = &self { #validity }),
         ))
     } else {
         Err(errors)
@@ -136,23 +161,30 @@ fn expand_enum_variant_unnamed_fields_varidation(
     ident: &syn::Ident,
     variant: &syn::Variant,
     unnamed_fields: &syn::FieldsUnnamed,
-) -> Result<TokenStream, crate::Errors> {
+    context: Option<&syn::Type>,
+) -> Result<(TokenStream, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
     let else_token = make_else_token(index);
 
-    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&variant.attrs) {
-        Ok(field_rules) => field_rules,
-        Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
-            (HashSet::new(), quote!())
-        }
-    };
+    let (rule_fields, rules) =
+        match collect_rules_from_unnamed_struct(&variant.attrs, unnamed_fields, context) {
+            Ok(field_rules) => field_rules,
+            Err(variant_errors) => {
+                errors.extend(variant_errors.into_iter());
+                (HashSet::new(), quote!())
+            }
+        };
 
-    let validates = match collect_unnamed_fields_validators_list(unnamed_fields) {
+    let validates = match collect_unnamed_fields_validators_list(unnamed_fields, context) {
         Ok(field_validators_list) => {
+            errors.extend(
+                field_validators_list
+                    .iter()
+                    .flat_map(|validators| validators.check_contradictions()),
+            );
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
 
@@ -182,24 +214,56 @@ fn expand_enum_variant_unnamed_fields_varidation(
     };
 
     if errors.is_empty() {
-        Ok(quote!(
-            #else_token if let #ident::#variant_ident(#fields_idents) = &self {
-                let mut __errors = ::serde_valid::validation::VecErrors::new();
-                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
-
-                #validates
-                #rules
-
-                if !(__errors.is_empty() && __properties_errors.is_empty()) {
-                    Err(#variant_errors)?
-                }
-            }
+        let (validation, validity) = expand_checks(quote!(#validates #rules), variant_errors);
+        Ok((
+            quote!(#else_token if let #ident::#variant_ident(#fields_idents) = &self { #validation }),
+            quote!(#else_token if let #ident::#variant_ident(#fields_idents) = &self { #validity }),
         ))
     } else {
         Err(errors)
     }
 }
 
+/// The rules on the enum are checked after the validations of the variant pass.
+fn expand_enum_rules(rules: TokenStream) -> (TokenStream, TokenStream) {
+    if rules.is_empty() {
+        return (quote!(), quote!());
+    }
+
+    expand_checks(rules, object_errors_tokens())
+}
+
+/// Returns the checks of `validations`, which return `errors` from `validate`,
+/// and `false` from `is_valid` without creating the errors.
+fn expand_checks(validations: TokenStream, errors: TokenStream) -> (TokenStream, TokenStream) {
+    (
+        quote!(
+            let mut __errors = ::serde_valid::validation::VecErrors::new();
+            let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+
+            '__validation: {
+                #validations
+            }
+
+            if !(__errors.is_empty() && __properties_errors.is_empty()) {
+                Err(#errors)?
+            }
+        ),
+        quote!(
+            let mut __errors = ::serde_valid::validation::VecErrors::new();
+            let mut __properties_errors = ::serde_valid::validation::Validity::default();
+
+            '__validation: {
+                #validations
+            }
+
+            if !(__errors.is_empty() && __properties_errors.is_valid()) {
+                return false;
+            }
+        ),
+    )
+}
+
 fn make_else_token(index: usize) -> TokenStream {
     if index == 0 {
         quote!()
@@ -1,3 +1,4 @@
+use super::expand_validate_impl;
 use crate::error::object_errors_tokens;
 use crate::rule::collect_rules_from_named_struct;
 use crate::serde::rename::collect_serde_rename_map;
@@ -13,29 +14,36 @@ use syn::parse_quote;
 pub fn expand_named_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsNamed,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
     let rename_map = collect_serde_rename_map(fields);
 
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&input.attrs) {
-        Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
-        Err(rule_errors) => {
-            errors.extend(rule_errors);
-            (HashSet::new(), quote!())
-        }
-    };
-
-    let validates = match collect_named_fields_validators_list(fields, &rename_map) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            if validator.is_empty() && rule_fields.contains(validator.ident()) {
-                validator.get_field_variable_token()
-            } else {
-                validator.generate_tokens()
+    let (rule_fields, rules) =
+        match collect_rules_from_named_struct(&input.attrs, fields, &rename_map, context) {
+            Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
+            Err(rule_errors) => {
+                errors.extend(rule_errors);
+                (HashSet::new(), quote!())
             }
-        })),
+        };
+
+    let validates = match collect_named_fields_validators_list(fields, &rename_map, context) {
+        Ok(field_validators) => {
+            errors.extend(
+                field_validators
+                    .iter()
+                    .flat_map(|validator| validator.check_contradictions()),
+            );
+            TokenStream::from_iter(field_validators.iter().map(|validator| {
+                if validator.is_empty() && rule_fields.contains(validator.ident()) {
+                    validator.get_field_variable_token()
+                } else {
+                    validator.generate_tokens()
+                }
+            }))
+        }
         Err(validation_errors) => {
             errors.extend(validation_errors.into_iter());
             quote!()
@@ -45,22 +53,35 @@ pub fn expand_named_struct_derive(
     let fields_errors = object_errors_tokens();
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
-
+        Ok(expand_validate_impl(
+            input,
+            context,
+            quote!(
+                let mut __errors = ::serde_valid::validation::VecErrors::new();
+                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+
+                '__validation: {
                     #validates
                     #rules
+                }
 
-                    if __errors.is_empty() && __properties_errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                if __errors.is_empty() && __properties_errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
+            ),
+            quote!(
+                let mut __errors = ::serde_valid::validation::VecErrors::new();
+                let mut __properties_errors = ::serde_valid::validation::Validity::default();
+
+                '__validation: {
+                    #validates
+                    #rules
+                }
+
+                __errors.is_empty() && __properties_errors.is_valid()
+            ),
         ))
     } else {
         Err(errors)
@@ -70,6 +91,7 @@ pub fn expand_named_struct_derive(
 pub fn collect_named_fields_validators_list<'a>(
     fields: &'a syn::FieldsNamed,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Vec<FieldValidators<'a, NamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -77,7 +99,7 @@ pub fn collect_named_fields_validators_list<'a>(
         .named
         .iter()
         .filter_map(
-            |field| match collect_named_field_validators(field, rename_map) {
+            |field| match collect_named_field_validators(field, rename_map, context) {
                 Ok(validators) => Some(validators),
                 Err(ref mut error) => {
                     errors.append(error);
@@ -97,6 +119,7 @@ pub fn collect_named_fields_validators_list<'a>(
 fn collect_named_field_validators<'a>(
     field: &'a syn::Field,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<FieldValidators<'a, NamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -105,15 +128,15 @@ fn collect_named_field_validators<'a>(
         .attrs()
         .iter()
         .filter(|attribute| attribute.path == parse_quote!(validate))
-        .filter_map(
-            |attribute| match extract_meta_validator(&named_field, attribute, rename_map) {
+        .filter_map(|attribute| {
+            match extract_meta_validator(&named_field, attribute, rename_map, context) {
                 Ok(validator) => Some(validator),
                 Err(validator_error) => {
                     errors.extend(validator_error);
                     None
                 }
-            },
-        )
+            }
+        })
         .collect::<Vec<_>>();
 
     if !errors.is_empty() {
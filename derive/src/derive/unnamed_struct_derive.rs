@@ -1,3 +1,4 @@
+use super::expand_validate_impl;
 use crate::error::{new_type_errors_tokens, object_errors_tokens};
 use crate::rule::collect_rules_from_unnamed_struct;
 use crate::types::{Field, UnnamedField};
@@ -12,28 +13,34 @@ use syn::parse_quote;
 pub fn expand_unnamed_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&input.attrs) {
-        Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
-        Err(rule_errors) => {
-            errors.extend(rule_errors);
-            (HashSet::new(), quote!())
-        }
-    };
-
-    let validates = match collect_unnamed_fields_validators_list(fields) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            if validator.is_empty() && rule_fields.contains(validator.ident()) {
-                validator.get_field_variable_token()
-            } else {
-                validator.generate_tokens()
+    let (rule_fields, rules) =
+        match collect_rules_from_unnamed_struct(&input.attrs, fields, context) {
+            Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
+            Err(rule_errors) => {
+                errors.extend(rule_errors);
+                (HashSet::new(), quote!())
             }
-        })),
+        };
+
+    let validates = match collect_unnamed_fields_validators_list(fields, context) {
+        Ok(field_validators) => {
+            errors.extend(
+                field_validators
+                    .iter()
+                    .flat_map(|validator| validator.check_contradictions()),
+            );
+            TokenStream::from_iter(field_validators.iter().map(|validator| {
+                if validator.is_empty() && rule_fields.contains(validator.ident()) {
+                    validator.get_field_variable_token()
+                } else {
+                    validator.generate_tokens()
+                }
+            }))
+        }
         Err(validation_errors) => {
             errors.extend(validation_errors.into_iter());
             quote!()
@@ -47,22 +54,35 @@ pub fn expand_unnamed_struct_derive(
     };
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
-
+        Ok(expand_validate_impl(
+            input,
+            context,
+            quote!(
+                let mut __errors = ::serde_valid::validation::VecErrors::new();
+                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+
+                '__validation: {
                     #validates
                     #rules
+                }
 
-                    if __errors.is_empty() && __properties_errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                if __errors.is_empty() && __properties_errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
+            ),
+            quote!(
+                let mut __errors = ::serde_valid::validation::VecErrors::new();
+                let mut __properties_errors = ::serde_valid::validation::Validity::default();
+
+                '__validation: {
+                    #validates
+                    #rules
+                }
+
+                __errors.is_empty() && __properties_errors.is_valid()
+            ),
         ))
     } else {
         Err(errors)
@@ -71,6 +91,7 @@ pub fn expand_unnamed_struct_derive(
 
 pub fn collect_unnamed_fields_validators_list<'a>(
     fields: &'a syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
 ) -> Result<Vec<FieldValidators<'a, UnnamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -78,13 +99,15 @@ pub fn collect_unnamed_fields_validators_list<'a>(
         .unnamed
         .iter()
         .enumerate()
-        .filter_map(|field| match collect_unnamed_field_validators(field) {
-            Ok(validators) => Some(validators),
-            Err(ref mut error) => {
-                errors.append(error);
-                None
-            }
-        })
+        .filter_map(
+            |field| match collect_unnamed_field_validators(field, context) {
+                Ok(validators) => Some(validators),
+                Err(ref mut error) => {
+                    errors.append(error);
+                    None
+                }
+            },
+        )
         .collect();
 
     if !errors.is_empty() {
@@ -96,6 +119,7 @@ pub fn collect_unnamed_fields_validators_list<'a>(
 
 fn collect_unnamed_field_validators<'a>(
     (index, field): (usize, &'a syn::Field),
+    context: Option<&syn::Type>,
 ) -> Result<FieldValidators<'a, UnnamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -105,7 +129,7 @@ fn collect_unnamed_field_validators<'a>(
         .iter()
         .filter(|attribute| attribute.path == parse_quote!(validate))
         .filter_map(|attribute| {
-            match extract_meta_validator(&unnamed_field, attribute, &HashMap::new()) {
+            match extract_meta_validator(&unnamed_field, attribute, &HashMap::new(), context) {
                 Ok(validator) => Some(validator),
                 Err(validator_errors) => {
                     errors.extend(validator_errors);
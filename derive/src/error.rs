@@ -11,28 +11,59 @@ pub fn object_errors_tokens() -> TokenStream {
             __properties_errors
                 .into_iter()
                 .map(|(field, errors)| {
-                    let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
+                    let mut __field_items_errors: Option<::serde_valid::validation::ArrayErrors> =
+                        None;
+                    let mut __field_properties_errors: Option<
+                        ::serde_valid::validation::ObjectErrors,
+                    > = None;
+                    let mut __merge_errors = vec![];
                     let mut __field_errors: ::serde_valid::validation::VecErrors = errors
                         .into_iter()
                         .filter_map(|error| match error {
                             ::serde_valid::validation::Error::Items(__array_errors) => {
-                                __field_items_errors.push(__array_errors);
+                                match __field_items_errors.as_mut() {
+                                    Some(__items_errors) => {
+                                        if let Err(__merge_error) =
+                                            __items_errors.merge(__array_errors)
+                                        {
+                                            __merge_errors.push(__merge_error);
+                                        }
+                                    }
+                                    None => __field_items_errors = Some(__array_errors),
+                                }
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                // The entries of the map are validated by each attribute, so they are merged.
+                                match __field_properties_errors.as_mut() {
+                                    Some(__properties_errors) => {
+                                        if let Err(__merge_error) =
+                                            __properties_errors.merge(__object_errors)
+                                        {
+                                            __merge_errors.push(__merge_error);
+                                        }
+                                    }
+                                    None => __field_properties_errors = Some(__object_errors),
+                                }
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
+                    if __field_properties_errors.is_some() && __field_items_errors.is_some() {
+                        __merge_errors
+                            .push(::serde_valid::validation::MergeError::new(String::new()));
+                    }
+                    // The conflicted errors can not be merged, so they are reported as the field errors.
+                    __field_errors.extend(__merge_errors.into_iter().map(|__merge_error| {
+                        ::serde_valid::validation::Error::Custom(__merge_error.to_string())
+                    }));
 
                     if let Some(__object_errors) = __field_properties_errors {
                         __field_errors.extend(__object_errors.errors);
 
                         (
-                            field,
+                            field.into(),
                             ::serde_valid::validation::Errors::Object(
                                 ::serde_valid::validation::ObjectErrors::new(
                                     __field_errors,
@@ -40,15 +71,11 @@ pub fn object_errors_tokens() -> TokenStream {
                                 ),
                             ),
                         )
-                    } else if !__field_items_errors.is_empty() {
-                        let __array_errors = __field_items_errors
-                            .into_iter()
-                            .reduce(|a, b| a.merge(b))
-                            .unwrap();
+                    } else if let Some(__array_errors) = __field_items_errors {
                         __field_errors.extend(__array_errors.errors);
 
                         (
-                            field,
+                            field.into(),
                             ::serde_valid::validation::Errors::Array(
                                 ::serde_valid::validation::ArrayErrors::new(
                                     __field_errors,
@@ -58,7 +85,7 @@ pub fn object_errors_tokens() -> TokenStream {
                         )
                     } else {
                         (
-                            field,
+                            field.into(),
                             ::serde_valid::validation::Errors::NewType(__field_errors),
                         )
                     }
@@ -93,6 +120,55 @@ impl Error {
         Self::new(input.span(), "#[derive(Validate)] does not support Union.")
     }
 
+    pub fn async_validate_context_not_support(input: &syn::DeriveInput) -> Self {
+        Self::new(
+            input.span(),
+            "#[derive(AsyncValidate)] does not support #[validate(context = ???)].",
+        )
+    }
+
+    pub fn json_schema_union_not_support(input: &syn::DeriveInput) -> Self {
+        Self::new(
+            input.span(),
+            "#[derive(JsonSchema)] does not support Union.",
+        )
+    }
+
+    pub fn json_schema_flatten_not_support(field: &syn::Field) -> Self {
+        Self::new(
+            field.span(),
+            "#[derive(JsonSchema)] does not support #[serde(flatten)].",
+        )
+    }
+
+    pub fn validate_impossible_range(lit: &syn::Lit, lower: &str, upper: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!("Impossible range: no value satisfies both `{lower}` and `{upper}`."),
+        )
+    }
+
+    pub fn validate_multiple_of_zero(lit: &syn::Lit) -> Self {
+        Self::new(lit.span(), "`multiple_of` must not be zero.")
+    }
+
+    pub fn validate_negative_size(lit: &syn::Lit, size: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!("`{size}` is negative, but the size must be non-negative."),
+        )
+    }
+
+    pub fn validate_enumerate_out_of_range(lit: &syn::Lit, limit: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "Enumerate value `{}` never satisfies `{limit}`.",
+                quote!(#lit)
+            ),
+        )
+    }
+
     pub fn rule_need_function(path: &syn::Path) -> Self {
         Self::new(path.span(), "#[rule(???)] needs rule_fn.")
     }
@@ -112,7 +188,7 @@ impl Error {
         let rule_fn_name = quote!(#rule_fn_name_path).to_string();
         Self::new(
             meta.span(),
-            format!("#[rule({rule_fn_name}(???, ...))] allow field path only."),
+            format!("#[rule({rule_fn_name}(???, ...))] allow field path or `self` only."),
         )
     }
 
@@ -123,7 +199,47 @@ impl Error {
         let rule_fn_name = quote!(#rule_fn_name_path).to_string();
         Self::new(
             meta.span(),
-            format!("#[rule({rule_fn_name}(???, ...))] allow index integer only."),
+            format!("#[rule({rule_fn_name}(???, ...))] allow index integer or `self` only."),
+        )
+    }
+
+    pub fn rule_allow_self_argument(rule_fn_name_path: &syn::Path, meta: &syn::NestedMeta) -> Self {
+        let rule_fn_name = quote!(#rule_fn_name_path).to_string();
+        Self::new(
+            meta.span(),
+            format!("#[rule({rule_fn_name}(???))] on the enum allow `self` only."),
+        )
+    }
+
+    pub fn rule_path_need_field(lit: &syn::Lit) -> Self {
+        Self::new(
+            lit.span(),
+            "#[rule(..., path = ???)] needs the field of the struct, like `path = \"field\"`.",
+        )
+    }
+
+    pub fn rule_duplicate_path(name_value: &syn::MetaNameValue) -> Self {
+        Self::new(name_value.span(), "#[rule] allow single `path`.")
+    }
+
+    pub fn rule_path_not_support(name_value: &syn::MetaNameValue, target: &str) -> Self {
+        Self::new(
+            name_value.span(),
+            format!("#[rule(..., path = ???)] does not support {target}."),
+        )
+    }
+
+    pub fn rule_dependent_required_need_fields(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`dependent_required` needs a field and its dependent fields.",
+        )
+    }
+
+    pub fn rule_dependent_required_named_fields_only(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`dependent_required` supports only the named fields.",
         )
     }
 
@@ -134,6 +250,20 @@ impl Error {
         Self::new(attribute.span(), format!("#[rule] parse error: {error}"))
     }
 
+    pub fn validate_context_parse_error(attribute: &syn::Attribute, error: &syn::Error) -> Self {
+        Self::new(
+            attribute.span(),
+            format!("#[validate(context = ???)] parse error: {error}"),
+        )
+    }
+
+    pub fn validate_context_duplicated(attribute: &syn::Attribute) -> Self {
+        Self::new(
+            attribute.span(),
+            "#[validate(context = ???)] is duplicated.",
+        )
+    }
+
     pub fn validate_meta_literal_not_support(lit: &syn::Lit) -> Self {
         Self::new(lit.span(), "#[validate(???)] does not support literal.")
     }
@@ -186,10 +316,119 @@ impl Error {
         )
     }
 
+    pub fn validate_unknown_format(lit: &syn::LitStr, candidates: &[&str]) -> Self {
+        let unknown = lit.value();
+        let filterd_candidates = did_you_mean(&unknown, candidates).unwrap_or(candidates.to_vec());
+
+        Self::new(
+            lit.span(),
+            format!(
+                "Unknown format: `{unknown}`. Is it one of the following?\n{filterd_candidates:#?}"
+            ),
+        )
+    }
+
     pub fn validate_enumerate_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), format!("`enumerate` need items."))
     }
 
+    pub fn validate_groups_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`groups` need items.")
+    }
+
+    pub fn validate_groups_allow_ident_only(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(nested_meta.span(), "`groups` allow group names only.")
+    }
+
+    pub fn validate_when_parse_error(lit: &syn::LitStr, error: &syn::Error) -> Self {
+        Self::new(lit.span(), format!("`when` parse error: {error}"))
+    }
+
+    pub fn validate_when_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`when` need items.")
+    }
+
+    pub fn validate_when_allow_function_only(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "`when` allow a predicate function only.",
+        )
+    }
+
+    pub fn validate_when_tail_error(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(nested_meta.span(), "`when` support only 1 item.")
+    }
+
+    pub fn validate_composition_need_item(path: &syn::Path, composition: &str) -> Self {
+        Self::new(path.span(), format!("`{composition}` need items."))
+    }
+
+    pub fn validate_entries_need_item(path: &syn::Path, entries: &str) -> Self {
+        Self::new(path.span(), format!("`{entries}` need items."))
+    }
+
+    pub fn validate_entries_duplicated(path: &syn::Path, entries: &str) -> Self {
+        Self::new(path.span(), format!("`{entries}` is duplicated."))
+    }
+
+    pub fn validate_pattern_properties_item_error(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "#[validate(pattern_properties(???))] items must be `\"pattern\" = validation`.",
+        )
+    }
+
+    pub fn validate_allowed_keys_allow_str_only(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "#[validate(allowed_keys(???))] allow only string literals.",
+        )
+    }
+
+    pub fn validate_allowed_keys_need_additional_properties(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`allowed_keys` needs `additional_properties = false`.",
+        )
+    }
+
+    pub fn validate_additional_properties_allow_bool_only(lit: &syn::Lit) -> Self {
+        Self::new(
+            lit.span(),
+            "#[validate(additional_properties = ???)] allow only `true` or `false`.",
+        )
+    }
+
+    pub fn validate_contains_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`contains` need items.")
+    }
+
+    pub fn validate_contains_duplicated(path: &syn::Path, contains: &str) -> Self {
+        Self::new(path.span(), format!("`{contains}` is duplicated."))
+    }
+
+    pub fn validate_contains_need_contains(path: &syn::Path, contains: &str) -> Self {
+        Self::new(path.span(), format!("`{contains}` needs `contains(...)`."))
+    }
+
+    pub fn validate_prefix_items_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`prefix_items` need items.")
+    }
+
+    pub fn validate_prefix_items_item_error(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "#[validate(prefix_items(???))] items must be `index(validation, ...)`.",
+        )
+    }
+
+    pub fn validate_prefix_items_duplicated(index: &syn::LitInt) -> Self {
+        Self::new(
+            index.span(),
+            format!("`prefix_items` index `{index}` is duplicated."),
+        )
+    }
+
     pub fn validate_custom_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), format!("`custom` need items."))
     }
@@ -198,6 +437,37 @@ impl Error {
         Self::new(nested.span(), format!("`custom` support only 1 item."))
     }
 
+    pub fn message_template_unknown_placeholder(
+        lit: &syn::LitStr,
+        placeholder: &str,
+        candidates: &[&str],
+    ) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "Unknown placeholder: `{{{placeholder}}}`. Is it one of the following?\n{:#?}",
+                candidates
+                    .iter()
+                    .map(|candidate| format!("{{{candidate}}}"))
+                    .collect::<Vec<_>>()
+            ),
+        )
+    }
+
+    pub fn message_template_unclosed_brace(lit: &syn::LitStr) -> Self {
+        Self::new(
+            lit.span(),
+            "the message template has unclosed `{`. Use `{{` to write `{`.",
+        )
+    }
+
+    pub fn message_template_unmatched_brace(lit: &syn::LitStr) -> Self {
+        Self::new(
+            lit.span(),
+            "the message template has unmatched `}`. Use `}}` to write `}`.",
+        )
+    }
+
     pub fn message_fn_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), format!("`message_fn` need items."))
     }
@@ -228,6 +498,14 @@ impl Error {
         Self::new(lit.span(), "Allow str literal only.")
     }
 
+    pub fn field_name_only(lit: &syn::Lit) -> Self {
+        Self::new(lit.span(), "Allow field name only.")
+    }
+
+    pub fn regex_syntax_error(lit: &syn::LitStr, error: &regex::Error) -> Self {
+        Self::new(lit.span(), format!("Invalid regex pattern.\n{error}"))
+    }
+
     pub fn literal_not_support(lit: &syn::Lit) -> Self {
         Self::new(lit.span(), "Literal does not support.")
     }
@@ -0,0 +1,39 @@
+mod enum_json_schema;
+mod field;
+mod named_struct_json_schema;
+mod unnamed_struct_json_schema;
+
+use enum_json_schema::expand_enum_json_schema_derive;
+use named_struct_json_schema::expand_named_struct_json_schema_derive;
+use proc_macro2::TokenStream;
+use quote::quote;
+use unnamed_struct_json_schema::expand_unnamed_struct_json_schema_derive;
+
+pub fn expand_json_schema_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    match &input.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
+            syn::Fields::Named(fields) => expand_named_struct_json_schema_derive(input, fields),
+            syn::Fields::Unnamed(fields) => {
+                Ok(expand_unnamed_struct_json_schema_derive(input, fields))
+            }
+            syn::Fields::Unit => Ok(expand_unit_struct_json_schema_derive(input)),
+        },
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            expand_enum_json_schema_derive(input, variants)
+        }
+        syn::Data::Union(_) => Err(vec![crate::Error::json_schema_union_not_support(input)]),
+    }
+}
+
+fn expand_unit_struct_json_schema_derive(input: &syn::DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::JsonSchema for #ident #type_generics #where_clause {
+            fn json_schema() -> ::serde_valid::json::Value {
+                ::serde_valid::json::json!({ "type": "null" })
+            }
+        }
+    )
+}
@@ -0,0 +1,158 @@
+use super::named_struct_json_schema::named_fields_schema_tokens;
+use super::unnamed_struct_json_schema::unnamed_fields_schema_tokens;
+use crate::derive::Variants;
+use crate::serde::case::RenameRule;
+use crate::serde::flag::has_serde_flag;
+use crate::serde::rename::find_serde_rename;
+use crate::serde::tag::TagType;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Schema of the enum in the representation of serde,
+/// which is selected by `#[serde(tag = "...", content = "...")]` and `#[serde(untagged)]`.
+pub fn expand_enum_json_schema_derive(
+    input: &syn::DeriveInput,
+    variants: &Variants,
+) -> Result<TokenStream, crate::Errors> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let tag_type = TagType::from_attributes(&input.attrs);
+    let rename_all = RenameRule::find(&input.attrs, "rename_all");
+    let rename_all_fields = RenameRule::find(&input.attrs, "rename_all_fields");
+
+    let variants = variants
+        .iter()
+        .filter(|variant| {
+            !(has_serde_flag(&variant.attrs, "skip")
+                || has_serde_flag(&variant.attrs, "skip_deserializing"))
+        })
+        .collect::<Vec<_>>();
+
+    let schema = if matches!(tag_type, TagType::External)
+        && variants
+            .iter()
+            .all(|variant| matches!(variant.fields, syn::Fields::Unit))
+    {
+        let names = variants
+            .iter()
+            .map(|variant| variant_name(variant, rename_all));
+        quote!(::serde_valid::json::json!({ "enum": [#(#names),*] }))
+    } else {
+        let mut errors = vec![];
+        let variant_schemas = variants
+            .iter()
+            .filter_map(|variant| {
+                match variant_schema_tokens(variant, &tag_type, rename_all, rename_all_fields) {
+                    Ok(variant_schema) => Some(variant_schema),
+                    Err(variant_errors) => {
+                        errors.extend(variant_errors);
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // Untagged variants may overlap, and serde takes the first one matched.
+        let keyword = if matches!(tag_type, TagType::None) {
+            "anyOf"
+        } else {
+            "oneOf"
+        };
+        quote!({
+            let __variants = vec![#(#variant_schemas),*];
+
+            ::serde_valid::json::json!({ #keyword: __variants })
+        })
+    };
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::JsonSchema for #ident #type_generics #where_clause {
+            fn json_schema() -> ::serde_valid::json::Value {
+                #schema
+            }
+        }
+    ))
+}
+
+fn variant_schema_tokens(
+    variant: &syn::Variant,
+    tag_type: &TagType,
+    rename_all: Option<RenameRule>,
+    rename_all_fields: Option<RenameRule>,
+) -> Result<TokenStream, crate::Errors> {
+    let name = variant_name(variant, rename_all);
+    let inner_schema = match &variant.fields {
+        syn::Fields::Named(named_fields) => Some(named_fields_schema_tokens(
+            &variant.attrs,
+            named_fields,
+            has_serde_flag(&variant.attrs, "default"),
+            RenameRule::find(&variant.attrs, "rename_all").or(rename_all_fields),
+        )?),
+        syn::Fields::Unnamed(unnamed_fields) => Some(unnamed_fields_schema_tokens(unnamed_fields)),
+        syn::Fields::Unit => None,
+    };
+
+    let schema = match (tag_type, inner_schema) {
+        (TagType::External, None) => quote!(::serde_valid::json::json!({ "const": #name })),
+        (TagType::External, Some(inner_schema)) => quote!({
+            let mut __properties = ::serde_valid::json::Map::new();
+            __properties.insert(#name.to_owned(), #inner_schema);
+
+            ::serde_valid::json::json!({
+                "type": "object",
+                "properties": __properties,
+                "required": [#name],
+                "additionalProperties": false,
+            })
+        }),
+        (TagType::Internal { tag }, None) | (TagType::Adjacent { tag, .. }, None) => {
+            tag_schema_tokens(tag, &name)
+        }
+        (TagType::Internal { tag }, Some(inner_schema)) => {
+            let tag_schema = tag_schema_tokens(tag, &name);
+            quote!({
+                let __all_of = vec![#tag_schema, #inner_schema];
+
+                ::serde_valid::json::json!({ "allOf": __all_of })
+            })
+        }
+        (TagType::Adjacent { tag, content }, Some(inner_schema)) => quote!({
+            let mut __properties = ::serde_valid::json::Map::new();
+            __properties.insert(#tag.to_owned(), ::serde_valid::json::json!({ "const": #name }));
+            __properties.insert(#content.to_owned(), #inner_schema);
+
+            ::serde_valid::json::json!({
+                "type": "object",
+                "properties": __properties,
+                "required": [#tag, #content],
+                "additionalProperties": false,
+            })
+        }),
+        (TagType::None, None) => quote!(::serde_valid::json::json!({ "type": "null" })),
+        (TagType::None, Some(inner_schema)) => inner_schema,
+    };
+
+    Ok(schema)
+}
+
+/// The object which has only the tag of the variant.
+fn tag_schema_tokens(tag: &str, name: &str) -> TokenStream {
+    quote!(::serde_valid::json::json!({
+        "type": "object",
+        "properties": { #tag: { "const": #name } },
+        "required": [#tag],
+    }))
+}
+
+fn variant_name(variant: &syn::Variant, rename_all: Option<RenameRule>) -> String {
+    find_serde_rename(&variant.attrs).unwrap_or_else(|| {
+        let name = variant.ident.to_string();
+        match rename_all {
+            Some(rename_all) => rename_all.apply_to_variant(&name),
+            None => name,
+        }
+    })
+}
@@ -0,0 +1,292 @@
+use crate::types::Field;
+use crate::validate::{
+    extract_pattern_property, extract_prefix_item, get_lit_or_expr, is_contains_meta,
+    is_entries_meta, is_groups_meta, is_when_meta, parse_validate_meta, MetaListValidation,
+    MetaNameValueValidation, MetaPathValidation,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::iter::FromIterator;
+use std::str::FromStr;
+use syn::parse_quote;
+
+/// Schema of the field type, with the keywords of `#[validate(...)]`.
+///
+/// The validations with `groups(...)` or `when` are not always applied, so they are ignored.
+pub fn field_schema_tokens(field: &impl Field) -> TokenStream {
+    let ty = field.ty();
+    let schema = quote!(<#ty as ::serde_valid::JsonSchema>::json_schema());
+    let keywords = TokenStream::from_iter(
+        field
+            .attrs()
+            .iter()
+            .filter(|attribute| attribute.path == parse_quote!(validate))
+            .filter_map(|attribute| match parse_validate_meta(attribute) {
+                Ok(syn::Meta::List(list))
+                    if !list.nested.iter().any(|nested_meta| {
+                        is_groups_meta(nested_meta) || is_when_meta(nested_meta)
+                    }) =>
+                {
+                    if list.nested.iter().any(is_entries_meta) {
+                        Some(TokenStream::from_iter(list.nested.iter().filter_map(
+                            |nested_meta| entries_keywords_tokens(nested_meta, ty),
+                        )))
+                    } else if list.nested.iter().any(is_contains_meta) {
+                        Some(TokenStream::from_iter(
+                            list.nested
+                                .iter()
+                                .filter(|nested_meta| is_contains_meta(nested_meta))
+                                .filter_map(|nested_meta| keyword_tokens(nested_meta, &schema)),
+                        ))
+                    } else {
+                        list.nested
+                            .first()
+                            .and_then(|nested_meta| keyword_tokens(nested_meta, &schema))
+                    }
+                }
+                _ => None,
+            }),
+    );
+
+    quote!({
+        #[allow(unused_mut)]
+        let mut __schema = <#ty as ::serde_valid::JsonSchema>::json_schema();
+        #keywords
+        __schema
+    })
+}
+
+/// Keyword insertion of the validation.
+///
+/// Invalid validations are ignored here, because `#[derive(Validate)]` reports them.
+fn keyword_tokens(nested_meta: &syn::NestedMeta, schema: &TokenStream) -> Option<TokenStream> {
+    let (keyword, value) = keyword_value_tokens(nested_meta, schema)?;
+
+    Some(quote!(
+        ::serde_valid::json_schema::insert_keyword(&mut __schema, #keyword, #value);
+    ))
+}
+
+/// Keyword insertions of the map entries validations.
+///
+/// `keys(...)` is inserted into `propertyNames`, `values(...)` into `additionalProperties`,
+/// `pattern_properties(...)` into `patternProperties`, `allowed_keys(...)` into `properties`,
+/// and `additional_properties = false` into `additionalProperties`.
+fn entries_keywords_tokens(nested_meta: &syn::NestedMeta, ty: &syn::Type) -> Option<TokenStream> {
+    let values_schema = quote!(::serde_valid::json_schema::additional_properties_schema(
+        &<#ty as ::serde_valid::JsonSchema>::json_schema()
+    ));
+    let (path, nested) = match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. })) => {
+            (path, nested)
+        }
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            lit: syn::Lit::Bool(syn::LitBool { value: false, .. }),
+            ..
+        })) if path.is_ident(MetaNameValueValidation::AdditionalProperties.name()) => {
+            return Some(quote!(
+                ::serde_valid::json_schema::insert_keyword(
+                    &mut __schema,
+                    "additionalProperties",
+                    ::serde_valid::json::json!(false),
+                );
+            ));
+        }
+        _ => None?,
+    };
+
+    let keywords_tokens = |insert_keyword: TokenStream, schema: TokenStream| {
+        TokenStream::from_iter(nested.iter().filter_map(|nested_meta| {
+            let (keyword, value) = keyword_value_tokens(nested_meta, &schema)?;
+            Some(quote!(
+                ::serde_valid::json_schema::#insert_keyword(&mut __schema, #keyword, #value);
+            ))
+        }))
+    };
+
+    Some(
+        match MetaListValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+            MetaListValidation::Keys => keywords_tokens(
+                quote!(insert_property_names_keyword),
+                quote!(::serde_valid::json::json!({ "type": "string" })),
+            ),
+            MetaListValidation::Values => {
+                keywords_tokens(quote!(insert_additional_properties_keyword), values_schema)
+            }
+            MetaListValidation::PatternProperties => {
+                TokenStream::from_iter(nested.iter().filter_map(|nested_meta| {
+                    let (pattern, validations) = extract_pattern_property(nested_meta).ok()?;
+                    let keywords = validations
+                        .into_iter()
+                        .filter_map(|validation| keyword_value_tokens(validation, &values_schema))
+                        .map(|(keyword, value)| quote!((#keyword, #value)));
+                    Some(quote!(
+                        ::serde_valid::json_schema::insert_pattern_property(
+                            &mut __schema,
+                            #pattern,
+                            ::serde_valid::json_schema::composition_schema(
+                                &#values_schema,
+                                vec![#(#keywords),*],
+                            ),
+                        );
+                    ))
+                }))
+            }
+            MetaListValidation::AllowedKeys => {
+                TokenStream::from_iter(nested.iter().filter_map(|nested_meta| match nested_meta {
+                    syn::NestedMeta::Lit(syn::Lit::Str(key)) => Some(quote!(
+                        ::serde_valid::json_schema::insert_property(
+                            &mut __schema,
+                            #key,
+                            #values_schema,
+                        );
+                    )),
+                    _ => None,
+                }))
+            }
+            _ => None?,
+        },
+    )
+}
+
+/// Keyword of the validation, whose composition schemas are based on the `schema`.
+fn keyword_value_tokens(
+    nested_meta: &syn::NestedMeta,
+    schema: &TokenStream,
+) -> Option<(&'static str, TokenStream)> {
+    Some(match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. })) => {
+            let keyword =
+                match MetaNameValueValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                    MetaNameValueValidation::Minimum => "minimum",
+                    MetaNameValueValidation::Maximum => "maximum",
+                    MetaNameValueValidation::ExclusiveMinimum => "exclusiveMinimum",
+                    MetaNameValueValidation::ExclusiveMaximum => "exclusiveMaximum",
+                    MetaNameValueValidation::MinLength => "minLength",
+                    MetaNameValueValidation::MaxLength => "maxLength",
+                    MetaNameValueValidation::MinItems => "minItems",
+                    MetaNameValueValidation::MaxItems => "maxItems",
+                    MetaNameValueValidation::MinProperties => "minProperties",
+                    MetaNameValueValidation::MaxProperties => "maxProperties",
+                    MetaNameValueValidation::MultipleOf => "multipleOf",
+                    MetaNameValueValidation::Pattern => "pattern",
+                    MetaNameValueValidation::Format => "format",
+                    MetaNameValueValidation::ConstValue => "const",
+                    MetaNameValueValidation::MinContains => "minContains",
+                    MetaNameValueValidation::MaxContains => "maxContains",
+                    MetaNameValueValidation::AdditionalProperties
+                    | MetaNameValueValidation::MinimumField
+                    | MetaNameValueValidation::MaximumField
+                    | MetaNameValueValidation::ExclusiveMinimumField
+                    | MetaNameValueValidation::ExclusiveMaximumField
+                    | MetaNameValueValidation::EqField => None?,
+                };
+            let value = get_lit_or_expr(lit);
+            (keyword, quote!(::serde_valid::json::json!(#value)))
+        }
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+            match MetaPathValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                MetaPathValidation::UniqueItems => {
+                    ("uniqueItems", quote!(::serde_valid::json::json!(true)))
+                }
+            }
+        }
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. })) => {
+            match MetaListValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                MetaListValidation::Enumerate => {
+                    let candidates = nested.iter().filter_map(|item| match item {
+                        syn::NestedMeta::Lit(lit) => Some(get_lit_or_expr(lit)),
+                        syn::NestedMeta::Meta(_) => None,
+                    });
+                    (
+                        "enum",
+                        quote!(::serde_valid::json::json!([#(#candidates),*])),
+                    )
+                }
+                MetaListValidation::AllOf => ("allOf", subschemas_tokens(nested, schema)?),
+                MetaListValidation::AnyOf => ("anyOf", subschemas_tokens(nested, schema)?),
+                MetaListValidation::OneOf => ("oneOf", subschemas_tokens(nested, schema)?),
+                MetaListValidation::Not => ("not", subschema_tokens(nested.iter(), schema)?),
+                MetaListValidation::PrefixItems => {
+                    let prefix_items = nested.iter().filter_map(|nested_meta| {
+                        let (index, validations) = extract_prefix_item(nested_meta).ok()?;
+                        let index = index.base10_parse::<usize>().ok()?;
+                        let keywords = validations
+                            .into_iter()
+                            .filter_map(|validation| {
+                                keyword_value_tokens(
+                                    validation,
+                                    &quote!(::serde_valid::json_schema::prefix_item_schema(
+                                        &#schema, #index
+                                    )),
+                                )
+                            })
+                            .map(|(keyword, value)| quote!((#keyword, #value)));
+                        Some(quote!((
+                            #index,
+                            ::serde_valid::json_schema::composition_schema(
+                                &::serde_valid::json_schema::prefix_item_schema(&#schema, #index),
+                                vec![#(#keywords),*],
+                            )
+                        )))
+                    });
+                    (
+                        "prefixItems",
+                        quote!(::serde_valid::json_schema::prefix_items_schema(
+                            &#schema,
+                            vec![#(#prefix_items),*],
+                        )),
+                    )
+                }
+                MetaListValidation::Contains => (
+                    "contains",
+                    subschema_tokens(
+                        nested.iter(),
+                        &quote!(::serde_valid::json_schema::items_schema(&#schema)),
+                    )?,
+                ),
+                MetaListValidation::Custom
+                | MetaListValidation::CustomAsync
+                | MetaListValidation::Keys
+                | MetaListValidation::Values
+                | MetaListValidation::PatternProperties
+                | MetaListValidation::AllowedKeys => None?,
+            }
+        }
+        syn::NestedMeta::Lit(_) => None?,
+    })
+}
+
+/// Schemas of each validation.
+///
+/// The composition is ignored if it has a validation which has no keyword.
+fn subschemas_tokens(
+    nested: &syn::punctuated::Punctuated<syn::NestedMeta, syn::token::Comma>,
+    schema: &TokenStream,
+) -> Option<TokenStream> {
+    let subschemas = nested
+        .iter()
+        .map(|nested_meta| subschema_tokens(std::iter::once(nested_meta), schema))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(quote!(::serde_valid::json::json!([#(#subschemas),*])))
+}
+
+/// Schema of the value with all the validations.
+fn subschema_tokens<'a>(
+    nested_metas: impl Iterator<Item = &'a syn::NestedMeta>,
+    schema: &TokenStream,
+) -> Option<TokenStream> {
+    let keywords = nested_metas
+        .map(|nested_meta| {
+            keyword_value_tokens(nested_meta, schema)
+                .map(|(keyword, value)| quote!((#keyword, #value)))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(quote!(::serde_valid::json_schema::composition_schema(
+        &#schema,
+        vec![#(#keywords),*],
+    )))
+}
@@ -0,0 +1,99 @@
+use super::field::field_schema_tokens;
+use crate::rule::dependent_required_schema_tokens;
+use crate::serde::case::RenameRule;
+use crate::serde::flag::has_serde_flag;
+use crate::serde::rename::collect_serde_rename_map;
+use crate::types::{Field, NamedField};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn expand_named_struct_json_schema_derive(
+    input: &syn::DeriveInput,
+    fields: &syn::FieldsNamed,
+) -> Result<TokenStream, crate::Errors> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let schema = named_fields_schema_tokens(
+        &input.attrs,
+        fields,
+        has_serde_flag(&input.attrs, "default"),
+        RenameRule::find(&input.attrs, "rename_all"),
+    )?;
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::JsonSchema for #ident #type_generics #where_clause {
+            fn json_schema() -> ::serde_valid::json::Value {
+                #schema
+            }
+        }
+    ))
+}
+
+pub fn named_fields_schema_tokens(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+    container_default: bool,
+    rename_rule: Option<RenameRule>,
+) -> Result<TokenStream, crate::Errors> {
+    let mut rename_map = collect_serde_rename_map(fields);
+    if let Some(rename_rule) = rename_rule {
+        for field in fields.named.iter() {
+            let field_name = NamedField::new(field).name().to_owned();
+            let rename = rename_rule.apply_to_field(&field_name);
+            rename_map.entry(field_name).or_insert(rename);
+        }
+    }
+
+    let mut errors = vec![];
+    let mut properties = vec![];
+    let mut required = vec![];
+    for field in fields.named.iter() {
+        let named_field = NamedField::new(field);
+        let attrs = named_field.attrs();
+        if has_serde_flag(attrs, "skip") || has_serde_flag(attrs, "skip_deserializing") {
+            continue;
+        }
+        if has_serde_flag(attrs, "flatten") {
+            errors.push(crate::Error::json_schema_flatten_not_support(field));
+            continue;
+        }
+
+        let field_name = named_field.name();
+        let rename = rename_map.get(field_name).unwrap_or(field_name);
+        let field_schema = field_schema_tokens(&named_field);
+        properties.push(quote!(
+            __properties.insert(#rename.to_owned(), #field_schema);
+        ));
+
+        if !(container_default
+            || has_serde_flag(attrs, "default")
+            || named_field.option_field().is_some())
+        {
+            required.push(rename.to_owned());
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let required = if required.is_empty() {
+        quote!()
+    } else {
+        quote!("required": [#(#required),*],)
+    };
+    let dependent_required =
+        dependent_required_schema_tokens(attributes, &rename_map).unwrap_or_default();
+
+    Ok(quote!({
+        let mut __properties = ::serde_valid::json::Map::new();
+        #(#properties)*
+
+        ::serde_valid::json::json!({
+            "type": "object",
+            "properties": __properties,
+            #required
+            #dependent_required
+        })
+    }))
+}
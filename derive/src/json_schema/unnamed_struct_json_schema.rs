@@ -0,0 +1,48 @@
+use super::field::field_schema_tokens;
+use crate::types::UnnamedField;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn expand_unnamed_struct_json_schema_derive(
+    input: &syn::DeriveInput,
+    fields: &syn::FieldsUnnamed,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let schema = unnamed_fields_schema_tokens(fields);
+
+    quote!(
+        impl #impl_generics ::serde_valid::JsonSchema for #ident #type_generics #where_clause {
+            fn json_schema() -> ::serde_valid::json::Value {
+                #schema
+            }
+        }
+    )
+}
+
+/// New type is the schema of the inner field, and the others are the tuple schema.
+pub fn unnamed_fields_schema_tokens(fields: &syn::FieldsUnnamed) -> TokenStream {
+    let mut field_schemas = fields
+        .unnamed
+        .iter()
+        .enumerate()
+        .map(|(index, field)| field_schema_tokens(&UnnamedField::new(index, field)))
+        .collect::<Vec<_>>();
+
+    if field_schemas.len() == 1 {
+        return field_schemas.remove(0);
+    }
+
+    let len = field_schemas.len();
+    quote!({
+        let __prefix_items = vec![#(#field_schemas),*];
+
+        ::serde_valid::json::json!({
+            "type": "array",
+            "prefixItems": __prefix_items,
+            "items": false,
+            "minItems": #len,
+            "maxItems": #len,
+        })
+    })
+}
@@ -1,18 +1,23 @@
+mod async_validate;
+mod context;
 mod derive;
 mod error;
+mod json_schema;
 mod rule;
 mod serde;
 mod types;
 mod validate;
 
+use async_validate::expand_async_validate_derive;
 use derive::expand_derive;
 use error::to_compile_errors;
 use error::{Error, Errors};
+use json_schema::expand_json_schema_derive;
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(Validate, attributes(rule, validate))]
+#[proc_macro_derive(Validate, attributes(rule, rule_async, validate))]
 #[proc_macro_error]
 pub fn derive_validate(tokens: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
@@ -21,3 +26,23 @@ pub fn derive_validate(tokens: TokenStream) -> TokenStream {
         .unwrap_or_else(to_compile_errors)
         .into()
 }
+
+#[proc_macro_derive(AsyncValidate, attributes(rule_async, validate))]
+#[proc_macro_error]
+pub fn derive_async_validate(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_async_validate_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(JsonSchema, attributes(validate))]
+#[proc_macro_error]
+pub fn derive_json_schema(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_json_schema_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
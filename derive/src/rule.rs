@@ -1,5 +1,113 @@
+mod dependent_required;
+mod enum_rule;
 mod named_struct_rule;
 mod unnamed_struct_rule;
 
-pub use named_struct_rule::collect_rules_from_named_struct;
-pub use unnamed_struct_rule::collect_rules_from_unnamed_struct;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::types::CommaSeparatedNestedMetas;
+use crate::validate::group_validator_tokens;
+
+pub use dependent_required::dependent_required_schema_tokens;
+pub use enum_rule::collect_rules_from_enum;
+pub use named_struct_rule::{
+    collect_async_rules_from_named_struct, collect_rules_from_named_struct,
+};
+pub use unnamed_struct_rule::{
+    collect_async_rules_from_unnamed_struct, collect_rules_from_unnamed_struct,
+};
+
+const PATH: &str = "path";
+
+/// Kind of the rule attribute.
+#[derive(Debug, Clone, Copy)]
+enum RuleKind {
+    /// `#[rule(...)]`
+    Sync,
+    /// `#[rule_async(...)]`
+    Async,
+}
+
+impl RuleKind {
+    fn name(&self) -> &'static str {
+        match self {
+            RuleKind::Sync => "rule",
+            RuleKind::Async => "rule_async",
+        }
+    }
+}
+
+/// Check the nested meta is `path = "..."` of the rule.
+fn is_path_option(nested_meta: &syn::NestedMeta) -> bool {
+    matches!(
+        nested_meta,
+        syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) if name_value.path.is_ident(PATH)
+    )
+}
+
+/// Split `#[rule(rule_fn(...), path = "field")]` into the rule functions and the path.
+fn split_rule_path(
+    nested: &CommaSeparatedNestedMetas,
+) -> (
+    Vec<&syn::NestedMeta>,
+    Result<Option<&syn::MetaNameValue>, crate::Errors>,
+) {
+    let functions = nested
+        .iter()
+        .filter(|nested_meta| !is_path_option(nested_meta))
+        .collect();
+
+    let mut paths = nested.iter().filter_map(|nested_meta| match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+            if name_value.path.is_ident(PATH) =>
+        {
+            Some(name_value)
+        }
+        _ => None,
+    });
+    let path = paths.next();
+    let errors = paths
+        .map(crate::Error::rule_duplicate_path)
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        (functions, Ok(path))
+    } else {
+        (functions, Err(errors))
+    }
+}
+
+/// `self` argument of the rule function, which passes the whole value.
+fn is_self_argument(nested_meta: &syn::NestedMeta) -> bool {
+    matches!(
+        nested_meta,
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("self")
+    )
+}
+
+/// Push the error of the rule function to the target field, or the value itself.
+fn rule_error_tokens(rule_fn_call: TokenStream, target: Option<&str>) -> TokenStream {
+    let push_error = match target {
+        Some(target) => quote!(
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #target,
+                || __error,
+            );
+        ),
+        None => quote!(
+            __errors.push(__error);
+        ),
+    };
+
+    // Rules belong to the default group.
+    group_validator_tokens(
+        quote!(
+            if let Err(__error) = #rule_fn_call {
+                #push_error
+            };
+        ),
+        None,
+    )
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::types::SingleIdentPath;
+use crate::validate::group_validator_tokens;
+
+const DEPENDENT_REQUIRED: &str = "dependent_required";
+
+/// Check the rule is `dependent_required(...)`.
+pub fn is_dependent_required(syn::MetaList { path, .. }: &syn::MetaList) -> bool {
+    path.is_ident(DEPENDENT_REQUIRED)
+}
+
+/// `dependent_required(field, dependent_fields...)` requires the dependent `Option` fields
+/// when the `Option` field is `Some`, like `dependentRequired` of JSON Schema.
+pub fn extract_dependent_required(
+    list: &syn::MetaList,
+    rename_map: &HashMap<String, String>,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    let (field, dependent_fields) = dependent_required_fields(list)?;
+
+    let field_ident = &field;
+    let requires = dependent_fields.iter().map(|dependent_ident| {
+        let dependent_name = dependent_ident.to_string();
+        let rename = rename_map.get(&dependent_name).unwrap_or(&dependent_name);
+        quote!(
+            if #dependent_ident.is_none() {
+                ::serde_valid::validation::PropertiesErrors::push_error(
+                    &mut __properties_errors,
+                    #rename,
+                    || {
+                        ::serde_valid::validation::Error::Required(
+                            ::serde_valid::error::Message::with_default_message(
+                                ::serde_valid::RequiredErrorParams {},
+                            ),
+                        )
+                    },
+                );
+            }
+        )
+    });
+
+    let arg_idents = std::iter::once(field.clone())
+        .chain(dependent_fields.iter().cloned())
+        .collect();
+
+    Ok((
+        arg_idents,
+        // Rules belong to the default group.
+        group_validator_tokens(
+            quote!(
+                if #field_ident.is_some() {
+                    #(#requires)*
+                }
+            ),
+            None,
+        ),
+    ))
+}
+
+/// `"dependentRequired"` keyword of the JSON Schema.
+///
+/// Invalid rules are ignored here, because `#[derive(Validate)]` reports them.
+pub fn dependent_required_schema_tokens(
+    attributes: &[syn::Attribute],
+    rename_map: &HashMap<String, String>,
+) -> Option<TokenStream> {
+    let rename = |ident: &syn::Ident| {
+        let name = ident.to_string();
+        rename_map.get(&name).cloned().unwrap_or(name)
+    };
+
+    let dependencies = attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("rule"))
+        .filter_map(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(syn::MetaList { nested, .. })) => match nested.first() {
+                Some(syn::NestedMeta::Meta(syn::Meta::List(list)))
+                    if is_dependent_required(list) =>
+                {
+                    dependent_required_fields(list).ok()
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|(field, dependent_fields)| {
+            let field = rename(&field);
+            let dependent_fields = dependent_fields.iter().map(rename);
+            quote!(#field: [#(#dependent_fields),*])
+        })
+        .collect::<Vec<_>>();
+
+    if dependencies.is_empty() {
+        None
+    } else {
+        Some(quote!("dependentRequired": { #(#dependencies),* },))
+    }
+}
+
+fn dependent_required_fields(
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+) -> Result<(syn::Ident, Vec<syn::Ident>), crate::Errors> {
+    let mut errors = vec![];
+
+    if nested.len() < 2 {
+        errors.push(crate::Error::rule_dependent_required_need_fields(path));
+    }
+
+    let mut fields = nested
+        .iter()
+        .filter_map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::Path(field)) => {
+                Some(SingleIdentPath::new(field).ident().clone())
+            }
+            _ => {
+                errors.push(crate::Error::rule_allow_path_arguments(path, nested_meta));
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        let field = fields.remove(0);
+        Ok((field, fields))
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{is_self_argument, rule_error_tokens, split_rule_path, RuleKind};
+use crate::types::CommaSeparatedTokenStreams;
+
+/// Collect `#[rule(rule_fn(self))]` on the enum, which checks the whole value.
+///
+/// The fields are different in each variant, so the rule function takes only `self`.
+pub fn collect_rules_from_enum(
+    attributes: &[syn::Attribute],
+    context: Option<&syn::Type>,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+
+    let rules = attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident(RuleKind::Sync.name()))
+        .filter_map(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(list)) => match collect_rule(&list, context) {
+                Ok(stream) => Some(stream),
+                Err(rule_errors) => {
+                    errors.extend(rule_errors);
+                    None
+                }
+            },
+            Ok(_) => {
+                errors.push(crate::Error::rule_need_function(&attribute.path));
+                None
+            }
+            Err(error) => {
+                errors.push(crate::Error::rule_validate_attribute_parse_error(
+                    attribute, &error,
+                ));
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(TokenStream::from_iter(rules))
+    } else {
+        Err(errors)
+    }
+}
+
+fn collect_rule(
+    syn::MetaList {
+        path, ref nested, ..
+    }: &syn::MetaList,
+    context: Option<&syn::Type>,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+
+    let (functions, rule_path) = split_rule_path(nested);
+    match rule_path {
+        Ok(Some(name_value)) => {
+            errors.push(crate::Error::rule_path_not_support(name_value, "the enum"))
+        }
+        Ok(None) => {}
+        Err(path_errors) => errors.extend(path_errors),
+    }
+
+    match functions.len() {
+        0 => Err(vec![crate::Error::rule_need_function(path)])?,
+        2.. => functions.iter().skip(1).for_each(|nested_meta| {
+            errors.push(crate::Error::rule_allow_single_function(nested_meta))
+        }),
+        _ => {}
+    }
+
+    let rule = match functions[0] {
+        syn::NestedMeta::Meta(meta) => match meta {
+            syn::Meta::List(list) => extract_rule_from_meta_list(list, context),
+            syn::Meta::NameValue(name_value) => {
+                Err(vec![crate::Error::meta_name_value_not_support(name_value)])
+            }
+            syn::Meta::Path(path) => Err(vec![crate::Error::meta_path_not_support(path)]),
+        },
+        syn::NestedMeta::Lit(lit) => Err(vec![crate::Error::literal_not_support(lit)]),
+    };
+
+    match rule {
+        Ok(_) => {
+            if errors.is_empty() {
+                rule
+            } else {
+                Err(errors)
+            }
+        }
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
+    }
+}
+
+fn extract_rule_from_meta_list(
+    syn::MetaList {
+        path: rule_fn_name,
+        ref nested,
+        ..
+    }: &syn::MetaList,
+    context: Option<&syn::Type>,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+
+    if nested.is_empty() {
+        errors.push(crate::Error::rule_need_arguments(rule_fn_name));
+    }
+
+    let mut rule_fn_args = nested
+        .iter()
+        .filter_map(|nested_meta| {
+            if is_self_argument(nested_meta) {
+                Some(quote!(self))
+            } else {
+                errors.push(crate::Error::rule_allow_self_argument(
+                    rule_fn_name,
+                    nested_meta,
+                ));
+                None
+            }
+        })
+        .collect::<CommaSeparatedTokenStreams>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if context.is_some() {
+        rule_fn_args.push(quote!(__context));
+    }
+
+    Ok(rule_error_tokens(
+        quote!(#rule_fn_name(#rule_fn_args)),
+        None,
+    ))
+}
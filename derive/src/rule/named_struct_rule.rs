@@ -1,32 +1,56 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::parse_quote;
 use syn::spanned::Spanned;
 
+use super::dependent_required::{extract_dependent_required, is_dependent_required};
+use super::{is_self_argument, rule_error_tokens, split_rule_path, RuleKind};
 use crate::types::CommaSeparatedTokenStreams;
 
 pub fn collect_rules_from_named_struct(
     attributes: &Vec<syn::Attribute>,
+    fields: &syn::FieldsNamed,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, RuleKind::Sync, fields, rename_map, context)
+}
+
+pub fn collect_async_rules_from_named_struct(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+    rename_map: &HashMap<String, String>,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, RuleKind::Async, fields, rename_map, None)
+}
+
+fn collect_rules(
+    attributes: &[syn::Attribute],
+    kind: RuleKind,
+    fields: &syn::FieldsNamed,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
     let mut rule_fields = HashSet::new();
     let rules = attributes
         .iter()
-        .filter(|attribute| attribute.path == parse_quote!(rule))
+        .filter(|attribute| attribute.path.is_ident(kind.name()))
         .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
-                Ok((field_ident, stream)) => {
-                    rule_fields.extend(field_ident);
-                    Some(stream)
-                }
-                Err(rule_errors) => {
-                    errors.extend(rule_errors);
-                    None
+            Ok(syn::Meta::List(list)) => {
+                match collect_rule(&list, kind, fields, rename_map, context) {
+                    Ok((field_ident, stream)) => {
+                        rule_fields.extend(field_ident);
+                        Some(stream)
+                    }
+                    Err(rule_errors) => {
+                        errors.extend(rule_errors);
+                        None
+                    }
                 }
-            },
+            }
             Ok(_) => {
                 errors.push(crate::Error::rule_need_function(&attribute.path));
                 None
@@ -51,20 +75,44 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    kind: RuleKind,
+    fields: &syn::FieldsNamed,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
-    match nested.len() {
+    let (functions, rule_path) = split_rule_path(nested);
+    let rule_path = rule_path.unwrap_or_else(|path_errors| {
+        errors.extend(path_errors);
+        None
+    });
+
+    match functions.len() {
         0 => Err(vec![crate::Error::rule_need_function(path)])?,
-        2.. => nested.iter().skip(1).for_each(|nested_meta| {
+        2.. => functions.iter().skip(1).for_each(|nested_meta| {
             errors.push(crate::Error::rule_allow_single_function(nested_meta))
         }),
         _ => {}
     }
 
-    let rule = match &nested[0] {
+    let rule = match functions[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(&list),
+            syn::Meta::List(list)
+                if matches!(kind, RuleKind::Sync) && is_dependent_required(list) =>
+            {
+                match rule_path {
+                    Some(name_value) => Err(vec![crate::Error::rule_path_not_support(
+                        name_value,
+                        "`dependent_required`",
+                    )]),
+                    None => extract_dependent_required(list, rename_map),
+                }
+            }
+            syn::Meta::List(list) => extract_rule_path_target(rule_path, fields, rename_map)
+                .and_then(|target| {
+                    extract_rule_from_meta_list(list, kind, target.as_deref(), context)
+                }),
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(&name_value)])
             }
@@ -85,12 +133,44 @@ fn collect_rule(
     }
 }
 
+/// The serde name of the field given by `path = "field"`.
+fn extract_rule_path_target(
+    rule_path: Option<&syn::MetaNameValue>,
+    fields: &syn::FieldsNamed,
+    rename_map: &HashMap<String, String>,
+) -> Result<Option<String>, crate::Errors> {
+    let lit = match rule_path {
+        Some(name_value) => &name_value.lit,
+        None => return Ok(None),
+    };
+
+    match lit {
+        syn::Lit::Str(field_name)
+            if fields.named.iter().any(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| *ident == field_name.value())
+            }) =>
+        {
+            let field_name = field_name.value();
+            Ok(Some(
+                rename_map.get(&field_name).cloned().unwrap_or(field_name),
+            ))
+        }
+        _ => Err(vec![crate::Error::rule_path_need_field(lit)]),
+    }
+}
+
 fn extract_rule_from_meta_list(
     syn::MetaList {
         path: rule_fn_name,
         ref nested,
         ..
     }: &syn::MetaList,
+    kind: RuleKind,
+    target: Option<&str>,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -99,10 +179,13 @@ fn extract_rule_from_meta_list(
     }
 
     let mut arg_idents = HashSet::new();
-    let rule_fn_args = nested
+    let mut rule_fn_args = nested
         .iter()
         .filter_map(|nested_meta| {
             let arg = match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if is_self_argument(nested_meta) => {
+                    Some(quote!(#path))
+                }
                 syn::NestedMeta::Meta(meta) => match meta {
                     syn::Meta::Path(path) => {
                         arg_idents.insert(syn::Ident::new(
@@ -129,12 +212,13 @@ fn extract_rule_from_meta_list(
         return Err(errors);
     }
 
-    Ok((
-        arg_idents,
-        quote!(
-            if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __errors.push(__error);
-            };
-        ),
-    ))
+    if context.is_some() {
+        rule_fn_args.push(quote!(__context));
+    }
+    let rule_fn_call = match kind {
+        RuleKind::Sync => quote!(#rule_fn_name(#rule_fn_args)),
+        RuleKind::Async => quote!(#rule_fn_name(#rule_fn_args).await),
+    };
+
+    Ok((arg_idents, rule_error_tokens(rule_fn_call, target)))
 }
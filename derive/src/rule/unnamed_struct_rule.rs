@@ -2,21 +2,40 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::parse_quote;
 
+use super::dependent_required::is_dependent_required;
+use super::{is_self_argument, rule_error_tokens, split_rule_path, RuleKind};
 use crate::types::CommaSeparatedTokenStreams;
 
 pub fn collect_rules_from_unnamed_struct(
     attributes: &Vec<syn::Attribute>,
+    fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, RuleKind::Sync, fields, context)
+}
+
+pub fn collect_async_rules_from_unnamed_struct(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsUnnamed,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, RuleKind::Async, fields, None)
+}
+
+fn collect_rules(
+    attributes: &[syn::Attribute],
+    kind: RuleKind,
+    fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
     let mut rule_fields = HashSet::new();
     let rules = attributes
         .iter()
-        .filter(|attribute| attribute.path == parse_quote!(rule))
+        .filter(|attribute| attribute.path.is_ident(kind.name()))
         .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
+            Ok(syn::Meta::List(list)) => match collect_rule(&list, kind, fields, context) {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
                     Some(stream)
@@ -50,20 +69,40 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    kind: RuleKind,
+    fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
-    match nested.len() {
+    let (functions, rule_path) = split_rule_path(nested);
+    let rule_path = rule_path.unwrap_or_else(|path_errors| {
+        errors.extend(path_errors);
+        None
+    });
+
+    match functions.len() {
         0 => Err(vec![crate::Error::rule_need_function(path)])?,
-        2.. => nested.iter().skip(1).for_each(|nested_meta| {
+        2.. => functions.iter().skip(1).for_each(|nested_meta| {
             errors.push(crate::Error::rule_allow_single_function(nested_meta))
         }),
         _ => {}
     }
 
-    let rule = match &nested[0] {
+    let rule = match functions[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(&list),
+            syn::Meta::List(list)
+                if matches!(kind, RuleKind::Sync) && is_dependent_required(list) =>
+            {
+                Err(vec![
+                    crate::Error::rule_dependent_required_named_fields_only(&list.path),
+                ])
+            }
+            syn::Meta::List(list) => {
+                extract_rule_path_target(rule_path, fields).and_then(|target| {
+                    extract_rule_from_meta_list(list, kind, target.as_deref(), context)
+                })
+            }
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(&name_value)])
             }
@@ -84,12 +123,34 @@ fn collect_rule(
     }
 }
 
+/// The index of the field given by `path = "0"`.
+fn extract_rule_path_target(
+    rule_path: Option<&syn::MetaNameValue>,
+    fields: &syn::FieldsUnnamed,
+) -> Result<Option<String>, crate::Errors> {
+    let lit = match rule_path {
+        Some(name_value) => &name_value.lit,
+        None => return Ok(None),
+    };
+
+    match lit {
+        syn::Lit::Str(index) => match index.value().parse::<usize>() {
+            Ok(index) if index < fields.unnamed.len() => Ok(Some(index.to_string())),
+            _ => Err(vec![crate::Error::rule_path_need_field(lit)]),
+        },
+        _ => Err(vec![crate::Error::rule_path_need_field(lit)]),
+    }
+}
+
 fn extract_rule_from_meta_list(
     syn::MetaList {
         path: rule_fn_name,
         ref nested,
         ..
     }: &syn::MetaList,
+    kind: RuleKind,
+    target: Option<&str>,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -98,7 +159,7 @@ fn extract_rule_from_meta_list(
     }
 
     let mut arg_idents = HashSet::new();
-    let rule_fn_args = nested
+    let mut rule_fn_args = nested
         .iter()
         .filter_map(|nested_meta| {
             let arg = match nested_meta {
@@ -110,6 +171,9 @@ fn extract_rule_from_meta_list(
                     }
                     _ => None,
                 },
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) if is_self_argument(nested_meta) => {
+                    Some(quote!(#path))
+                }
                 syn::NestedMeta::Meta(_) => None,
             };
             if arg.is_none() {
@@ -126,13 +190,13 @@ fn extract_rule_from_meta_list(
         return Err(errors);
     }
 
-    Ok((
-        arg_idents,
-        quote!(
-            if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __errors
-                    .push(__error);
-            };
-        ),
-    ))
+    if context.is_some() {
+        rule_fn_args.push(quote!(__context));
+    }
+    let rule_fn_call = match kind {
+        RuleKind::Sync => quote!(#rule_fn_name(#rule_fn_args)),
+        RuleKind::Async => quote!(#rule_fn_name(#rule_fn_args).await),
+    };
+
+    Ok((arg_idents, rule_error_tokens(rule_fn_call, target)))
 }
@@ -1 +1,4 @@
+pub mod case;
+pub mod flag;
 pub mod rename;
+pub mod tag;
@@ -0,0 +1,17 @@
+use syn::parse_quote;
+
+/// Whether `#[serde(...)]` attributes have the flag, like `#[serde(skip)]` or `#[serde(default = "...")]`.
+pub fn has_serde_flag(attributes: &[syn::Attribute], flag: &str) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde))
+        .filter_map(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(serde_list)) => Some(serde_list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|serde_nested_meta| match serde_nested_meta {
+            syn::NestedMeta::Meta(serde_meta) => serde_meta.path().is_ident(flag),
+            syn::NestedMeta::Lit(_) => false,
+        })
+}
@@ -8,21 +8,32 @@ pub fn collect_serde_rename_map(fields: &syn::FieldsNamed) -> HashMap<String, St
     let mut renames = HashMap::new();
     for field in fields.named.iter() {
         let named_field = NamedField::new(field);
-        for attribute in named_field.attrs() {
-            if attribute.path == parse_quote!(serde) {
-                if let Some(rename) = find_rename_from_serde_attributes(attribute) {
-                    renames.insert(field.ident.to_token_stream().to_string(), rename);
-                }
-            }
+        if let Some(rename) = find_serde_rename(named_field.attrs()) {
+            renames.insert(field.ident.to_token_stream().to_string(), rename);
         }
     }
     renames
 }
 
-fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<String> {
+pub fn find_serde_rename(attributes: &[syn::Attribute]) -> Option<String> {
+    find_serde_name_value(attributes, "rename")
+}
+
+/// Find the string of `#[serde(name = "...")]` or `#[serde(name(deserialize = "..."))]`.
+pub fn find_serde_name_value(attributes: &[syn::Attribute], name: &str) -> Option<String> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde))
+        .find_map(|attribute| find_name_value_from_serde_attributes(attribute, name))
+}
+
+fn find_name_value_from_serde_attributes(attribute: &syn::Attribute, name: &str) -> Option<String> {
     if let Ok(syn::Meta::List(serde_list)) = attribute.parse_meta() {
         for serde_nested_meta in serde_list.nested {
             if let syn::NestedMeta::Meta(serde_meta) = &serde_nested_meta {
+                if !serde_meta.path().is_ident(name) {
+                    continue;
+                }
                 if let Some(rename) = find_rename_from_serde_rename_attributes(serde_meta) {
                     return Some(rename);
                 }
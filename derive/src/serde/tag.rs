@@ -0,0 +1,30 @@
+use super::flag::has_serde_flag;
+use super::rename::find_serde_name_value;
+
+/// The enum representation of serde, selected by `#[serde(tag, content, untagged)]`.
+pub enum TagType {
+    /// `{"Variant": {...}}`, which is the default.
+    External,
+    /// `{"tag": "Variant", ...}` by `#[serde(tag = "tag")]`.
+    Internal { tag: String },
+    /// `{"tag": "Variant", "content": {...}}` by `#[serde(tag = "tag", content = "content")]`.
+    Adjacent { tag: String, content: String },
+    /// `{...}` by `#[serde(untagged)]`.
+    None,
+}
+
+impl TagType {
+    pub fn from_attributes(attributes: &[syn::Attribute]) -> Self {
+        if has_serde_flag(attributes, "untagged") {
+            return Self::None;
+        }
+        match (
+            find_serde_name_value(attributes, "tag"),
+            find_serde_name_value(attributes, "content"),
+        ) {
+            (Some(tag), Some(content)) => Self::Adjacent { tag, content },
+            (Some(tag), None) => Self::Internal { tag },
+            _ => Self::External,
+        }
+    }
+}
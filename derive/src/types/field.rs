@@ -1,8 +1,10 @@
 mod array;
+mod entry;
 mod named;
 mod option;
 mod unnamed;
 
+pub use entry::EntryField;
 pub use named::NamedField;
 pub use unnamed::UnnamedField;
 
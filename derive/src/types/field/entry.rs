@@ -0,0 +1,61 @@
+use super::Field;
+use quote::quote;
+
+/// Key or value of the map field, for `#[validate(keys(...), values(...))]`,
+/// or item of the array field, for `#[validate(contains(...))]`.
+///
+/// The validators of the entry push their errors into the entry of the field name.
+pub struct EntryField {
+    name: String,
+    ident: syn::Ident,
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ty: syn::Type,
+}
+
+impl EntryField {
+    pub fn new(field: &impl Field, ident: &str) -> Self {
+        Self {
+            name: field.name().to_owned(),
+            ident: syn::Ident::new(ident, field.ident().span()),
+            attrs: field.attrs().to_owned(),
+            vis: field.vis().to_owned(),
+            ty: field.ty().to_owned(),
+        }
+    }
+}
+
+impl Field for EntryField {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn getter_token(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        quote!(#ident)
+    }
+
+    fn attrs(&self) -> &Vec<syn::Attribute> {
+        &self.attrs
+    }
+
+    fn vis(&self) -> &syn::Visibility {
+        &self.vis
+    }
+
+    fn ty(&self) -> &syn::Type {
+        &self.ty
+    }
+
+    fn array_field(&self) -> Option<Self> {
+        None
+    }
+
+    fn option_field(&self) -> Option<Self> {
+        None
+    }
+}
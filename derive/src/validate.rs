@@ -7,5 +7,14 @@ mod numeric;
 mod object;
 mod string;
 
+pub use common::{
+    extract_groups, extract_when, get_lit_or_expr, group_validator_tokens, is_groups_meta,
+    is_when_meta, when_validator_tokens, MetaListValidation, MetaNameValueValidation,
+    MetaPathValidation,
+};
 pub use field::{FieldValidators, Validator};
-pub use meta::extract_meta_validator;
+pub use generic::extract_custom_fn_name_from_list;
+pub use meta::{
+    extract_meta_validator, extract_pattern_property, extract_prefix_item, is_contains_meta,
+    is_entries_meta, parse_validate_meta, push_inner_errors_tokens,
+};
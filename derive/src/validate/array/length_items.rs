@@ -1,5 +1,5 @@
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::{get_numeric, message_tokens};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -30,24 +30,18 @@ macro_rules! extract_array_length_validator{
                 let field_ident = field.ident();
                 let rename = rename_map.get(field_name).unwrap_or(field_name);
                 let [<$ErrorType:snake>] = get_numeric(validation_value)?;
-                let message =
-                    message_fn.unwrap_or(quote!(::serde_valid::[<$ErrorType ErrorParams>]::to_default_message));
+                let message = message_tokens(quote!(error_params), message_fn);
 
                 Ok(quote!(
                     if let Err(error_params) = ::serde_valid::[<Validate $ErrorType>]::[<validate_ $ErrorType:snake>](
                         #field_ident,
                         #[<$ErrorType:snake>],
                     ) {
-                        use ::serde_valid::error::ToDefaultMessage;
-                        __properties_errors
-                            .entry(#rename)
-                            .or_default()
-                            .push(::serde_valid::validation::Error::$ErrorType(
-                                ::serde_valid::error::Message::new(
-                                    error_params,
-                                    #message
-                                )
-                            ));
+                        ::serde_valid::validation::PropertiesErrors::push_error(
+                            &mut __properties_errors,
+                            #rename,
+                            || ::serde_valid::validation::Error::$ErrorType(#message),
+                        );
                     }
                 ))
             }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::{types::Field, validate::Validator};
+use crate::types::Field;
+use crate::validate::common::message_tokens;
+use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -20,24 +22,17 @@ fn inner_extract_array_unique_items_validator(
     let field_name = field.name();
     let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(field_name);
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::UniqueItemsErrorParams::to_default_message
-    ));
+    let message = message_tokens(quote!(error_params), message_fn);
 
     quote!(
         if let Err(error_params) = ::serde_valid::ValidateUniqueItems::validate_unique_items(
             #field_ident
         ) {
-            use ::serde_valid::error::ToDefaultMessage;
-            __properties_errors
-                .entry(#rename)
-                .or_default()
-                .push(::serde_valid::validation::Error::UniqueItems(
-                    ::serde_valid::error::Message::new(
-                        error_params,
-                        #message
-                    )
-                ));
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || ::serde_valid::validation::Error::UniqueItems(#message),
+            );
         }
     )
 }
@@ -1,8 +1,22 @@
+mod contradiction;
+mod field_reference;
+mod group;
 mod lit;
 mod message;
-
-pub use lit::{get_numeric, get_str};
-pub use message::extract_message_fn_tokens;
+mod message_key;
+mod message_template;
+mod when;
+
+pub use contradiction::is_allow_contradiction_meta;
+pub use field_reference::{collect_field_references, get_field_reference};
+pub use group::{extract_groups, group_validator_tokens, is_groups_meta};
+pub use lit::{
+    expr_literal, get_expr, get_expr_ident, get_lit_or_expr, get_numeric, get_regex_str, get_str,
+};
+pub use message::{extract_message_fn_tokens, into_error_tokens, message_tokens};
+pub use message_key::{extract_message_key, is_message_key_meta, message_key_validator_tokens};
+pub use message_template::MessageTemplateParams;
+pub use when::{extract_when, is_when_meta, when_validator_tokens};
 
 macro_rules! count {
     () => (0usize);
@@ -71,6 +85,17 @@ enum_str! {
     pub enum MetaListValidation {
         Enumerate = "enumerate",
         Custom = "custom",
+        CustomAsync = "custom_async",
+        AllOf = "all_of",
+        AnyOf = "any_of",
+        OneOf = "one_of",
+        Not = "not",
+        Keys = "keys",
+        Values = "values",
+        PatternProperties = "pattern_properties",
+        AllowedKeys = "allowed_keys",
+        Contains = "contains",
+        PrefixItems = "prefix_items",
     }
 }
 
@@ -88,6 +113,16 @@ enum_str! {
         MaxProperties = "max_properties",
         MultipleOf = "multiple_of",
         Pattern = "pattern",
+        Format = "format",
+        ConstValue = "const_value",
+        AdditionalProperties = "additional_properties",
+        MinContains = "min_contains",
+        MaxContains = "max_contains",
+        MinimumField = "minimum_field",
+        MaximumField = "maximum_field",
+        ExclusiveMinimumField = "exclusive_minimum_field",
+        ExclusiveMaximumField = "exclusive_maximum_field",
+        EqField = "eq_field",
     }
 }
 
@@ -103,12 +138,30 @@ enum_str! {
     }
 }
 
+enum_str! {
+    pub enum MetaListGroup {
+        Groups = "groups",
+    }
+}
+
+enum_str! {
+    pub enum MetaWhen {
+        When = "when",
+    }
+}
+
 enum_str! {
     pub enum MetaNameValueMessage {
         Message = "message",
     }
 }
 
+enum_str! {
+    pub enum MetaNameValueMessageKey {
+        MessageKey = "message_key",
+    }
+}
+
 enum_str! {
     pub enum MetaPathMessage {
     }
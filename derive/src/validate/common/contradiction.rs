@@ -0,0 +1,8 @@
+/// Check the nested meta is `allow_contradiction`,
+/// which skips the validator from the check of the contradicting validators.
+pub fn is_allow_contradiction_meta(nested_meta: &syn::NestedMeta) -> bool {
+    matches!(
+        nested_meta,
+        syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("allow_contradiction")
+    )
+}
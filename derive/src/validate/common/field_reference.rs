@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use crate::types::Field;
+use crate::validate::meta::parse_validate_meta;
+
+use super::{get_expr_ident, MetaNameValueValidation};
+
+/// The field which the validation refers to, like `start` of `minimum_field = start`.
+pub fn get_field_reference(lit: &syn::Lit) -> Result<syn::Ident, crate::Errors> {
+    get_expr_ident(lit).ok_or_else(|| vec![crate::Error::field_name_only(lit)])
+}
+
+/// The fields which the validations of the field refer to,
+/// which must be bound as the variables before the validations.
+pub fn collect_field_references(field: &impl Field) -> Vec<syn::Ident> {
+    let mut references = vec![];
+    for attribute in field
+        .attrs()
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("validate"))
+    {
+        if let Ok(syn::Meta::List(syn::MetaList { nested, .. })) = parse_validate_meta(attribute) {
+            nested
+                .iter()
+                .for_each(|nested_meta| push_field_references(nested_meta, &mut references));
+        }
+    }
+
+    references
+}
+
+fn push_field_references(nested_meta: &syn::NestedMeta, references: &mut Vec<syn::Ident>) {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. })) => {
+            let is_reference = path
+                .get_ident()
+                .and_then(|ident| MetaNameValueValidation::from_str(&ident.to_string()).ok())
+                .map(|validation| is_field_reference_validation(&validation))
+                .unwrap_or(false);
+            match get_expr_ident(lit) {
+                Some(ident) if is_reference && !references.contains(&ident) => {
+                    references.push(ident)
+                }
+                _ => {}
+            }
+        }
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { nested, .. })) => nested
+            .iter()
+            .for_each(|nested_meta| push_field_references(nested_meta, references)),
+        _ => {}
+    }
+}
+
+fn is_field_reference_validation(validation: &MetaNameValueValidation) -> bool {
+    matches!(
+        validation,
+        MetaNameValueValidation::MinimumField
+            | MetaNameValueValidation::MaximumField
+            | MetaNameValueValidation::ExclusiveMinimumField
+            | MetaNameValueValidation::ExclusiveMaximumField
+            | MetaNameValueValidation::EqField
+    )
+}
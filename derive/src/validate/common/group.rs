@@ -0,0 +1,76 @@
+use crate::types::SingleIdentPath;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+
+use super::MetaListGroup;
+
+/// Check the nested meta is `groups(...)`.
+pub fn is_groups_meta(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, .. })) => path
+            .get_ident()
+            .map(|ident| MetaListGroup::from_str(&ident.to_string()).is_ok())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+pub fn extract_groups(nested_meta: &syn::NestedMeta) -> Result<Vec<syn::Ident>, crate::Errors> {
+    let (path, nested) = match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. })) => {
+            (path, nested)
+        }
+        _ => unreachable!("`groups` is checked by `is_groups_meta`."),
+    };
+
+    let mut errors = vec![];
+    if nested.is_empty() {
+        errors.push(crate::Error::validate_groups_need_item(path));
+    }
+
+    let groups = nested
+        .iter()
+        .filter_map(|group| match group {
+            syn::NestedMeta::Meta(syn::Meta::Path(group_path)) => {
+                Some(SingleIdentPath::new(group_path).ident().clone())
+            }
+            _ => {
+                errors.push(crate::Error::validate_groups_allow_ident_only(group));
+                None
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(groups)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run the validator only when `__group` is one of the groups.
+///
+/// The validator without groups belongs to the default group.
+pub fn group_validator_tokens(
+    validator: TokenStream,
+    groups: Option<&[syn::Ident]>,
+) -> TokenStream {
+    if validator.is_empty() {
+        return validator;
+    }
+
+    let condition = match groups {
+        Some(groups) => {
+            let names = groups.iter().map(|group| group.to_string());
+            quote!([#(::serde_valid::validation::Group::new(#names)),*].contains(&__group))
+        }
+        None => quote!(__group == ::serde_valid::validation::Group::DEFAULT),
+    };
+
+    quote!(
+        if #condition {
+            #validator
+        }
+    )
+}
@@ -1,9 +1,14 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Group, Literal, Span, TokenStream, TokenTree};
 use quote::ToTokens;
 
+/// Suffix of the str literal which holds the expression of the validation,
+/// like `max_length = MAX_NAME_LENGTH`, see [`expr_literal`].
+const EXPR_SUFFIX: &str = "__serde_valid_expr";
+
 pub enum LitNumeric<'a> {
     Int(&'a syn::LitInt),
     Float(&'a syn::LitFloat),
+    Expr(TokenStream),
 }
 
 impl<'a> ToTokens for LitNumeric<'a> {
@@ -11,6 +16,7 @@ impl<'a> ToTokens for LitNumeric<'a> {
         match self {
             LitNumeric::Int(lin) => lin.to_tokens(tokens),
             LitNumeric::Float(lin) => lin.to_tokens(tokens),
+            LitNumeric::Expr(expr) => expr.to_tokens(tokens),
         }
     }
 }
@@ -19,13 +25,89 @@ pub fn get_numeric<'a>(lit: &'a syn::Lit) -> Result<LitNumeric<'a>, crate::Error
     match lit {
         syn::Lit::Int(int) => Ok(LitNumeric::Int(int)),
         syn::Lit::Float(float) => Ok(LitNumeric::Float(float)),
-        _ => Err(vec![crate::Error::numeric_literal_only(lit)]),
+        _ => match get_expr(lit) {
+            Some(expr) => Ok(LitNumeric::Expr(expr)),
+            None => Err(vec![crate::Error::numeric_literal_only(lit)]),
+        },
     }
 }
 
 pub fn get_str(lit: &syn::Lit) -> Result<&syn::LitStr, crate::Errors> {
     match lit {
-        syn::Lit::Str(lit_str) => Ok(lit_str),
+        syn::Lit::Str(lit_str) if lit_str.suffix() != EXPR_SUFFIX => Ok(lit_str),
         _ => Err(vec![crate::Error::str_literal_only(lit)]),
     }
 }
+
+/// The str literal which holds the expression, like `MAX_NAME_LENGTH` or `u16::MAX as u32 / 2`.
+///
+/// [`syn::Meta`] accepts only literals as the values,
+/// so [`parse_validate_meta`](crate::validate::parse_validate_meta) rewrites the expressions
+/// into the str literals with the suffix, and [`get_expr`] restores them.
+pub fn expr_literal(expr: &[TokenTree]) -> TokenTree {
+    let expr = TokenStream::from_iter(expr.iter().cloned());
+    let mut literal: Literal = format!("{}{EXPR_SUFFIX}", Literal::string(&expr.to_string()))
+        .parse()
+        .expect("the str literal with the suffix is a literal.");
+    if let Some(token) = expr.into_iter().next() {
+        literal.set_span(token.span());
+    }
+
+    TokenTree::Literal(literal)
+}
+
+/// The expression of the validation, which is rewritten by [`expr_literal`].
+pub fn get_expr(lit: &syn::Lit) -> Option<TokenStream> {
+    match lit {
+        syn::Lit::Str(lit_str) if lit_str.suffix() == EXPR_SUFFIX => {
+            let expr = respan(lit_str.value().parse().ok()?, lit_str.span());
+            Some(quote::quote_spanned!(lit_str.span()=> (#expr)))
+        }
+        _ => None,
+    }
+}
+
+/// The identifier of the validation, like `minimum_field = start`, which is rewritten by [`expr_literal`].
+pub fn get_expr_ident(lit: &syn::Lit) -> Option<syn::Ident> {
+    match lit {
+        syn::Lit::Str(lit_str) if lit_str.suffix() == EXPR_SUFFIX => {
+            let mut ident: syn::Ident = syn::parse_str(&lit_str.value()).ok()?;
+            ident.set_span(lit_str.span());
+            Some(ident)
+        }
+        _ => None,
+    }
+}
+
+/// The value of the validation, which is the literal or the expression.
+pub fn get_lit_or_expr(lit: &syn::Lit) -> TokenStream {
+    get_expr(lit).unwrap_or_else(|| lit.to_token_stream())
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
+
+/// The str literal of the regex pattern, which is checked to compile at the expansion.
+///
+/// The patterns of the constants and the expressions can not be checked here,
+/// so their validators report the compile error of the regex at the validation instead.
+pub fn get_regex_str(lit: &syn::Lit) -> Result<&syn::LitStr, crate::Errors> {
+    let lit_str = get_str(lit)?;
+    match regex::Regex::new(&lit_str.value()) {
+        Ok(_) => Ok(lit_str),
+        Err(error) => Err(vec![crate::Error::regex_syntax_error(lit_str, &error)]),
+    }
+}
@@ -3,10 +3,15 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use std::str::FromStr;
 
-use super::{get_str, MetaListMessage, MetaNameValueMessage, MetaPathMessage};
+use super::message_template::{message_template_tokens, MessageTemplateParams};
+use super::{
+    get_str, MetaListGroup, MetaListMessage, MetaNameValueMessage, MetaNameValueMessageKey,
+    MetaPathMessage, MetaWhen,
+};
 
 pub fn extract_message_fn_tokens(
     nested_meta: &syn::NestedMeta,
+    template_params: &MessageTemplateParams,
 ) -> Result<TokenStream, crate::Errors> {
     match nested_meta {
         syn::NestedMeta::Meta(meta) => match meta {
@@ -14,7 +19,7 @@ pub fn extract_message_fn_tokens(
                 extract_message_fn_tokens_from_meta_list(message_fn_list)
             }
             syn::Meta::NameValue(name_value) => {
-                extract_message_fn_tokens_from_name_value(name_value)
+                extract_message_fn_tokens_from_name_value(name_value, template_params)
             }
             syn::Meta::Path(path) => {
                 let path_label = SingleIdentPath::new(path).ident().to_string();
@@ -35,6 +40,9 @@ pub fn extract_message_fn_tokens(
                         &(MetaNameValueMessage::iter().map(|x| x.name()))
                             .chain(MetaListMessage::iter().map(|x| x.name()))
                             .chain(MetaPathMessage::iter().map(|x| x.name()))
+                            .chain(MetaListGroup::iter().map(|x| x.name()))
+                            .chain(MetaNameValueMessageKey::iter().map(|x| x.name()))
+                            .chain(MetaWhen::iter().map(|x| x.name()))
                             .collect::<Vec<_>>(),
                     ))
                 }
@@ -68,6 +76,7 @@ fn extract_message_fn_tokens_from_meta_list(
                     &unknown,
                     &MetaListMessage::iter()
                         .map(|x| x.name())
+                        .chain(MetaListGroup::iter().map(|x| x.name()))
                         .collect::<Vec<_>>(),
                 )
             };
@@ -78,12 +87,13 @@ fn extract_message_fn_tokens_from_meta_list(
 
 fn extract_message_fn_tokens_from_name_value(
     syn::MetaNameValue { path, lit, .. }: &syn::MetaNameValue,
+    template_params: &MessageTemplateParams,
 ) -> Result<TokenStream, crate::Errors> {
     let path_ident = SingleIdentPath::new(&path).ident();
     let path_label = path_ident.to_string();
 
     match MetaNameValueMessage::from_str(&path_label) {
-        Ok(MetaNameValueMessage::Message) => get_message_fn_from_lit(lit),
+        Ok(MetaNameValueMessage::Message) => get_message_fn_from_lit(lit, template_params),
         Err(unknown) => if MetaListMessage::from_str(&path_label).is_ok() {
             Err(crate::Error::validate_meta_list_need_value(
                 path,
@@ -100,6 +110,8 @@ fn extract_message_fn_tokens_from_name_value(
                 &unknown,
                 &MetaNameValueMessage::iter()
                     .map(|x| x.name())
+                    .chain(MetaNameValueMessageKey::iter().map(|x| x.name()))
+                    .chain(MetaWhen::iter().map(|x| x.name()))
                     .collect::<Vec<_>>(),
             ))
         }
@@ -133,6 +145,26 @@ fn get_message_fn_from_nested_meta(
     }
 }
 
-fn get_message_fn_from_lit(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
-    get_str(lit).map(|lit_str| quote!(|_| { #lit_str.to_string() }))
+fn get_message_fn_from_lit(
+    lit: &syn::Lit,
+    template_params: &MessageTemplateParams,
+) -> Result<TokenStream, crate::Errors> {
+    message_template_tokens(get_str(lit)?, template_params)
+}
+
+/// `into_error_by` of the custom message function, or `into_error` of the default message,
+/// which needs no allocation.
+pub fn into_error_tokens(message_fn: Option<TokenStream>) -> TokenStream {
+    match message_fn {
+        Some(message_fn) => quote!(into_error_by(#message_fn)),
+        None => quote!(into_error()),
+    }
+}
+
+/// `Message` of the error params by the custom message function, or the default message.
+pub fn message_tokens(params: TokenStream, message_fn: Option<TokenStream>) -> TokenStream {
+    match message_fn {
+        Some(message_fn) => quote!(::serde_valid::error::Message::new(#params, #message_fn)),
+        None => quote!(::serde_valid::error::Message::with_default_message(#params)),
+    }
 }
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+
+use super::{get_str, MetaNameValueMessageKey};
+
+/// Check the nested meta is `message_key = "..."`.
+pub fn is_message_key_meta(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, .. })) => path
+            .get_ident()
+            .map(|ident| MetaNameValueMessageKey::from_str(&ident.to_string()).is_ok())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+pub fn extract_message_key(nested_meta: &syn::NestedMeta) -> Result<syn::LitStr, crate::Errors> {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { lit, .. })) => {
+            get_str(lit).cloned()
+        }
+        _ => unreachable!("`message_key` is checked by `is_message_key_meta`."),
+    }
+}
+
+/// Set the message key to the errors which the validator pushes into the field.
+pub fn message_key_validator_tokens(
+    validator: TokenStream,
+    message_key: Option<&syn::LitStr>,
+    rename: &str,
+) -> TokenStream {
+    let message_key = match message_key {
+        Some(message_key) if !validator.is_empty() => message_key,
+        _ => return validator,
+    };
+
+    quote!(
+        let __message_key_start =
+            ::serde_valid::validation::PropertiesErrors::field_count(&__properties_errors, #rename);
+        #validator
+        ::serde_valid::validation::PropertiesErrors::set_message_key(
+            &mut __properties_errors,
+            #rename,
+            __message_key_start,
+            #message_key,
+        );
+    )
+}
@@ -0,0 +1,174 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Placeholders which `message = "..."` of the validator can use, like `{maximum}`.
+///
+/// `{field}` is the name of the field in the errors, which is renamed by `#[serde(rename = ...)]`,
+/// and the others are the fields of the `*ErrorParams` or the values given by [`Self::with_value`].
+pub struct MessageTemplateParams {
+    params_type: Option<TokenStream>,
+    params_fields: &'static [&'static str],
+    values: Vec<(&'static str, TokenStream)>,
+    rename: String,
+}
+
+impl MessageTemplateParams {
+    pub fn new(validation_name: Option<&str>, rename: &str) -> Self {
+        let (params_type, params_fields): (Option<TokenStream>, &'static [&'static str]) =
+            match validation_name {
+                Some("minimum" | "minimum_field") => {
+                    (Some(quote!(MinimumErrorParams)), &["minimum"])
+                }
+                Some("maximum" | "maximum_field") => {
+                    (Some(quote!(MaximumErrorParams)), &["maximum"])
+                }
+                Some("exclusive_minimum" | "exclusive_minimum_field") => (
+                    Some(quote!(ExclusiveMinimumErrorParams)),
+                    &["exclusive_minimum"],
+                ),
+                Some("exclusive_maximum" | "exclusive_maximum_field") => (
+                    Some(quote!(ExclusiveMaximumErrorParams)),
+                    &["exclusive_maximum"],
+                ),
+                Some("multiple_of") => (Some(quote!(MultipleOfErrorParams)), &["multiple_of"]),
+                Some("min_length") => (Some(quote!(MinLengthErrorParams)), &["min_length"]),
+                Some("max_length") => (Some(quote!(MaxLengthErrorParams)), &["max_length"]),
+                Some("pattern") => (Some(quote!(PatternErrorParams)), &["pattern"]),
+                Some("format") => (Some(quote!(FormatErrorParams)), &["format"]),
+                Some("min_items") => (Some(quote!(MinItemsErrorParams)), &["min_items"]),
+                Some("max_items") => (Some(quote!(MaxItemsErrorParams)), &["max_items"]),
+                Some("min_contains") => (Some(quote!(MinContainsErrorParams)), &["min_contains"]),
+                Some("max_contains") => (Some(quote!(MaxContainsErrorParams)), &["max_contains"]),
+                Some("min_properties") => {
+                    (Some(quote!(MinPropertiesErrorParams)), &["min_properties"])
+                }
+                Some("max_properties") => {
+                    (Some(quote!(MaxPropertiesErrorParams)), &["max_properties"])
+                }
+                Some("enumerate") => (Some(quote!(EnumerateErrorParams)), &["enumerate"]),
+                Some("const_value") => (Some(quote!(ConstErrorParams)), &["const_value"]),
+                Some("eq_field") => (Some(quote!(EqFieldErrorParams)), &["eq_field"]),
+                _ => (None, &[]),
+            };
+
+        Self {
+            params_type,
+            params_fields,
+            values: vec![],
+            rename: rename.to_owned(),
+        }
+    }
+
+    /// Add the placeholder of the constant value, which is not the field of the `*ErrorParams`,
+    /// like `{max_contains}` in the message of `min_contains`.
+    pub fn with_value(mut self, name: &'static str, value: TokenStream) -> Self {
+        if !self.params_fields.contains(&name) {
+            self.values.push((name, value));
+        }
+        self
+    }
+
+    fn candidates(&self) -> Vec<&str> {
+        std::iter::once("field")
+            .chain(self.params_fields.iter().copied())
+            .chain(self.values.iter().map(|(name, _)| *name))
+            .collect()
+    }
+
+    fn placeholder_tokens(&self, name: &str) -> Option<TokenStream> {
+        if name == "field" {
+            let rename = &self.rename;
+            Some(quote!(#rename))
+        } else if name == "enumerate" && self.params_fields.contains(&name) {
+            Some(quote!(__params
+                .enumerate
+                .iter()
+                .map(::std::string::ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")))
+        } else if self.params_fields.contains(&name) {
+            let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+            Some(quote!(__params.#ident))
+        } else {
+            self.values
+                .iter()
+                .find(|(value_name, _)| *value_name == name)
+                .map(|(_, value)| value.clone())
+        }
+    }
+}
+
+/// Message function of the template, which replaces `{name}` by the parameter,
+/// and `{{` and `}}` by the braces as same as `format!`.
+pub fn message_template_tokens(
+    lit_str: &syn::LitStr,
+    params: &MessageTemplateParams,
+) -> Result<TokenStream, crate::Errors> {
+    let template = lit_str.value();
+    let mut errors = vec![];
+    let mut format = String::new();
+    let mut args = vec![];
+
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format.push_str("}}");
+            }
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(c);
+                }
+                if !closed {
+                    errors.push(crate::Error::message_template_unclosed_brace(lit_str));
+                    break;
+                }
+
+                match params.placeholder_tokens(name.trim()) {
+                    Some(arg) => {
+                        format.push_str("{}");
+                        args.push(arg);
+                    }
+                    None => errors.push(crate::Error::message_template_unknown_placeholder(
+                        lit_str,
+                        &name,
+                        &params.candidates(),
+                    )),
+                }
+            }
+            '}' => errors.push(crate::Error::message_template_unmatched_brace(lit_str)),
+            c => format.push(c),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if args.is_empty() {
+        let message = syn::LitStr::new(
+            &format.replace("{{", "{").replace("}}", "}"),
+            lit_str.span(),
+        );
+        return Ok(quote!(|_| { #message.to_string() }));
+    }
+
+    let format = syn::LitStr::new(&format, lit_str.span());
+    Ok(match &params.params_type {
+        Some(params_type) => quote!(
+            move |__params: &::serde_valid::#params_type| ::std::format!(#format, #(#args),*)
+        ),
+        None => quote!(move |_| ::std::format!(#format, #(#args),*)),
+    })
+}
@@ -0,0 +1,74 @@
+use crate::types::SingleIdentPath;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+
+use super::{get_str, MetaWhen};
+
+/// Check the nested meta is `when = "..."` or `when(...)`.
+pub fn is_when_meta(nested_meta: &syn::NestedMeta) -> bool {
+    let path = match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, .. })) => path,
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, .. })) => path,
+        _ => return false,
+    };
+
+    path.get_ident()
+        .map(|ident| MetaWhen::from_str(&ident.to_string()).is_ok())
+        .unwrap_or(false)
+}
+
+/// Condition of the validator.
+///
+/// `when = "expr"` is the boolean expression which can use `self`,
+/// and `when(predicate)` calls `predicate(self)`.
+pub fn extract_when(
+    nested_meta: &syn::NestedMeta,
+    context: Option<&syn::Type>,
+) -> Result<TokenStream, crate::Errors> {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { lit, .. })) => {
+            let lit_str = get_str(lit)?;
+            lit_str
+                .parse::<TokenStream>()
+                .map(|expr| quote!((#expr)))
+                .map_err(|error| vec![crate::Error::validate_when_parse_error(lit_str, &error)])
+        }
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. })) => {
+            let predicate = match nested.len() {
+                0 => Err(vec![crate::Error::validate_when_need_item(path)])?,
+                1 => match &nested[0] {
+                    syn::NestedMeta::Meta(syn::Meta::Path(predicate)) => {
+                        SingleIdentPath::new(predicate).ident().clone()
+                    }
+                    nested_meta => Err(vec![crate::Error::validate_when_allow_function_only(
+                        nested_meta,
+                    )])?,
+                },
+                _ => Err(nested
+                    .iter()
+                    .skip(1)
+                    .map(crate::Error::validate_when_tail_error)
+                    .collect::<Vec<_>>())?,
+            };
+
+            Ok(match context {
+                Some(_) => quote!(#predicate(self, __context)),
+                None => quote!(#predicate(self)),
+            })
+        }
+        _ => unreachable!("`when` is checked by `is_when_meta`."),
+    }
+}
+
+/// Run the validator only when the condition is true.
+pub fn when_validator_tokens(validator: TokenStream, when: Option<&TokenStream>) -> TokenStream {
+    match when {
+        Some(condition) if !validator.is_empty() => quote!(
+            if #condition {
+                #validator
+            }
+        ),
+        _ => validator,
+    }
+}
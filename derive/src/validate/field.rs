@@ -1,4 +1,7 @@
+mod consistency;
+
 use crate::types::Field;
+use crate::validate::common::collect_field_references;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -28,18 +31,40 @@ impl<'a, F: Field + Clone> FieldValidators<'a, F> {
     }
 
     pub fn push(&mut self, validator: Validator) {
-        self.validators.push(validator)
+        if !validator.is_empty() {
+            self.validators.push(validator)
+        }
+    }
+
+    /// Errors of the validators which contradict each other.
+    pub fn check_contradictions(&self) -> crate::Errors {
+        consistency::check_field_consistency(self.field.as_ref())
     }
 
     pub fn get_tokens(&self) -> Option<TokenStream> {
         if !self.validators.is_empty() {
-            let validators = TokenStream::from_iter(self.validators.clone());
+            let validators = TokenStream::from_iter(self.validators.iter().map(|validator| {
+                quote!(
+                    #validator
+                    if __options.is_exceeded(|| ::serde_valid::validation::count_errors(
+                        &__errors,
+                        &__properties_errors
+                    )) {
+                        break '__validation;
+                    }
+                )
+            }));
             Some(quote! (#validators))
         } else {
             None
         }
     }
 
+    /// The other fields which the validators refer to, like `minimum_field = start`.
+    pub fn field_references(&self) -> Vec<syn::Ident> {
+        collect_field_references(self.field.as_ref())
+    }
+
     pub fn get_field_variable_token(&self) -> TokenStream {
         let field_ident = self.field.ident();
         let field_getter = self.field.getter_token();
@@ -53,8 +78,10 @@ impl<'a, F: Field + Clone> FieldValidators<'a, F> {
 
         if normal_tokens.is_some() {
             let field_variable_token = self.get_field_variable_token();
+            let field_references = self.field_references();
             quote!(
                 #field_variable_token
+                #(let #field_references = &self.#field_references;)*
                 #normal_tokens
             )
         } else {
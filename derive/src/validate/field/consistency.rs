@@ -0,0 +1,229 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use quote::ToTokens;
+
+use crate::types::Field;
+use crate::validate::common::{
+    extract_groups, is_allow_contradiction_meta, is_groups_meta, is_when_meta,
+};
+use crate::validate::meta::parse_validate_meta;
+
+/// Pairs of the lower and the upper limit, and whether the equal limits are satisfiable.
+const RANGES: &[(&str, &str, bool)] = &[
+    ("minimum", "maximum", true),
+    ("minimum", "exclusive_maximum", false),
+    ("exclusive_minimum", "maximum", false),
+    ("exclusive_minimum", "exclusive_maximum", false),
+    ("min_length", "max_length", true),
+    ("min_items", "max_items", true),
+    ("min_properties", "max_properties", true),
+];
+
+/// Name of `Group::DEFAULT`, which `groups(default)` also refers to.
+const DEFAULT_GROUP: &str = "default";
+
+const SIZES: &[&str] = &[
+    "min_length",
+    "max_length",
+    "min_items",
+    "max_items",
+    "min_properties",
+    "max_properties",
+];
+
+struct Limit {
+    name: String,
+    value: f64,
+    lit: syn::Lit,
+    /// Position of the limit in the field, which identifies the limit in the groups.
+    index: usize,
+}
+
+impl Limit {
+    fn new(name: String, lit: &syn::Lit, index: usize) -> Option<Self> {
+        let value = match lit {
+            syn::Lit::Int(int) => int.base10_digits().parse().ok()?,
+            syn::Lit::Float(float) => float.base10_digits().parse().ok()?,
+            _ => return None,
+        };
+        Some(Self {
+            name,
+            value,
+            lit: lit.clone(),
+            index,
+        })
+    }
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.name, self.lit.to_token_stream())
+    }
+}
+
+/// Limits of the validators, which always apply together.
+#[derive(Default)]
+struct Limits<'a> {
+    limits: HashMap<&'a str, Vec<&'a Limit>>,
+    enumerate: Vec<&'a Limit>,
+}
+
+impl<'a> Limits<'a> {
+    fn push(&mut self, limit: &'a Limit) {
+        if limit.name == "enumerate" {
+            self.enumerate.push(limit);
+        } else {
+            self.limits.entry(&limit.name).or_default().push(limit);
+        }
+    }
+
+    fn get(&self, name: &str) -> &[&'a Limit] {
+        self.limits.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Check the limits, where `reported` skips the contradictions found in the other groups.
+    fn check(&self, reported: &mut HashSet<(usize, usize)>, errors: &mut crate::Errors) {
+        for (lower_name, upper_name, inclusive) in RANGES {
+            for lower in self.get(lower_name) {
+                for upper in self.get(upper_name) {
+                    let is_impossible =
+                        lower.value > upper.value || (!inclusive && lower.value == upper.value);
+                    if is_impossible && reported.insert((lower.index, upper.index)) {
+                        errors.push(crate::Error::validate_impossible_range(
+                            &upper.lit,
+                            &lower.to_string(),
+                            &upper.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for multiple_of in self.get("multiple_of") {
+            if multiple_of.value == 0.0 && reported.insert((multiple_of.index, multiple_of.index)) {
+                errors.push(crate::Error::validate_multiple_of_zero(&multiple_of.lit));
+            }
+        }
+
+        for name in SIZES {
+            for size in self.get(name) {
+                if size.value < 0.0 && reported.insert((size.index, size.index)) {
+                    errors.push(crate::Error::validate_negative_size(
+                        &size.lit,
+                        &size.to_string(),
+                    ));
+                }
+            }
+        }
+
+        for value in &self.enumerate {
+            let out_of_range = |name: &str, is_out: fn(f64, f64) -> bool| {
+                self.get(name)
+                    .iter()
+                    .find(|limit| is_out(value.value, limit.value))
+            };
+            if let Some(limit) = out_of_range("minimum", |value, limit| value < limit)
+                .or_else(|| out_of_range("maximum", |value, limit| value > limit))
+                .or_else(|| out_of_range("exclusive_minimum", |value, limit| value <= limit))
+                .or_else(|| out_of_range("exclusive_maximum", |value, limit| value >= limit))
+            {
+                if reported.insert((value.index, limit.index)) {
+                    errors.push(crate::Error::validate_enumerate_out_of_range(
+                        &value.lit,
+                        &limit.to_string(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Check the validators of the field contradict each other,
+/// like `#[validate(minimum = 10)]` and `#[validate(maximum = 5)]`, which always fail.
+///
+/// Only the literal limits are compared, in each group where the validators run,
+/// and the validators with `when` or `allow_contradiction` are skipped.
+pub fn check_field_consistency(field: &impl Field) -> crate::Errors {
+    let mut validators: Vec<(Vec<String>, Vec<Limit>)> = vec![];
+    let mut index = 0;
+
+    for attribute in field
+        .attrs()
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("validate"))
+    {
+        let nested = match parse_validate_meta(attribute) {
+            Ok(syn::Meta::List(syn::MetaList { nested, .. })) => nested,
+            _ => continue,
+        };
+        if nested.iter().any(|nested_meta| {
+            is_when_meta(nested_meta) || is_allow_contradiction_meta(nested_meta)
+        }) {
+            continue;
+        }
+
+        let mut groups = vec![];
+        for nested_meta in nested
+            .iter()
+            .filter(|nested_meta| is_groups_meta(nested_meta))
+        {
+            if let Ok(idents) = extract_groups(nested_meta) {
+                groups.extend(idents.iter().map(ToString::to_string));
+            }
+        }
+        // The validators without groups run in the default group.
+        if groups.is_empty() {
+            groups.push(DEFAULT_GROUP.to_owned());
+        }
+
+        let mut limits = vec![];
+        for nested_meta in nested.iter() {
+            match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit,
+                    ..
+                })) => {
+                    let name = match path.get_ident() {
+                        Some(ident) => ident.to_string(),
+                        None => continue,
+                    };
+                    index += 1;
+                    limits.extend(Limit::new(name, lit, index));
+                }
+                syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. }))
+                    if path.is_ident("enumerate") =>
+                {
+                    for item in nested.iter() {
+                        if let syn::NestedMeta::Lit(lit) = item {
+                            index += 1;
+                            limits.extend(Limit::new("enumerate".to_owned(), lit, index));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        validators.push((groups, limits));
+    }
+
+    let all_groups = validators
+        .iter()
+        .flat_map(|(groups, _)| groups.iter())
+        .collect::<BTreeSet<_>>();
+
+    let mut errors = vec![];
+    let mut reported = HashSet::new();
+    for group in all_groups {
+        let mut limits = Limits::default();
+        for (_, group_limits) in validators
+            .iter()
+            .filter(|(groups, _)| groups.contains(group))
+        {
+            group_limits.iter().for_each(|limit| limits.push(limit));
+        }
+        limits.check(&mut reported, &mut errors);
+    }
+
+    errors
+}
@@ -1,5 +1,9 @@
+mod const_value;
 mod custom;
 mod enumerate;
+mod eq_field;
 
-pub use custom::extract_generic_custom_validator;
+pub use const_value::extract_generic_const_value_validator;
+pub use custom::{extract_custom_fn_name_from_list, extract_generic_custom_validator};
 pub use enumerate::extract_generic_enumerate_validator;
+pub use eq_field::extract_generic_eq_field_validator;
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use crate::types::Field;
+use crate::validate::common::{get_lit_or_expr, into_error_tokens};
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn extract_generic_const_value_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_generic_const_value_validator(field, validation_value, message_fn, rename_map)
+}
+
+fn inner_extract_generic_const_value_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let const_value = get_const_value(validation_value)?;
+    let into_error = into_error_tokens(message_fn);
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedConst::validate_composited_const(
+            #field_ident,
+            #const_value,
+        ) {
+            use ::serde_valid::validation::IntoError;
+
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || __composited_error_params.#into_error,
+            );
+        }
+    ))
+}
+
+fn get_const_value(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
+    match lit {
+        syn::Lit::Str(_)
+        | syn::Lit::Char(_)
+        | syn::Lit::Int(_)
+        | syn::Lit::Float(_)
+        | syn::Lit::Bool(_) => Ok(get_lit_or_expr(lit)),
+        _ => Err(vec![crate::Error::literal_not_support(lit)]),
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::types::Field;
+use crate::types::{CommaSeparatedNestedMetas, Field};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -9,28 +9,41 @@ pub fn extract_generic_custom_validator(
     field: &impl Field,
     syn::MetaList { path, nested, .. }: &syn::MetaList,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
     let field_name = field.name();
     let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(field_name);
 
-    let custom_fn_name = match nested.len() {
-        0 => Err(crate::Error::validate_custom_need_item(path)),
-        1 => extract_custom_fn_name(&nested[0]),
-        _ => Err(crate::Error::validate_custom_tail_error(&nested)),
-    }
-    .map_err(|error| vec![error])?;
+    let custom_fn_name = extract_custom_fn_name_from_list(path, nested)?;
+    let custom_fn_args = match context {
+        Some(_) => quote!(#field_ident, __context),
+        None => quote!(#field_ident),
+    };
 
     Ok(quote!(
-        if let Err(__error) = #custom_fn_name(#field_ident) {
-            __properties_errors
-                .entry(#rename)
-                .or_default()
-                .push(__error);
+        if let Err(__error) = #custom_fn_name(#custom_fn_args) {
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || __error,
+            );
         };
     ))
 }
 
+pub fn extract_custom_fn_name_from_list(
+    path: &syn::Path,
+    nested: &CommaSeparatedNestedMetas,
+) -> Result<TokenStream, crate::Errors> {
+    match nested.len() {
+        0 => Err(crate::Error::validate_custom_need_item(path)),
+        1 => extract_custom_fn_name(&nested[0]),
+        _ => Err(crate::Error::validate_custom_tail_error(nested)),
+    }
+    .map_err(|error| vec![error])
+}
+
 fn extract_custom_fn_name(nested_meta: &syn::NestedMeta) -> Result<TokenStream, crate::Error> {
     match nested_meta {
         syn::NestedMeta::Meta(meta) => match meta {
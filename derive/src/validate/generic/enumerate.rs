@@ -1,12 +1,11 @@
 use std::collections::HashMap;
 
 use crate::types::Field;
+use crate::validate::common::{get_lit_or_expr, into_error_tokens};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
 
-type Lits<'a> = syn::punctuated::Punctuated<&'a syn::Lit, syn::token::Comma>;
-
 pub fn extract_generic_enumerate_validator(
     field: &impl Field,
     item_list: &syn::MetaList,
@@ -28,39 +27,36 @@ fn inner_extract_generic_enumerate_validator(
     let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(field_name);
     let enumerate = get_enumerate(item_list)?;
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::EnumerateErrorParams::to_default_message
-    ));
+    let into_error = into_error_tokens(message_fn);
 
     Ok(quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedEnumerate::validate_composited_enumerate(
             #field_ident,
-            &[#enumerate],
+            &[#(#enumerate),*],
         ) {
-            use ::serde_valid::error::ToDefaultMessage;
             use ::serde_valid::validation::IntoError;
 
-            __properties_errors
-                .entry(#rename)
-                .or_default()
-                .push(__composited_error_params.into_error_by(#message)
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || __composited_error_params.#into_error,
             );
         }
     ))
 }
 
-fn get_enumerate<'a>(
-    syn::MetaList { path, nested, .. }: &'a syn::MetaList,
-) -> Result<Lits<'a>, crate::Errors> {
+fn get_enumerate(
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+) -> Result<Vec<TokenStream>, crate::Errors> {
     let mut errors = vec![];
-    let mut enumerate = Lits::new();
+    let mut enumerate = vec![];
 
     if nested.len() == 0 {
         errors.push(crate::Error::validate_enumerate_need_item(path));
     }
     for item in nested {
         match item {
-            syn::NestedMeta::Lit(lit) => enumerate.push(lit),
+            syn::NestedMeta::Lit(lit) => enumerate.push(get_lit_or_expr(lit)),
             syn::NestedMeta::Meta(meta) => errors.push(crate::Error::literal_only(meta)),
         }
     }
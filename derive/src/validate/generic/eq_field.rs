@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::types::Field;
+use crate::validate::common::{get_field_reference, into_error_tokens};
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn extract_generic_eq_field_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let other_field_ident = get_field_reference(validation_value)?;
+    let other_field_name = other_field_ident.to_string();
+    let other_rename = rename_map
+        .get(&other_field_name)
+        .unwrap_or(&other_field_name);
+    let into_error = into_error_tokens(message_fn);
+
+    Ok(quote!(
+        if #field_ident != #other_field_ident {
+            use ::serde_valid::validation::IntoError;
+
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || {
+                    ::serde_valid::validation::Composited::Single(
+                        ::serde_valid::EqFieldErrorParams::new(#other_rename)
+                    )
+                    .#into_error
+                },
+            );
+        }
+    ))
+}
@@ -1,14 +1,24 @@
 mod meta_list;
 mod meta_path;
+mod nested_meta_composition;
+mod nested_meta_contains;
+mod nested_meta_entries;
 mod nested_meta_list;
 mod nested_meta_name_value;
 mod nested_meta_path;
+mod nested_meta_prefix_items;
+mod parse_meta;
 
 use std::collections::HashMap;
 
 use crate::types::Field;
 use crate::validate::Validator;
 use meta_path::extract_validator_from_meta_path;
+pub use meta_path::push_inner_errors_tokens;
+pub use nested_meta_contains::is_contains_meta;
+pub use nested_meta_entries::{extract_pattern_property, is_entries_meta};
+pub use nested_meta_prefix_items::extract_prefix_item;
+pub use parse_meta::parse_validate_meta;
 
 use self::meta_list::extract_validator_from_meta_list;
 
@@ -16,12 +26,13 @@ pub fn extract_meta_validator(
     field: &impl Field,
     attribute: &syn::Attribute,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
-    match attribute.parse_meta() {
+    match parse_validate_meta(attribute) {
         Ok(syn::Meta::List(list)) => {
-            extract_validator_from_meta_list(field, attribute, &list, rename_map)
+            extract_validator_from_meta_list(field, attribute, &list, rename_map, context)
         }
-        Ok(syn::Meta::Path(_)) => extract_validator_from_meta_path(field, rename_map),
+        Ok(syn::Meta::Path(_)) => extract_validator_from_meta_path(field, rename_map, context),
         Ok(syn::Meta::NameValue(name_value)) => {
             Err(vec![crate::Error::validate_meta_name_value_not_support(
                 name_value,
@@ -1,10 +1,18 @@
 use std::collections::HashMap;
 
+use proc_macro2::TokenStream;
+
+use super::nested_meta_contains::{extract_contains_validator, is_contains_meta};
+use super::nested_meta_entries::{extract_entries_validator, is_entries_meta};
 use super::nested_meta_list::extract_validator_from_nested_meta_list;
 use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value;
 use super::nested_meta_path::extract_validator_from_nested_meta_path;
 use crate::types::Field;
-use crate::validate::common::extract_message_fn_tokens;
+use crate::validate::common::{
+    extract_groups, extract_message_fn_tokens, extract_message_key, extract_when,
+    group_validator_tokens, is_allow_contradiction_meta, is_groups_meta, is_message_key_meta,
+    is_when_meta, message_key_validator_tokens, when_validator_tokens, MessageTemplateParams,
+};
 use crate::validate::Validator;
 
 pub fn extract_validator_from_meta_list(
@@ -12,45 +20,176 @@ pub fn extract_validator_from_meta_list(
     attribute: &syn::Attribute,
     syn::MetaList { nested, .. }: &syn::MetaList,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
-    let messaeg_fn = match nested.len() {
-        0..=1 => None,
-        2 => match extract_message_fn_tokens(&nested[1]) {
-            Ok(message_fn) => Some(message_fn),
-            Err(message_fn_errors) => {
-                errors.extend(message_fn_errors);
+
+    // `keys(...)`, `values(...)` and the other entries validations can be written side by side,
+    // as same as `contains(...)`, `min_contains` and `max_contains`.
+    let (entries_metas, nested_metas): (Vec<_>, Vec<_>) = nested
+        .iter()
+        .partition(|nested_meta| is_entries_meta(nested_meta));
+    let (contains_metas, nested_metas): (Vec<_>, Vec<_>) = nested_metas
+        .into_iter()
+        .partition(|nested_meta| is_contains_meta(nested_meta));
+    let (validation_meta, option_metas) = if entries_metas.is_empty() && contains_metas.is_empty() {
+        (
+            nested_metas.first().copied(),
+            &nested_metas[nested_metas.len().min(1)..],
+        )
+    } else {
+        (None, nested_metas.as_slice())
+    };
+
+    let (groups_metas, option_metas): (Vec<_>, Vec<_>) = option_metas
+        .iter()
+        .copied()
+        .partition(|nested_meta| is_groups_meta(nested_meta));
+    let (when_metas, option_metas): (Vec<_>, Vec<_>) = option_metas
+        .into_iter()
+        .partition(|nested_meta| is_when_meta(nested_meta));
+    // `allow_contradiction` is used only by the check of the contradicting validators.
+    let option_metas = option_metas
+        .into_iter()
+        .filter(|nested_meta| !is_allow_contradiction_meta(nested_meta))
+        .collect::<Vec<_>>();
+    let (message_key_metas, message_fn_metas): (Vec<_>, Vec<_>) = option_metas
+        .into_iter()
+        .partition(|nested_meta| is_message_key_meta(nested_meta));
+
+    let field_name = field.name();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let template_params =
+        MessageTemplateParams::new(validation_meta.and_then(validation_name).as_deref(), rename);
+    let message_fn_meta = match message_fn_metas.as_slice() {
+        [] => None,
+        [message_fn_meta] => Some(*message_fn_meta),
+        _ => {
+            for meta in message_fn_metas {
+                errors.push(crate::Error::too_many_list_items(meta));
+            }
+            None
+        }
+    };
+    // The message of `contains(...)` is extracted for each count, which has its own params.
+    let messaeg_fn = match message_fn_meta {
+        Some(message_fn_meta) if contains_metas.is_empty() => {
+            match extract_message_fn_tokens(message_fn_meta, &template_params) {
+                Ok(message_fn) => Some(message_fn),
+                Err(message_fn_errors) => {
+                    errors.extend(message_fn_errors);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let message_key = match message_key_metas.as_slice() {
+        [] => None,
+        [message_key_meta] => match extract_message_key(message_key_meta) {
+            Ok(message_key) => Some(message_key),
+            Err(message_key_errors) => {
+                errors.extend(message_key_errors);
                 None
             }
         },
         _ => {
-            for meta in nested.iter().skip(1) {
+            for meta in message_key_metas {
                 errors.push(crate::Error::too_many_list_items(meta));
             }
             None
         }
     };
 
-    if nested.len() > 0 {
-        let meta_item = &nested[0];
-        match meta_item {
-            syn::NestedMeta::Meta(meta) => match meta {
-                syn::Meta::Path(path) => {
-                    extract_validator_from_nested_meta_path(field, path, messaeg_fn, rename_map)
-                }
-                syn::Meta::List(list) => {
-                    extract_validator_from_nested_meta_list(field, list, messaeg_fn, rename_map)
-                }
-                syn::Meta::NameValue(name_value) => extract_validator_from_nested_meta_name_value(
-                    field, attribute, name_value, messaeg_fn, rename_map,
-                ),
+    let groups = match groups_metas.as_slice() {
+        [] => None,
+        [groups_meta] => match extract_groups(groups_meta) {
+            Ok(groups) => Some(groups),
+            Err(groups_errors) => {
+                errors.extend(groups_errors);
+                None
+            }
+        },
+        _ => {
+            for meta in groups_metas {
+                errors.push(crate::Error::too_many_list_items(meta));
+            }
+            None
+        }
+    };
+
+    let when = match when_metas.as_slice() {
+        [] => None,
+        [when_meta] => match extract_when(when_meta, context) {
+            Ok(when) => Some(when),
+            Err(when_errors) => {
+                errors.extend(when_errors);
+                None
             }
-            .map_err(|validator_errors| {
+        },
+        _ => {
+            for meta in when_metas {
+                errors.push(crate::Error::too_many_list_items(meta));
+            }
+            None
+        }
+    };
+
+    let validator = if !entries_metas.is_empty() {
+        for meta in contains_metas {
+            errors.push(crate::Error::too_many_list_items(meta));
+        }
+        Some(extract_entries_validator(
+            field,
+            attribute,
+            &to_metas(&entries_metas),
+            messaeg_fn,
+            rename_map,
+            context,
+        ))
+    } else if !contains_metas.is_empty() {
+        Some(extract_contains_validator(
+            field,
+            attribute,
+            &to_metas(&contains_metas),
+            |template_params| {
+                message_fn_meta
+                    .map(|message_fn_meta| {
+                        extract_message_fn_tokens(message_fn_meta, &template_params)
+                    })
+                    .transpose()
+            },
+            rename_map,
+            context,
+        ))
+    } else {
+        validation_meta.map(|validation_meta| {
+            extract_validator_from_nested_meta(
+                field,
+                attribute,
+                validation_meta,
+                messaeg_fn,
+                rename_map,
+                context,
+            )
+        })
+    };
+
+    if let Some(validator) = validator {
+        match validator {
+            Ok(validator) if errors.is_empty() => {
+                let validator =
+                    message_key_validator_tokens(validator, message_key.as_ref(), rename);
+
+                Ok(group_validator_tokens(
+                    when_validator_tokens(validator, when.as_ref()),
+                    groups.as_deref(),
+                ))
+            }
+            Ok(_) => Err(errors),
+            Err(validator_errors) => {
                 errors.extend(validator_errors);
-                errors
-            }),
-            syn::NestedMeta::Lit(lit) => {
-                errors.push(crate::Error::validate_meta_literal_not_support(lit));
                 Err(errors)
             }
         }
@@ -59,3 +198,48 @@ pub fn extract_validator_from_meta_list(
         Err(errors)
     }
 }
+
+/// Name of the validation, like `maximum` of `maximum = 10`.
+fn validation_name(nested_meta: &syn::NestedMeta) -> Option<String> {
+    let path = match nested_meta {
+        syn::NestedMeta::Meta(meta) => meta.path(),
+        syn::NestedMeta::Lit(_) => return None,
+    };
+    path.get_ident().map(ToString::to_string)
+}
+
+fn to_metas(nested_metas: &[&syn::NestedMeta]) -> Vec<syn::Meta> {
+    nested_metas
+        .iter()
+        .filter_map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(meta) => Some(meta.clone()),
+            syn::NestedMeta::Lit(_) => None,
+        })
+        .collect()
+}
+
+pub fn extract_validator_from_nested_meta(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    nested_meta: &syn::NestedMeta,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    match nested_meta {
+        syn::NestedMeta::Meta(meta) => match meta {
+            syn::Meta::Path(path) => {
+                extract_validator_from_nested_meta_path(field, path, message_fn, rename_map)
+            }
+            syn::Meta::List(list) => extract_validator_from_nested_meta_list(
+                field, attribute, list, message_fn, rename_map, context,
+            ),
+            syn::Meta::NameValue(name_value) => extract_validator_from_nested_meta_name_value(
+                field, attribute, name_value, message_fn, rename_map, context,
+            ),
+        },
+        syn::NestedMeta::Lit(lit) => {
+            Err(vec![crate::Error::validate_meta_literal_not_support(lit)])
+        }
+    }
+}
@@ -8,34 +8,65 @@ use quote::quote;
 pub fn extract_validator_from_meta_path(
     field: &impl Field,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
-    Ok(inner_extract_validator_from_meta_path(field, rename_map))
+    Ok(inner_extract_validator_from_meta_path(
+        field, rename_map, context,
+    ))
 }
 
 fn inner_extract_validator_from_meta_path(
     field: &impl Field,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> TokenStream {
     let field_ident = field.ident();
     let field_name = field.name();
     let rename = rename_map.get(field_name).unwrap_or(field_name);
+    match context {
+        Some(_) => {
+            let push_inner_errors = push_inner_errors_tokens(rename);
+            quote!(
+                if let Err(__inner_errors) = ::serde_valid::ValidateWith::validate_group_with(
+                    #field_ident,
+                    __context,
+                    __group
+                ) {
+                    #push_inner_errors
+                }
+            )
+        }
+        None => quote!(
+            let __field_options = __options.remaining(|| ::serde_valid::validation::count_errors(
+                &__errors,
+                &__properties_errors
+            ));
+            ::serde_valid::validation::PropertiesErrors::validate_field(
+                &mut __properties_errors,
+                #rename,
+                || #field_ident.validate_group_with_options(__group, __field_options),
+                || #field_ident.is_valid_group(__group),
+            );
+        ),
+    }
+}
 
+/// Push `__inner_errors` of the nested field into `__properties_errors`.
+pub fn push_inner_errors_tokens(rename: &str) -> TokenStream {
     quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
-            match __inner_errors {
-                ::serde_valid::validation::Errors::Object(__object_errors) => {
-                    __properties_errors.entry(#rename).or_default().push(
-                        ::serde_valid::validation::Error::Properties(__object_errors)
-                    );
-                }
-                ::serde_valid::validation::Errors::Array(__array_errors) => {
-                    __properties_errors.entry(#rename).or_default().push(
-                        ::serde_valid::validation::Error::Items(__array_errors)
-                    );
-                }
-                ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
-                    __properties_errors.entry(#rename).or_default().extend(__new_type_errors);
-                }
+        match __inner_errors {
+            ::serde_valid::validation::Errors::Object(__object_errors) => {
+                __properties_errors.entry(#rename).or_default().push(
+                    ::serde_valid::validation::Error::Properties(__object_errors)
+                );
+            }
+            ::serde_valid::validation::Errors::Array(__array_errors) => {
+                __properties_errors.entry(#rename).or_default().push(
+                    ::serde_valid::validation::Error::Items(__array_errors)
+                );
+            }
+            ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                __properties_errors.entry(#rename).or_default().extend(__new_type_errors);
             }
         }
     )
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::meta_list::extract_validator_from_nested_meta;
+use crate::types::Field;
+use crate::validate::common::{message_tokens, MetaListValidation};
+use crate::validate::Validator;
+
+/// `all_of(...)`, `any_of(...)`, `one_of(...)` and `not(...)` of the validators.
+pub fn extract_composition_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    composition: MetaListValidation,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+
+    if nested.is_empty() {
+        return Err(vec![crate::Error::validate_composition_need_item(
+            path,
+            composition.name(),
+        )]);
+    }
+
+    // The failures of `all_of` are the failures of the validators themselves.
+    let inner_message_fn = match composition {
+        MetaListValidation::AllOf => message_fn.clone(),
+        _ => None,
+    };
+
+    let mut errors = vec![];
+    let validators = nested
+        .iter()
+        .filter_map(|nested_meta| {
+            match extract_validator_from_nested_meta(
+                field,
+                attribute,
+                nested_meta,
+                inner_message_fn.clone(),
+                rename_map,
+                context,
+            ) {
+                Ok(validator) => Some(validator),
+                Err(validator_errors) => {
+                    errors.extend(validator_errors);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let (error_variant, params, condition) = match composition {
+        MetaListValidation::AllOf => return Ok(quote!(#(#validators)*)),
+        MetaListValidation::AnyOf => (
+            quote!(AnyOf),
+            quote!(::serde_valid::AnyOfErrorParams::new(__composition_errors)),
+            quote!(__composition_errors.iter().all(|errors| !errors.is_empty())),
+        ),
+        MetaListValidation::OneOf => (
+            quote!(OneOf),
+            quote!(::serde_valid::OneOfErrorParams::new(__composition_errors)),
+            quote!(
+                __composition_errors
+                    .iter()
+                    .filter(|errors| errors.is_empty())
+                    .count()
+                    != 1
+            ),
+        ),
+        MetaListValidation::Not => (
+            quote!(Not),
+            quote!(::serde_valid::NotErrorParams {}),
+            quote!(__composition_errors.iter().all(|errors| errors.is_empty())),
+        ),
+        _ => unreachable!("the composition is checked by the caller."),
+    };
+    let message = message_tokens(params, message_fn);
+
+    Ok(quote!({
+        let __composition_errors: Vec<::serde_valid::validation::VecErrors> = vec![
+            #({
+                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+                #validators
+                __properties_errors.shift_remove(#rename).unwrap_or_default()
+            }),*
+        ];
+
+        if #condition {
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || ::serde_valid::validation::Error::#error_variant(#message),
+            );
+        }
+    }))
+}
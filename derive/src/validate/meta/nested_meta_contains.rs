@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::meta_list::extract_validator_from_nested_meta;
+use crate::types::{EntryField, Field, SingleIdentPath};
+use crate::validate::common::{
+    get_numeric, message_tokens, MessageTemplateParams, MetaListValidation, MetaNameValueValidation,
+};
+use crate::validate::Validator;
+
+/// Check the nested meta is `contains(...)`, `min_contains = ...` or `max_contains = ...`.
+pub fn is_contains_meta(nested_meta: &syn::NestedMeta) -> bool {
+    let (meta, label) = match nested_meta {
+        syn::NestedMeta::Meta(meta) => match meta.path().get_ident() {
+            Some(ident) => (meta, ident.to_string()),
+            None => return false,
+        },
+        syn::NestedMeta::Lit(_) => return false,
+    };
+    match meta {
+        syn::Meta::List(_) => matches!(
+            MetaListValidation::from_str(&label),
+            Ok(MetaListValidation::Contains)
+        ),
+        syn::Meta::NameValue(_) => matches!(
+            MetaNameValueValidation::from_str(&label),
+            Ok(MetaNameValueValidation::MinContains | MetaNameValueValidation::MaxContains)
+        ),
+        syn::Meta::Path(_) => false,
+    }
+}
+
+/// `contains(...)` validator of the array items, with `min_contains` and `max_contains`.
+///
+/// The errors of the items are not reported, only the number of the matching items is.
+/// `message_fn` makes the message of each count by its template params,
+/// so that the template can use `{min_contains}` and `{max_contains}`.
+pub fn extract_contains_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    contains_metas: &[syn::Meta],
+    message_fn: impl Fn(MessageTemplateParams) -> Result<Option<TokenStream>, crate::Errors>,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let item_field = EntryField::new(field, "__item");
+
+    let mut errors = vec![];
+    let mut labels = vec![];
+    let mut validators = None;
+    let mut min_contains = None;
+    let mut max_contains = None;
+    for meta in contains_metas {
+        let path = meta.path();
+        let path_label = SingleIdentPath::new(path).ident().to_string();
+        if labels.contains(&path_label) {
+            errors.push(crate::Error::validate_contains_duplicated(
+                path,
+                &path_label,
+            ));
+        }
+        labels.push(path_label.clone());
+
+        match meta {
+            syn::Meta::List(syn::MetaList { nested, .. }) => {
+                if nested.is_empty() {
+                    errors.push(crate::Error::validate_contains_need_item(path));
+                }
+                let mut item_validators = vec![];
+                for nested_meta in nested {
+                    match extract_validator_from_nested_meta(
+                        &item_field,
+                        attribute,
+                        nested_meta,
+                        None,
+                        rename_map,
+                        context,
+                    ) {
+                        Ok(validator) => item_validators.push(validator),
+                        Err(validator_errors) => errors.extend(validator_errors),
+                    }
+                }
+                validators = Some(item_validators);
+            }
+            syn::Meta::NameValue(syn::MetaNameValue { lit, .. }) => match get_numeric(lit) {
+                Ok(count) => {
+                    if path_label == MetaNameValueValidation::MinContains.name() {
+                        min_contains = Some((path, count));
+                    } else {
+                        max_contains = Some((path, count));
+                    }
+                }
+                Err(count_errors) => errors.extend(count_errors),
+            },
+            syn::Meta::Path(_) => unreachable!("the contains are checked by `is_contains_meta`."),
+        }
+    }
+
+    let validators = match validators {
+        Some(validators) => validators,
+        None => {
+            for (path, _) in min_contains.iter().chain(max_contains.iter()) {
+                let label = path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                errors.push(crate::Error::validate_contains_need_contains(path, &label));
+            }
+            vec![]
+        }
+    };
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let template_params = |validation_name: &str| {
+        min_contains
+            .iter()
+            .map(|(_, count)| (MetaNameValueValidation::MinContains.name(), count))
+            .chain(
+                max_contains
+                    .iter()
+                    .map(|(_, count)| (MetaNameValueValidation::MaxContains.name(), count)),
+            )
+            .fold(
+                MessageTemplateParams::new(Some(validation_name), rename),
+                |params, (name, count)| params.with_value(name, quote!(#count)),
+            )
+    };
+    let push_error =
+        |variant: TokenStream, message_fn: Option<TokenStream>, result: TokenStream| {
+            let message = message_tokens(quote!(__error_params), message_fn);
+            quote!(
+                if let Err(__error_params) = #result {
+                    ::serde_valid::validation::PropertiesErrors::push_error(
+                        &mut __properties_errors,
+                        #rename,
+                        || ::serde_valid::validation::Error::#variant(#message),
+                    );
+                }
+            )
+        };
+    let min_contains_validator = match &min_contains {
+        Some((_, min_contains)) => push_error(
+            quote!(MinContains),
+            message_fn(template_params(MetaNameValueValidation::MinContains.name()))?,
+            quote!(::serde_valid::validation::validate_min_contains(
+                __contains_count,
+                #min_contains
+            )),
+        ),
+        None => push_error(
+            quote!(Contains),
+            message_fn(template_params(MetaListValidation::Contains.name()))?,
+            quote!(::serde_valid::validation::validate_contains(
+                __contains_count
+            )),
+        ),
+    };
+    let max_contains_validator = max_contains
+        .as_ref()
+        .map(|(_, max_contains)| {
+            Ok::<_, crate::Errors>(push_error(
+                quote!(MaxContains),
+                message_fn(template_params(MetaNameValueValidation::MaxContains.name()))?,
+                quote!(::serde_valid::validation::validate_max_contains(
+                    __contains_count,
+                    #max_contains
+                )),
+            ))
+        })
+        .transpose()?;
+
+    Ok(quote!(
+        if let Some(__contains_count) = ::serde_valid::validation::ValidateContains::count_contains(
+            #field_ident,
+            |__item| {
+                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+                #(#validators)*
+                __properties_errors.shift_remove(#rename).unwrap_or_default().is_empty()
+            },
+        ) {
+            #min_contains_validator
+            #max_contains_validator
+        }
+    ))
+}
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+
+use super::meta_list::extract_validator_from_nested_meta;
+use super::parse_meta::parse_validate_meta;
+use crate::types::{EntryField, Field, SingleIdentPath};
+use crate::validate::common::{
+    get_regex_str, message_tokens, MetaListValidation, MetaNameValueValidation,
+};
+use crate::validate::Validator;
+
+/// Check the nested meta is the validation of the map entries,
+/// such as `keys(...)`, `values(...)`, `pattern_properties(...)`,
+/// `allowed_keys(...)` or `additional_properties = false`.
+pub fn is_entries_meta(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(meta) => is_entries(meta),
+        _ => false,
+    }
+}
+
+fn is_entries(meta: &syn::Meta) -> bool {
+    let label = match meta.path().get_ident() {
+        Some(ident) => ident.to_string(),
+        None => return false,
+    };
+    match meta {
+        syn::Meta::List(_) => matches!(
+            MetaListValidation::from_str(&label),
+            Ok(MetaListValidation::Keys
+                | MetaListValidation::Values
+                | MetaListValidation::PatternProperties
+                | MetaListValidation::AllowedKeys)
+        ),
+        syn::Meta::NameValue(_) => matches!(
+            MetaNameValueValidation::from_str(&label),
+            Ok(MetaNameValueValidation::AdditionalProperties)
+        ),
+        syn::Meta::Path(_) => false,
+    }
+}
+
+/// Validators of the map entries, which push the errors into the entry of the key.
+pub fn extract_entries_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    entries_metas: &[syn::Meta],
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let key_field = EntryField::new(field, "__key");
+    let value_field = EntryField::new(field, "__value");
+
+    let mut errors = vec![];
+    let mut labels = vec![];
+    let mut validators = vec![];
+    let mut allowed_keys = None;
+    let mut additional_properties = None;
+    for meta in entries_metas {
+        let path = meta.path();
+        let path_label = SingleIdentPath::new(path).ident().to_string();
+        if labels.contains(&path_label) {
+            errors.push(crate::Error::validate_entries_duplicated(path, &path_label));
+        }
+        labels.push(path_label.clone());
+
+        let nested = match meta {
+            syn::Meta::List(syn::MetaList { nested, .. }) => nested,
+            syn::Meta::NameValue(syn::MetaNameValue { lit, .. }) => {
+                match lit {
+                    syn::Lit::Bool(syn::LitBool { value, .. }) => {
+                        additional_properties = Some(*value)
+                    }
+                    _ => errors
+                        .push(crate::Error::validate_additional_properties_allow_bool_only(lit)),
+                }
+                continue;
+            }
+            syn::Meta::Path(_) => unreachable!("the entries are checked by `is_entries_meta`."),
+        };
+        if nested.is_empty() {
+            errors.push(crate::Error::validate_entries_need_item(path, &path_label));
+        }
+
+        match MetaListValidation::from_str(&path_label) {
+            Ok(MetaListValidation::Keys | MetaListValidation::Values) => {
+                for nested_meta in nested {
+                    let validator = if path_label == MetaListValidation::Keys.name() {
+                        extract_validator_from_nested_meta(
+                            &key_field,
+                            attribute,
+                            nested_meta,
+                            message_fn.clone(),
+                            rename_map,
+                            context,
+                        )
+                    } else {
+                        extract_validator_from_nested_meta(
+                            &value_field,
+                            attribute,
+                            nested_meta,
+                            message_fn.clone(),
+                            rename_map,
+                            context,
+                        )
+                    };
+                    match validator {
+                        Ok(validator) => validators.push(validator),
+                        Err(validator_errors) => errors.extend(validator_errors),
+                    }
+                }
+            }
+            Ok(MetaListValidation::PatternProperties) => {
+                for (index, nested_meta) in nested.iter().enumerate() {
+                    let (pattern, pattern_validations) = match extract_pattern_property(nested_meta)
+                    {
+                        Ok(pattern_property) => pattern_property,
+                        Err(pattern_errors) => {
+                            errors.extend(pattern_errors);
+                            continue;
+                        }
+                    };
+                    let mut pattern_validators = vec![];
+                    for validation in pattern_validations {
+                        match extract_validator_from_nested_meta(
+                            &value_field,
+                            attribute,
+                            validation,
+                            message_fn.clone(),
+                            rename_map,
+                            context,
+                        ) {
+                            Ok(validator) => pattern_validators.push(validator),
+                            Err(validator_errors) => errors.extend(validator_errors),
+                        }
+                    }
+                    let pattern_ident =
+                        syn::Ident::new(&format!("__PATTERN_PROPERTIES_{index}"), pattern.span());
+                    validators.push(quote!(
+                        static #pattern_ident: ::once_cell::sync::OnceCell<::regex::Regex> =
+                            ::once_cell::sync::OnceCell::new();
+                        if #pattern_ident
+                            .get_or_init(|| ::regex::Regex::new(#pattern).unwrap())
+                            .is_match(&::std::string::ToString::to_string(__key))
+                        {
+                            #(#pattern_validators)*
+                        }
+                    ));
+                }
+            }
+            Ok(MetaListValidation::AllowedKeys) => {
+                let mut keys = vec![];
+                for nested_meta in nested {
+                    match nested_meta {
+                        syn::NestedMeta::Lit(syn::Lit::Str(key)) => keys.push(key.clone()),
+                        _ => errors.push(crate::Error::validate_allowed_keys_allow_str_only(
+                            nested_meta,
+                        )),
+                    }
+                }
+                allowed_keys = Some((path, keys));
+            }
+            _ => unreachable!("the entries are checked by `is_entries_meta`."),
+        }
+    }
+
+    match (additional_properties, allowed_keys) {
+        (Some(false), allowed_keys) => {
+            let allowed_keys = allowed_keys.map(|(_, keys)| keys).unwrap_or_default();
+            let patterns = field_pattern_properties(field);
+            let message =
+                message_tokens(quote!(::serde_valid::NotAllowedErrorParams {}), message_fn);
+            validators.push(quote!(
+                static __ADDITIONAL_PROPERTIES_PATTERNS: ::once_cell::sync::OnceCell<::regex::RegexSet> =
+                    ::once_cell::sync::OnceCell::new();
+                let __key_name = ::std::string::ToString::to_string(__key);
+                if ![#(#allowed_keys),*].contains(&__key_name.as_str())
+                    && !__ADDITIONAL_PROPERTIES_PATTERNS
+                        .get_or_init(|| ::regex::RegexSet::new::<_, &str>([#(#patterns),*]).unwrap())
+                        .is_match(&__key_name)
+                {
+                    ::serde_valid::validation::PropertiesErrors::push_error(
+                        &mut __properties_errors,
+                        #rename,
+                        || ::serde_valid::validation::Error::NotAllowed(#message),
+                    );
+                }
+            ));
+        }
+        (_, Some((path, _))) => {
+            errors.push(crate::Error::validate_allowed_keys_need_additional_properties(path))
+        }
+        (_, None) => {}
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(quote!(
+        if let Err(__object_errors) = ::serde_valid::validation::ValidateEntries::validate_entries(
+            #field_ident,
+            |__key, __value| {
+                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+                #(#validators)*
+                __properties_errors.shift_remove(#rename).unwrap_or_default()
+            },
+        ) {
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || ::serde_valid::validation::Error::Properties(__object_errors),
+            );
+        }
+    ))
+}
+
+/// The pattern and the validations of the `pattern_properties` item,
+/// which is rewritten into `pattern("^x-", custom(f))` by [`parse_validate_meta`].
+pub fn extract_pattern_property(
+    nested_meta: &syn::NestedMeta,
+) -> Result<(&syn::LitStr, Vec<&syn::NestedMeta>), crate::Errors> {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. }))
+            if path.is_ident("pattern") && nested.len() >= 2 =>
+        {
+            let mut items = nested.iter();
+            let pattern = match items.next() {
+                Some(syn::NestedMeta::Lit(lit)) => get_regex_str(lit)?,
+                _ => Err(vec![crate::Error::validate_pattern_properties_item_error(
+                    nested_meta,
+                )])?,
+            };
+            Ok((pattern, items.collect()))
+        }
+        _ => Err(vec![crate::Error::validate_pattern_properties_item_error(
+            nested_meta,
+        )]),
+    }
+}
+
+/// The patterns of `pattern_properties(...)` in all `#[validate(...)]` of the field.
+///
+/// They are not additional properties as same as JSON Schema.
+fn field_pattern_properties(field: &impl Field) -> Vec<syn::LitStr> {
+    field
+        .attrs()
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(validate))
+        .filter_map(|attribute| match parse_validate_meta(attribute) {
+            Ok(syn::Meta::List(syn::MetaList { nested, .. })) => Some(nested),
+            _ => None,
+        })
+        .flat_map(|nested| nested.into_iter())
+        .filter_map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. }))
+                if path.is_ident(MetaListValidation::PatternProperties.name()) =>
+            {
+                Some(nested)
+            }
+            _ => None,
+        })
+        .flat_map(|nested| nested.into_iter())
+        .filter_map(|nested_meta| {
+            extract_pattern_property(&nested_meta)
+                .ok()
+                .map(|(pattern, _)| pattern.clone())
+        })
+        .collect()
+}
@@ -1,3 +1,7 @@
+use super::nested_meta_composition::extract_composition_validator;
+use super::nested_meta_contains::extract_contains_validator;
+use super::nested_meta_entries::extract_entries_validator;
+use super::nested_meta_prefix_items::extract_prefix_items_validator;
 use crate::types::{Field, SingleIdentPath};
 use crate::validate::common::MetaListValidation;
 use crate::validate::generic::{
@@ -5,14 +9,17 @@ use crate::validate::generic::{
 };
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
+use quote::quote;
 use std::collections::HashMap;
 use std::str::FromStr;
 
 pub fn extract_validator_from_nested_meta_list(
     field: &impl Field,
+    attribute: &syn::Attribute,
     validation_list: &syn::MetaList,
     message_fn: Option<TokenStream>,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
     let syn::MetaList {
         path: validation_name,
@@ -25,8 +32,53 @@ pub fn extract_validator_from_nested_meta_list(
             extract_generic_enumerate_validator(field, validation_list, message_fn, rename_map)
         }
         Ok(MetaListValidation::Custom) => {
-            extract_generic_custom_validator(field, validation_list, rename_map)
+            extract_generic_custom_validator(field, validation_list, rename_map, context)
         }
+        // `custom_async` is awaited by `#[derive(AsyncValidate)]`.
+        Ok(MetaListValidation::CustomAsync) => Ok(quote!()),
+        Ok(
+            composition @ (MetaListValidation::AllOf
+            | MetaListValidation::AnyOf
+            | MetaListValidation::OneOf
+            | MetaListValidation::Not),
+        ) => extract_composition_validator(
+            field,
+            attribute,
+            validation_list,
+            composition,
+            message_fn,
+            rename_map,
+            context,
+        ),
+        Ok(
+            MetaListValidation::Keys
+            | MetaListValidation::Values
+            | MetaListValidation::PatternProperties
+            | MetaListValidation::AllowedKeys,
+        ) => extract_entries_validator(
+            field,
+            attribute,
+            &[syn::Meta::List(validation_list.clone())],
+            message_fn,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::Contains) => extract_contains_validator(
+            field,
+            attribute,
+            &[syn::Meta::List(validation_list.clone())],
+            |_| Ok(message_fn.clone()),
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::PrefixItems) => extract_prefix_items_validator(
+            field,
+            attribute,
+            validation_list,
+            message_fn,
+            rename_map,
+            context,
+        ),
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
             &unknown,
@@ -1,19 +1,26 @@
+use super::nested_meta_contains::extract_contains_validator;
+use super::nested_meta_entries::extract_entries_validator;
 use crate::types::{Field, SingleIdentPath};
 use crate::validate::array::{
     extract_array_max_items_validator, extract_array_min_items_validator,
 };
 use crate::validate::common::MetaNameValueValidation;
+use crate::validate::generic::{
+    extract_generic_const_value_validator, extract_generic_eq_field_validator,
+};
 use crate::validate::numeric::{
-    extract_numeric_exclusive_maximum_validator, extract_numeric_exclusive_minimum_validator,
-    extract_numeric_maximum_validator, extract_numeric_minimum_validator,
+    extract_numeric_exclusive_maximum_field_validator, extract_numeric_exclusive_maximum_validator,
+    extract_numeric_exclusive_minimum_field_validator, extract_numeric_exclusive_minimum_validator,
+    extract_numeric_maximum_field_validator, extract_numeric_maximum_validator,
+    extract_numeric_minimum_field_validator, extract_numeric_minimum_validator,
     extract_numeric_multiple_of_validator,
 };
 use crate::validate::object::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
 use crate::validate::string::{
-    extract_string_max_length_validator, extract_string_min_length_validator,
-    extract_string_pattern_validator,
+    extract_string_format_validator, extract_string_max_length_validator,
+    extract_string_min_length_validator, extract_string_pattern_validator,
 };
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
@@ -22,15 +29,17 @@ use std::str::FromStr;
 
 pub fn extract_validator_from_nested_meta_name_value(
     field: &impl Field,
-    _attribute: &syn::Attribute,
-    syn::MetaNameValue {
-        path: validation_name,
-        lit: validation_value,
-        ..
-    }: &syn::MetaNameValue,
+    attribute: &syn::Attribute,
+    name_value: &syn::MetaNameValue,
     message_fn: Option<TokenStream>,
     rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
+    let syn::MetaNameValue {
+        path: validation_name,
+        lit: validation_value,
+        ..
+    } = name_value;
     let validation_name_ident = SingleIdentPath::new(validation_name).ident();
     match MetaNameValueValidation::from_str(&validation_name_ident.to_string()) {
         Ok(MetaNameValueValidation::Minimum) => {
@@ -55,6 +64,28 @@ pub fn extract_validator_from_nested_meta_name_value(
                 rename_map,
             )
         }
+        Ok(MetaNameValueValidation::MinimumField) => {
+            extract_numeric_minimum_field_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::MaximumField) => {
+            extract_numeric_maximum_field_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::ExclusiveMinimumField) => {
+            extract_numeric_exclusive_minimum_field_validator(
+                field,
+                validation_value,
+                message_fn,
+                rename_map,
+            )
+        }
+        Ok(MetaNameValueValidation::ExclusiveMaximumField) => {
+            extract_numeric_exclusive_maximum_field_validator(
+                field,
+                validation_value,
+                message_fn,
+                rename_map,
+            )
+        }
         Ok(MetaNameValueValidation::MinLength) => {
             extract_string_min_length_validator(field, validation_value, message_fn, rename_map)
         }
@@ -79,6 +110,33 @@ pub fn extract_validator_from_nested_meta_name_value(
         Ok(MetaNameValueValidation::Pattern) => {
             extract_string_pattern_validator(field, validation_value, message_fn, rename_map)
         }
+        Ok(MetaNameValueValidation::Format) => {
+            extract_string_format_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::ConstValue) => {
+            extract_generic_const_value_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::EqField) => {
+            extract_generic_eq_field_validator(field, validation_value, message_fn, rename_map)
+        }
+        Ok(MetaNameValueValidation::AdditionalProperties) => extract_entries_validator(
+            field,
+            attribute,
+            &[syn::Meta::NameValue(name_value.clone())],
+            message_fn,
+            rename_map,
+            context,
+        ),
+        Ok(MetaNameValueValidation::MinContains | MetaNameValueValidation::MaxContains) => {
+            extract_contains_validator(
+                field,
+                attribute,
+                &[syn::Meta::NameValue(name_value.clone())],
+                |_| Ok(message_fn.clone()),
+                rename_map,
+                context,
+            )
+        }
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
             &unknown,
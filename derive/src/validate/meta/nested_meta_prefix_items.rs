@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use super::meta_list::extract_validator_from_nested_meta;
+use crate::types::{EntryField, Field};
+use crate::validate::Validator;
+
+/// `prefix_items(0(...), 1(...))` validator of the items at each position.
+///
+/// The errors are reported at the index of the items.
+pub fn extract_prefix_items_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let item_field = EntryField::new(field, "__prefix_item");
+
+    if nested.is_empty() {
+        return Err(vec![crate::Error::validate_prefix_items_need_item(path)]);
+    }
+
+    let mut errors = vec![];
+    let mut indexes = vec![];
+    let mut item_validators = vec![];
+    for nested_meta in nested {
+        let (index, validations) = match extract_prefix_item(nested_meta) {
+            Ok(prefix_item) => prefix_item,
+            Err(item_errors) => {
+                errors.extend(item_errors);
+                continue;
+            }
+        };
+        let index_value = match index.base10_parse::<usize>() {
+            Ok(index_value) => index_value,
+            Err(_) => {
+                errors.push(crate::Error::validate_prefix_items_item_error(nested_meta));
+                continue;
+            }
+        };
+        if indexes.contains(&index_value) {
+            errors.push(crate::Error::validate_prefix_items_duplicated(index));
+        }
+        indexes.push(index_value);
+
+        let mut validators = vec![];
+        for validation in validations {
+            match extract_validator_from_nested_meta(
+                &item_field,
+                attribute,
+                validation,
+                message_fn.clone(),
+                rename_map,
+                context,
+            ) {
+                Ok(validator) => validators.push(validator),
+                Err(validator_errors) => errors.extend(validator_errors),
+            }
+        }
+
+        let index = Literal::usize_unsuffixed(index_value);
+        item_validators.push(quote!(
+            if let Some(__prefix_item) =
+                ::serde_valid::validation::PrefixItem::<#index>::prefix_item(#field_ident)
+            {
+                let mut __properties_errors = ::serde_valid::validation::MapErrors::new();
+                #(#validators)*
+                let __item_errors = __properties_errors.shift_remove(#rename).unwrap_or_default();
+                if !__item_errors.is_empty() {
+                    __prefix_items_errors.items.insert(
+                        #index,
+                        ::serde_valid::validation::Errors::NewType(__item_errors),
+                    );
+                }
+            }
+        ));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(quote!({
+        let mut __prefix_items_errors =
+            ::serde_valid::validation::ArrayErrors::new(vec![], Default::default());
+        #(#item_validators)*
+        if !__prefix_items_errors.items.is_empty() {
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || ::serde_valid::validation::Error::Items(__prefix_items_errors),
+            );
+        }
+    }))
+}
+
+/// The index and the validations of the `prefix_items` item,
+/// which is rewritten into `item(0, max_length = 5)` by `parse_validate_meta`.
+pub fn extract_prefix_item(
+    nested_meta: &syn::NestedMeta,
+) -> Result<(&syn::LitInt, Vec<&syn::NestedMeta>), crate::Errors> {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. }))
+            if path.is_ident("item") && nested.len() >= 2 =>
+        {
+            let mut items = nested.iter();
+            match items.next() {
+                Some(syn::NestedMeta::Lit(syn::Lit::Int(index))) => Ok((index, items.collect())),
+                _ => Err(vec![crate::Error::validate_prefix_items_item_error(
+                    nested_meta,
+                )]),
+            }
+        }
+        _ => Err(vec![crate::Error::validate_prefix_items_item_error(
+            nested_meta,
+        )]),
+    }
+}
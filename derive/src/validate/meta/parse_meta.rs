@@ -0,0 +1,153 @@
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::validate::common::expr_literal;
+
+/// Parse `#[validate(...)]` as same as [`syn::Attribute::parse_meta`].
+///
+/// The items of `pattern_properties("^x-" = custom(f))` and `prefix_items(0(max_length = 5))`
+/// are not the metas of syn, so they are rewritten into
+/// `pattern_properties(pattern("^x-", custom(f)))` and `prefix_items(item(0, max_length = 5))`
+/// before parsing.
+///
+/// The expressions like `max_length = MAX_LENGTH` and `enumerate(Self::A, Self::B)`
+/// are rewritten into the str literals by [`expr_literal`] as well.
+pub fn parse_validate_meta(attribute: &syn::Attribute) -> syn::Result<syn::Meta> {
+    let path = &attribute.path;
+    let tokens = rewrite_meta_tokens(attribute.tokens.clone());
+
+    syn::parse2(quote!(#path #tokens))
+}
+
+fn rewrite_meta_tokens(tokens: TokenStream) -> TokenStream {
+    let mut list_name = None;
+    let mut rewritten = vec![];
+    for token in tokens {
+        let token = match token {
+            TokenTree::Group(group) => {
+                let stream = match (list_name.as_deref(), group.delimiter()) {
+                    (Some("pattern_properties"), Delimiter::Parenthesis) => {
+                        rewrite_items(group.stream(), rewrite_pattern_item)
+                    }
+                    (Some("prefix_items"), Delimiter::Parenthesis) => {
+                        rewrite_items(group.stream(), rewrite_prefix_item)
+                    }
+                    (Some("enumerate"), Delimiter::Parenthesis) => {
+                        rewrite_items(group.stream(), rewrite_enumerate_item)
+                    }
+                    _ => rewrite_meta_tokens(group.stream()),
+                };
+                let mut rewritten_group = Group::new(group.delimiter(), stream);
+                rewritten_group.set_span(group.span());
+                TokenTree::Group(rewritten_group)
+            }
+            token => token,
+        };
+        list_name = match &token {
+            TokenTree::Ident(ident) => Some(ident.to_string()),
+            _ => None,
+        };
+        rewritten.push(token);
+    }
+
+    rewrite_name_value_exprs(rewritten)
+}
+
+/// `max_length = MAX_LENGTH` into `max_length = "MAX_LENGTH"__serde_valid_expr`.
+fn rewrite_name_value_exprs(tokens: Vec<TokenTree>) -> TokenStream {
+    let mut rewritten = vec![];
+    for item in tokens.split_inclusive(is_comma) {
+        let (item, comma) = match item.split_last() {
+            Some((last, item)) if is_comma(last) => (item, Some(last)),
+            _ => (item, None),
+        };
+        match item {
+            [TokenTree::Ident(name), TokenTree::Punct(eq), value @ ..]
+                if eq.as_char() == '=' && !value.is_empty() && !is_literal_value(value) =>
+            {
+                rewritten.extend([
+                    TokenTree::Ident(name.clone()),
+                    TokenTree::Punct(eq.clone()),
+                    expr_literal(value),
+                ]);
+            }
+            _ => rewritten.extend(item.iter().cloned()),
+        }
+        rewritten.extend(comma.cloned());
+    }
+
+    TokenStream::from_iter(rewritten)
+}
+
+fn is_comma(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Punct(punct) if punct.as_char() == ',')
+}
+
+/// The value which [`syn::Lit`] can parse, like `5`, `-5`, `"^x-"` and `false`.
+fn is_literal_value(value: &[TokenTree]) -> bool {
+    match value {
+        [TokenTree::Literal(_)] => true,
+        [TokenTree::Punct(minus), TokenTree::Literal(_)] => minus.as_char() == '-',
+        [TokenTree::Ident(ident)] => ident == "true" || ident == "false",
+        _ => false,
+    }
+}
+
+fn rewrite_items(
+    tokens: TokenStream,
+    rewrite_item: fn(&[TokenTree]) -> Option<TokenStream>,
+) -> TokenStream {
+    let mut items = vec![vec![]];
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => items.push(vec![]),
+            _ => items.last_mut().unwrap().push(token),
+        }
+    }
+
+    let items = items
+        .into_iter()
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            rewrite_item(&item).unwrap_or_else(|| rewrite_meta_tokens(TokenStream::from_iter(item)))
+        });
+
+    quote!(#(#items),*)
+}
+
+/// `"^x-" = custom(f)` into `pattern("^x-", custom(f))`.
+fn rewrite_pattern_item(item: &[TokenTree]) -> Option<TokenStream> {
+    match item {
+        [TokenTree::Literal(pattern), TokenTree::Punct(eq), validation @ ..]
+            if eq.as_char() == '=' && !validation.is_empty() =>
+        {
+            let ident = Ident::new("pattern", pattern.span());
+            let validation = rewrite_meta_tokens(TokenStream::from_iter(validation.to_vec()));
+            Some(quote!(#ident(#pattern, #validation)))
+        }
+        _ => None,
+    }
+}
+
+/// `0(max_length = 5)` into `item(0, max_length = 5)`.
+fn rewrite_prefix_item(item: &[TokenTree]) -> Option<TokenStream> {
+    match item {
+        [TokenTree::Literal(index), TokenTree::Group(validations)]
+            if validations.delimiter() == Delimiter::Parenthesis =>
+        {
+            let ident = Ident::new("item", index.span());
+            let validations = rewrite_meta_tokens(validations.stream());
+            Some(quote!(#ident(#index, #validations)))
+        }
+        _ => None,
+    }
+}
+
+/// `Self::A` into `"Self::A"__serde_valid_expr`, keeping the literals.
+fn rewrite_enumerate_item(item: &[TokenTree]) -> Option<TokenStream> {
+    if is_literal_value(item) {
+        None
+    } else {
+        Some(TokenStream::from(expr_literal(item)))
+    }
+}
@@ -1,8 +1,14 @@
 mod multiple_of;
 mod range;
+mod range_field;
 
 pub use multiple_of::extract_numeric_multiple_of_validator;
 pub use range::{
     extract_numeric_exclusive_maximum_validator, extract_numeric_exclusive_minimum_validator,
     extract_numeric_maximum_validator, extract_numeric_minimum_validator,
 };
+pub use range_field::{
+    extract_numeric_exclusive_maximum_field_validator,
+    extract_numeric_exclusive_minimum_field_validator, extract_numeric_maximum_field_validator,
+    extract_numeric_minimum_field_validator,
+};
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::{get_numeric, into_error_tokens};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -30,22 +30,19 @@ fn inner_extract_numeric_multiple_of_validator(
     let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(field_name);
     let multiple_of = get_numeric(validation_value)?;
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::MultipleOfErrorParams::to_default_message
-    ));
+    let into_error = into_error_tokens(message_fn);
 
     Ok(quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedMultipleOf::validate_composited_multiple_of(
             #field_ident,
             #multiple_of,
         ) {
-            use ::serde_valid::error::ToDefaultMessage;
             use ::serde_valid::validation::IntoError;
 
-            __properties_errors
-                .entry(#rename)
-                .or_default()
-                .push(__composited_error_params.into_error_by(#message)
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || __composited_error_params.#into_error,
             );
         }
     ))
@@ -0,0 +1,59 @@
+use crate::types::Field;
+use crate::validate::common::{get_field_reference, into_error_tokens};
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use std::collections::HashMap;
+
+/// Range validation by the value of the other field, like `minimum_field = start`.
+///
+/// The errors are the same as the range validation by the literal,
+/// and the validation is skipped when the other field is `None`.
+macro_rules! extract_numeric_range_field_validator{
+    ($ErrorType:ident) => {
+        paste::paste! {
+            pub fn [<extract_numeric_ $ErrorType:snake _field_validator>](
+                field: &impl Field,
+                validation_value: &syn::Lit,
+                message_fn: Option<TokenStream>,
+                rename_map: &HashMap<String, String>,
+            ) -> Result<Validator, crate::Errors> {
+                let field_name = field.name();
+                let field_ident = field.ident();
+                let rename = rename_map.get(field_name).unwrap_or(field_name);
+                let other_field_ident = get_field_reference(validation_value)?;
+                let into_error = into_error_tokens(message_fn);
+
+                // The type errors of the fields are reported at the referenced field.
+                let field_limit = quote_spanned!(other_field_ident.span()=>
+                    ::serde_valid::validation::FieldLimit::field_limit(#other_field_ident)
+                );
+                let validate = quote_spanned!(other_field_ident.span()=>
+                    ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
+                        #field_ident,
+                        __field_limit,
+                    )
+                );
+
+                Ok(quote!(
+                    if let Some(__field_limit) = #field_limit {
+                        if let Err(__composited_error_params) = #validate {
+                            use ::serde_valid::validation::IntoError;
+
+                            ::serde_valid::validation::PropertiesErrors::push_error(
+                                &mut __properties_errors,
+                                #rename,
+                                || __composited_error_params.#into_error,
+                            );
+                        }
+                    }
+                ))
+            }
+        }
+    }
+}
+
+extract_numeric_range_field_validator!(Maximum);
+extract_numeric_range_field_validator!(Minimum);
+extract_numeric_range_field_validator!(ExclusiveMaximum);
+extract_numeric_range_field_validator!(ExclusiveMinimum);
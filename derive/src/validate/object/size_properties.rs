@@ -1,5 +1,5 @@
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::{get_numeric, into_error_tokens};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -30,21 +30,19 @@ macro_rules! extract_object_size_validator {
                 let field_ident = field.ident();
                 let rename = rename_map.get(field_name).unwrap_or(field_name);
                 let [<$ErrorType:snake>] = get_numeric(validation_value)?;
-                let message =
-                    message_fn.unwrap_or(quote!(::serde_valid::[<$ErrorType ErrorParams>]::to_default_message));
+                let into_error = into_error_tokens(message_fn);
 
                 Ok(quote!(
                     if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
                         #field_ident,
                         #[<$ErrorType:snake>]
                     ) {
-                        use ::serde_valid::error::ToDefaultMessage;
                         use ::serde_valid::validation::IntoError;
 
-                        __properties_errors
-                            .entry(#rename)
-                            .or_default()
-                            .push(__composited_error_params.into_error_by(#message)
+                        ::serde_valid::validation::PropertiesErrors::push_error(
+                            &mut __properties_errors,
+                            #rename,
+                            || __composited_error_params.#into_error,
                         );
                     }
                 ))
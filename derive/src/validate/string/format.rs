@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use crate::{
+    types::Field,
+    validate::{
+        common::{get_str, into_error_tokens},
+        Validator,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_valid_literal::Format;
+use std::str::FromStr;
+
+pub fn extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_string_format_validator(field, validation_value, message_fn, rename_map)
+}
+
+fn inner_extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    message_fn: Option<TokenStream>,
+    rename_map: &HashMap<String, String>,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let rename = rename_map.get(field_name).unwrap_or(field_name);
+    let format = get_str(validation_value)?;
+    let format_variant = match Format::from_str(&format.value()) {
+        Ok(format_value) => syn::Ident::new(&format!("{format_value:?}"), format.span()),
+        Err(_) => Err(vec![crate::Error::validate_unknown_format(
+            format,
+            &Format::ALL.map(|format| format.name()),
+        )])?,
+    };
+    let into_error = into_error_tokens(message_fn);
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedFormat::validate_composited_format(
+            #field_ident,
+            ::serde_valid::validation::Format::#format_variant,
+        ) {
+            use ::serde_valid::validation::IntoError;
+
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || __composited_error_params.#into_error,
+            );
+        }
+    ))
+}
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use crate::{
     types::Field,
-    validate::{common::get_str, Validator},
+    validate::{
+        common::{get_expr, get_regex_str, into_error_tokens},
+        Validator,
+    },
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -30,31 +33,54 @@ fn inner_extract_string_pattern_validator(
     let field_name = field.name();
     let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(field_name);
-    let pattern = get_str(validation_value)?;
-    let message = message_fn.unwrap_or(quote!(
-        ::serde_valid::PatternErrorParams::to_default_message
-    ));
+    let into_error = into_error_tokens(message_fn);
     let pattern_ident = syn::Ident::new(
-        &format!("{}_PATTERN", &field_ident).to_uppercase(),
+        &format!("__{}_PATTERN", &field_ident).to_uppercase(),
         field_ident.span(),
     );
-
-    Ok(quote!(
-        static #pattern_ident : ::once_cell::sync::OnceCell<::regex::Regex> = ::once_cell::sync::OnceCell::new();
-        let __pattern = #pattern_ident.get_or_init(|| ::regex::Regex::new(#pattern).unwrap());
+    let validate = quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedPattern::validate_composited_pattern(
             #field_ident,
             __pattern,
         ) {
 
-            use ::serde_valid::error::ToDefaultMessage;
             use ::serde_valid::validation::IntoError;
 
-            __properties_errors
-                .entry(#rename)
-                .or_default()
-                .push(__composited_error_params.into_error_by(#message)
+            ::serde_valid::validation::PropertiesErrors::push_error(
+                &mut __properties_errors,
+                #rename,
+                || __composited_error_params.#into_error,
             );
         }
-    ))
+    );
+
+    Ok(match get_expr(validation_value) {
+        // The pattern of the expression is not known until it is compiled at the first validation,
+        // so the invalid pattern is reported as the error instead of the panic.
+        Some(pattern) => quote!(
+            static #pattern_ident : ::once_cell::sync::OnceCell<std::result::Result<::regex::Regex, ::regex::Error>> = ::once_cell::sync::OnceCell::new();
+            match #pattern_ident.get_or_init(|| ::regex::Regex::new(#pattern)) {
+                Ok(__pattern) => {
+                    #validate
+                }
+                Err(__pattern_error) => {
+                    ::serde_valid::validation::PropertiesErrors::push_error(
+                        &mut __properties_errors,
+                        #rename,
+                        || ::serde_valid::validation::Error::Custom(
+                            format!("the pattern is invalid: {}", __pattern_error)
+                        ),
+                    );
+                }
+            }
+        ),
+        None => {
+            let pattern = get_regex_str(validation_value)?;
+            quote!(
+                static #pattern_ident : ::once_cell::sync::OnceCell<::regex::Regex> = ::once_cell::sync::OnceCell::new();
+                let __pattern = #pattern_ident.get_or_init(|| ::regex::Regex::new(#pattern).unwrap());
+                #validate
+            )
+        }
+    })
 }
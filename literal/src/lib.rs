@@ -1,7 +1,9 @@
+mod format;
 mod literal;
 mod number;
 mod pattern;
 
+pub use format::Format;
 pub use literal::Literal;
 pub use number::Number;
 pub use pattern::Pattern;
@@ -1,23 +1,41 @@
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct Pattern(String);
 
-macro_rules! impl_from_trait {
-    ($type:ty) => {
-        impl From<$type> for Pattern {
-            fn from(item: $type) -> Self {
-                Self(format!("{:?}", item))
-            }
-        }
-    };
+impl From<regex::Regex> for Pattern {
+    fn from(item: regex::Regex) -> Self {
+        Self(item.as_str().to_owned())
+    }
+}
+
+impl From<&regex::Regex> for Pattern {
+    fn from(item: &regex::Regex) -> Self {
+        Self(item.as_str().to_owned())
+    }
+}
+
+impl From<String> for Pattern {
+    fn from(item: String) -> Self {
+        Self(item)
+    }
 }
 
-impl_from_trait!(regex::Regex);
-impl_from_trait!(&regex::Regex);
-impl_from_trait!(String);
-impl_from_trait!(&str);
+impl From<&str> for Pattern {
+    fn from(item: &str) -> Self {
+        Self(item.to_owned())
+    }
+}
 
 impl std::fmt::Display for Pattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:}", self.0)
     }
 }
+
+impl serde::Serialize for Pattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
@@ -2,13 +2,17 @@ mod generic;
 mod message;
 mod params;
 
-pub use generic::EnumerateErrorParams;
-pub use message::{Message, ToDefaultMessage};
+pub use generic::{
+    AnyOfErrorParams, ConstErrorParams, EnumerateErrorParams, EqFieldErrorParams, NotErrorParams,
+    OneOfErrorParams, TypeErrorParams,
+};
+pub use message::{Message, MessageFn, ToDefaultMessage};
 pub use params::{
-    ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams, MaxItemsErrorParams,
-    MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams, MinItemsErrorParams,
+    ContainsErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
+    FormatErrorParams, MaxContainsErrorParams, MaxItemsErrorParams, MaxLengthErrorParams,
+    MaxPropertiesErrorParams, MaximumErrorParams, MinContainsErrorParams, MinItemsErrorParams,
     MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams,
-    PatternErrorParams, UniqueItemsErrorParams,
+    NotAllowedErrorParams, PatternErrorParams, RequiredErrorParams, UniqueItemsErrorParams,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -1,2 +1,10 @@
+mod composition;
+mod const_value;
 mod enumerate;
+mod eq_field;
+mod types;
+pub use composition::{AnyOfErrorParams, NotErrorParams, OneOfErrorParams};
+pub use const_value::ConstErrorParams;
 pub use enumerate::EnumerateErrorParams;
+pub use eq_field::EqFieldErrorParams;
+pub use types::TypeErrorParams;
@@ -0,0 +1,60 @@
+use crate::{error::ToDefaultMessage, validation::VecErrors};
+
+/// Parameters of `any_of`, with the errors of every schema.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AnyOfErrorParams {
+    pub errors: Vec<VecErrors>,
+}
+
+impl AnyOfErrorParams {
+    pub fn new(errors: Vec<VecErrors>) -> Self {
+        Self { errors }
+    }
+}
+
+impl ToDefaultMessage for AnyOfErrorParams {
+    fn to_default_message(&self) -> String {
+        "the value must match at least one of the schemas.".to_string()
+    }
+}
+
+/// Parameters of `one_of`, with the errors of every schema.
+///
+/// The errors of the matched schemas are empty.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct OneOfErrorParams {
+    pub errors: Vec<VecErrors>,
+}
+
+impl OneOfErrorParams {
+    pub fn new(errors: Vec<VecErrors>) -> Self {
+        Self { errors }
+    }
+
+    /// Count of the matched schemas.
+    pub fn valid_count(&self) -> usize {
+        self.errors
+            .iter()
+            .filter(|errors| errors.is_empty())
+            .count()
+    }
+}
+
+impl ToDefaultMessage for OneOfErrorParams {
+    fn to_default_message(&self) -> String {
+        format!(
+            "the value must match exactly one of the schemas, but matched {}.",
+            self.valid_count()
+        )
+    }
+}
+
+/// Parameters of `not`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NotErrorParams {}
+
+impl ToDefaultMessage for NotErrorParams {
+    fn to_default_message(&self) -> String {
+        "the value must not match the schema.".to_string()
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{error::ToDefaultMessage, validation::Literal};
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConstErrorParams {
+    pub const_value: Literal,
+}
+
+impl ConstErrorParams {
+    pub fn new<T>(const_value: T) -> Self
+    where
+        T: Into<Literal>,
+    {
+        Self {
+            const_value: const_value.into(),
+        }
+    }
+}
+
+impl ToDefaultMessage for ConstErrorParams {
+    fn to_default_message(&self) -> String {
+        format!("the value must be exactly `{}`.", self.const_value)
+    }
+}
@@ -2,7 +2,7 @@ use itertools::Itertools;
 
 use crate::{error::ToDefaultMessage, validation::Literal};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct EnumerateErrorParams {
     pub enumerate: Vec<Literal>,
 }
@@ -0,0 +1,18 @@
+use crate::error::ToDefaultMessage;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EqFieldErrorParams {
+    pub eq_field: &'static str,
+}
+
+impl EqFieldErrorParams {
+    pub fn new(eq_field: &'static str) -> Self {
+        Self { eq_field }
+    }
+}
+
+impl ToDefaultMessage for EqFieldErrorParams {
+    fn to_default_message(&self) -> String {
+        format!("the value must be equal to the `{}` field.", self.eq_field)
+    }
+}
@@ -0,0 +1,25 @@
+use itertools::Itertools;
+
+use crate::error::ToDefaultMessage;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TypeErrorParams {
+    pub types: Vec<&'static str>,
+}
+
+impl TypeErrorParams {
+    pub fn new(types: &[&'static str]) -> Self {
+        Self {
+            types: types.to_vec(),
+        }
+    }
+}
+
+impl ToDefaultMessage for TypeErrorParams {
+    fn to_default_message(&self) -> String {
+        format!(
+            "the type of the value must be {}.",
+            self.types.iter().map(|t| format!("`{}`", t)).join(" or ")
+        )
+    }
+}
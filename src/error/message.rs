@@ -8,26 +8,107 @@ impl ToDefaultMessage for String {
     }
 }
 
+/// Function to format the message of the error parameters.
+///
+/// Unlike `fn(&Params) -> String`, it can capture the configuration, like the locale.
+pub type MessageFn<Params> = std::sync::Arc<dyn Fn(&Params) -> String + Send + Sync>;
+
+#[derive(Clone)]
+enum MessageFormat<Params> {
+    /// [`ToDefaultMessage::to_default_message`], which needs no allocation.
+    Default(fn(&Params) -> String),
+    /// The custom message, like `message_fn`, the message template or the closure.
+    Custom(MessageFn<Params>),
+}
+
 #[derive(Clone)]
 pub struct Message<Params>
 where
     Params: ToDefaultMessage,
 {
     params: Params,
-    format_fn: for<'a> fn(&'a Params) -> String,
+    format: MessageFormat<Params>,
+    key: Option<&'static str>,
 }
 
 impl<Params> Message<Params>
 where
     Params: ToDefaultMessage,
 {
-    pub fn new(params: Params, format_fn: fn(&Params) -> String) -> Self {
-        Self { params, format_fn }
+    pub fn new<F>(params: Params, format_fn: F) -> Self
+    where
+        F: Fn(&Params) -> String + Send + Sync + 'static,
+    {
+        Self::with_message_fn(params, std::sync::Arc::new(format_fn))
+    }
+
+    /// Create the message sharing the format function with the other messages.
+    pub fn with_message_fn(params: Params, format_fn: MessageFn<Params>) -> Self {
+        Self {
+            params,
+            format: MessageFormat::Custom(format_fn),
+            key: None,
+        }
+    }
+
+    /// Create the message formatted by [`ToDefaultMessage::to_default_message`].
+    pub fn with_default_message(params: Params) -> Self {
+        Self {
+            params,
+            format: MessageFormat::Default(Params::to_default_message),
+            key: None,
+        }
+    }
+
+    /// Set the key of the message in [`MessageCatalog`](crate::validation::MessageCatalog).
+    pub fn with_key(mut self, key: &'static str) -> Self {
+        self.key = Some(key);
+        self
     }
 
     pub fn params(&self) -> &Params {
         &self.params
     }
+
+    pub(crate) fn params_mut(&mut self) -> &mut Params {
+        &mut self.params
+    }
+
+    /// The custom format function, or `None` for the default message.
+    pub fn message_fn(&self) -> Option<&MessageFn<Params>> {
+        match &self.format {
+            MessageFormat::Default(_) => None,
+            MessageFormat::Custom(format_fn) => Some(format_fn),
+        }
+    }
+
+    /// Whether the message is the default message of the parameters.
+    pub fn is_default(&self) -> bool {
+        matches!(self.format, MessageFormat::Default(_))
+    }
+
+    /// Key of the message in [`MessageCatalog`](crate::validation::MessageCatalog),
+    /// set by `#[validate(..., message_key = "...")]`.
+    pub fn key(&self) -> Option<&'static str> {
+        self.key
+    }
+
+    pub(crate) fn set_key(&mut self, key: &'static str) {
+        self.key = Some(key);
+    }
+
+    /// Replace the message with the rendered one.
+    pub(crate) fn set_message(&mut self, message: String) {
+        self.format = MessageFormat::Custom(std::sync::Arc::new(move |_| message.clone()));
+    }
+
+    /// Format the message by the format function of the validator.
+    pub fn format(&self) -> String {
+        match &self.format {
+            MessageFormat::Default(format_fn) => format_fn(&self.params),
+            MessageFormat::Custom(format_fn) => format_fn(&self.params),
+        }
+    }
 }
 
 impl<Params> std::fmt::Debug for Message<Params>
@@ -35,7 +116,14 @@ where
     Params: ToDefaultMessage + std::fmt::Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Message {{ params: {:?} }}", &self.params)
+        match self.key {
+            Some(key) => write!(
+                f,
+                "Message {{ params: {:?}, key: {:?} }}",
+                &self.params, key
+            ),
+            None => write!(f, "Message {{ params: {:?} }}", &self.params),
+        }
     }
 }
 
@@ -44,6 +132,6 @@ where
     Params: ToDefaultMessage,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", { self.format_fn }(&self.params))
+        write!(f, "{}", self.format())
     }
 }
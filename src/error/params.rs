@@ -1,5 +1,5 @@
 use super::message::ToDefaultMessage;
-use crate::validation::{Number, Pattern};
+use crate::validation::{Format, Number, Pattern};
 
 macro_rules! struct_error_params {
     (
@@ -9,7 +9,7 @@ macro_rules! struct_error_params {
             pub $limit:ident: $type:ty,
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $ErrorParams {
             pub $limit: $type,
         }
@@ -35,7 +35,7 @@ macro_rules! struct_error_params {
         pub struct $ErrorParams:ident {
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $ErrorParams {}
 
         impl ToDefaultMessage for $ErrorParams {
@@ -112,6 +112,14 @@ struct_error_params!(
     }
 );
 
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "the value must match the format of \"{}\"."]
+    pub struct FormatErrorParams {
+        pub format: Format,
+    }
+);
+
 // Array
 struct_error_params!(
     #[derive(Debug, Clone)]
@@ -135,6 +143,28 @@ struct_error_params!(
     pub struct UniqueItemsErrorParams {}
 );
 
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "the items must contain at least one matching item."]
+    pub struct ContainsErrorParams {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "the number of the matching items must be `>= {}`."]
+    pub struct MinContainsErrorParams {
+        pub min_contains: usize,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "the number of the matching items must be `<= {}`."]
+    pub struct MaxContainsErrorParams {
+        pub max_contains: usize,
+    }
+);
+
 // Object
 struct_error_params!(
     #[derive(Debug, Clone)]
@@ -151,3 +181,16 @@ struct_error_params!(
         pub min_properties: usize,
     }
 );
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "the property is required."]
+    pub struct RequiredErrorParams {}
+);
+
+// Generic
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "the value is not allowed."]
+    pub struct NotAllowedErrorParams {}
+);
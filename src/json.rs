@@ -2,6 +2,7 @@ mod from_json_reader;
 mod from_json_slice;
 mod from_json_str;
 mod from_json_value;
+mod schema;
 mod to_json_string;
 mod to_json_value;
 mod to_json_writer;
@@ -12,6 +13,7 @@ pub use from_json_reader::FromJsonReader;
 pub use from_json_slice::FromJsonSlice;
 pub use from_json_str::FromJsonStr;
 pub use from_json_value::FromJsonValue;
+pub use schema::{Schema, SchemaError};
 pub use to_json_string::ToJsonString;
 pub use to_json_value::ToJsonValue;
 pub use to_json_writer::ToJsonWriter;
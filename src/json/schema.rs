@@ -0,0 +1,799 @@
+mod error;
+
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+pub use error::SchemaError;
+
+use crate::error::{Message, ToDefaultMessage};
+use crate::validation::{
+    escape_json_pointer, validate_contains, validate_max_contains, validate_min_contains,
+    ArrayErrors, Error, Errors, Format, Literal, ObjectErrors, VecErrors,
+};
+use crate::{
+    AnyOfErrorParams, ConstErrorParams, EnumerateErrorParams, NotAllowedErrorParams,
+    NotErrorParams, OneOfErrorParams, RequiredErrorParams, TypeErrorParams, UniqueItemsErrorParams,
+    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateFormat, ValidateMaxItems,
+    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
+    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
+};
+
+const TYPES: [&str; 7] = [
+    "null", "boolean", "object", "array", "number", "string", "integer",
+];
+
+const UNSUPPORTED_KEYWORDS: [&str; 8] = [
+    "$ref",
+    "$dynamicRef",
+    "if",
+    "then",
+    "else",
+    "dependentSchemas",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+];
+
+/// Compare the numbers as same integer types if possible, otherwise as floats.
+macro_rules! validate_number {
+    ($number:expr, $limit:expr, $validate:path) => {
+        match ($number.as_i64(), $limit.as_i64()) {
+            (Some(number), Some(limit)) => $validate(&number, limit),
+            _ => match ($number.as_u64(), $limit.as_u64()) {
+                (Some(number), Some(limit)) => $validate(&number, limit),
+                _ => $validate(&as_f64($number), as_f64($limit)),
+            },
+        }
+    };
+}
+
+/// JSON Schema compiled from [`Value`], to validate the values which have no Rust types.
+///
+/// The errors are reported by the same [`Errors`] as `#[derive(Validate)]`.
+///
+/// ```rust
+/// use serde_valid::json::{json, Schema};
+///
+/// let schema = Schema::new(&json!({
+///     "type": "object",
+///     "properties": {
+///         "name": { "type": "string", "minLength": 1 }
+///     },
+///     "required": ["name"]
+/// }))
+/// .unwrap();
+///
+/// assert!(schema.validate(&json!({ "name": "serde_valid" })).is_ok());
+/// assert_eq!(
+///     schema.validate(&json!({ "name": "" })).unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "name": {
+///                 "errors": ["the length of the value must be `>= 1`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Schema {
+    kind: SchemaKind,
+}
+
+#[derive(Debug, Clone)]
+enum SchemaKind {
+    True,
+    False,
+    Keywords(Box<Keywords>),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Keywords {
+    types: Option<Vec<&'static str>>,
+    enumerate: Option<Vec<Value>>,
+    const_value: Option<Value>,
+    all_of: Vec<Schema>,
+    any_of: Vec<Schema>,
+    one_of: Vec<Schema>,
+    not: Option<Schema>,
+    minimum: Option<serde_json::Number>,
+    maximum: Option<serde_json::Number>,
+    exclusive_minimum: Option<serde_json::Number>,
+    exclusive_maximum: Option<serde_json::Number>,
+    multiple_of: Option<serde_json::Number>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+    format: Option<Format>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+    prefix_items: Vec<Schema>,
+    items: Option<Schema>,
+    contains: Option<Schema>,
+    min_contains: Option<usize>,
+    max_contains: Option<usize>,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+    properties: IndexMap<String, Schema>,
+    pattern_properties: Vec<(Regex, Schema)>,
+    required: Vec<String>,
+    dependent_required: IndexMap<String, Vec<String>>,
+    additional_properties: Option<Schema>,
+    property_names: Option<Schema>,
+}
+
+impl Schema {
+    /// Compile the JSON Schema document.
+    pub fn new(schema: &Value) -> Result<Self, SchemaError> {
+        compile(schema, "#")
+    }
+
+    pub fn validate(&self, value: &Value) -> Result<(), Errors> {
+        match &self.kind {
+            SchemaKind::True => Ok(()),
+            SchemaKind::False => Err(Errors::NewType(vec![Error::NotAllowed(
+                Message::with_default_message(NotAllowedErrorParams {}),
+            )])),
+            SchemaKind::Keywords(keywords) => keywords.validate(value),
+        }
+    }
+}
+
+impl TryFrom<&Value> for Schema {
+    type Error = SchemaError;
+
+    fn try_from(schema: &Value) -> Result<Self, Self::Error> {
+        Schema::new(schema)
+    }
+}
+
+fn compile(schema: &Value, location: &str) -> Result<Schema, SchemaError> {
+    let kind = match schema {
+        Value::Bool(true) => SchemaKind::True,
+        Value::Bool(false) => SchemaKind::False,
+        Value::Object(object) => {
+            SchemaKind::Keywords(Box::new(Keywords::compile(object, location)?))
+        }
+        _ => {
+            return Err(SchemaError::InvalidSchema {
+                location: location.to_owned(),
+            })
+        }
+    };
+
+    Ok(Schema { kind })
+}
+
+impl Keywords {
+    fn compile(object: &Map<String, Value>, location: &str) -> Result<Self, SchemaError> {
+        let mut keywords = Keywords::default();
+
+        for (keyword, value) in object {
+            let location = format!("{location}/{}", escape_json_pointer(keyword));
+            match keyword.as_str() {
+                "type" => keywords.types = Some(get_types(value, &location)?),
+                "enum" => keywords.enumerate = Some(get_array(value, &location)?.clone()),
+                "const" => keywords.const_value = Some(value.clone()),
+                "allOf" => keywords.all_of = get_schemas(value, &location)?,
+                "anyOf" => keywords.any_of = get_schemas(value, &location)?,
+                "oneOf" => keywords.one_of = get_schemas(value, &location)?,
+                "not" => keywords.not = Some(compile(value, &location)?),
+                "minimum" => keywords.minimum = Some(get_number(value, &location)?),
+                "maximum" => keywords.maximum = Some(get_number(value, &location)?),
+                "exclusiveMinimum" => {
+                    keywords.exclusive_minimum = Some(get_number(value, &location)?)
+                }
+                "exclusiveMaximum" => {
+                    keywords.exclusive_maximum = Some(get_number(value, &location)?)
+                }
+                "multipleOf" => keywords.multiple_of = Some(get_positive_number(value, &location)?),
+                "minLength" => keywords.min_length = Some(get_usize(value, &location)?),
+                "maxLength" => keywords.max_length = Some(get_usize(value, &location)?),
+                "pattern" => keywords.pattern = Some(get_pattern(value, &location)?),
+                "format" => keywords.format = get_format(value, &location)?,
+                "minItems" => keywords.min_items = Some(get_usize(value, &location)?),
+                "maxItems" => keywords.max_items = Some(get_usize(value, &location)?),
+                "uniqueItems" => keywords.unique_items = get_bool(value, &location)?,
+                "prefixItems" => keywords.prefix_items = get_schemas(value, &location)?,
+                "items" => keywords.items = Some(compile(value, &location)?),
+                "contains" => keywords.contains = Some(compile(value, &location)?),
+                "minContains" => keywords.min_contains = Some(get_usize(value, &location)?),
+                "maxContains" => keywords.max_contains = Some(get_usize(value, &location)?),
+                "minProperties" => keywords.min_properties = Some(get_usize(value, &location)?),
+                "maxProperties" => keywords.max_properties = Some(get_usize(value, &location)?),
+                "properties" => {
+                    keywords.properties = get_object(value, &location)?
+                        .iter()
+                        .map(|(name, schema)| {
+                            let location = format!("{location}/{}", escape_json_pointer(name));
+                            Ok((name.to_owned(), compile(schema, &location)?))
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                "required" => keywords.required = get_strings(value, &location)?,
+                "dependentRequired" => {
+                    keywords.dependent_required = get_object(value, &location)?
+                        .iter()
+                        .map(|(name, dependents)| {
+                            let location = format!("{location}/{}", escape_json_pointer(name));
+                            Ok((name.to_owned(), get_strings(dependents, &location)?))
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                "additionalProperties" => {
+                    keywords.additional_properties = Some(compile(value, &location)?)
+                }
+                "patternProperties" => {
+                    keywords.pattern_properties = get_object(value, &location)?
+                        .iter()
+                        .map(|(pattern, schema)| {
+                            let location = format!("{location}/{}", escape_json_pointer(pattern));
+                            Ok((
+                                get_pattern(&Value::String(pattern.to_owned()), &location)?,
+                                compile(schema, &location)?,
+                            ))
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                "propertyNames" => keywords.property_names = Some(compile(value, &location)?),
+                keyword if UNSUPPORTED_KEYWORDS.contains(&keyword) => {
+                    return Err(SchemaError::UnsupportedKeyword { location })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(keywords)
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), Errors> {
+        let mut errors = VecErrors::new();
+
+        if let Some(types) = &self.types {
+            if !types.iter().any(|type_name| is_type(value, type_name)) {
+                errors.push(Error::Type(Message::with_default_message(
+                    TypeErrorParams::new(types),
+                )));
+            }
+        }
+
+        if let Some(enumerate) = &self.enumerate {
+            if !enumerate.iter().any(|item| is_equal(item, value)) {
+                let params = EnumerateErrorParams {
+                    enumerate: enumerate.iter().map(to_literal).collect(),
+                };
+                errors.push(Error::Enumerate(Message::with_default_message(params)));
+            }
+        }
+
+        if let Some(const_value) = &self.const_value {
+            if !is_equal(const_value, value) {
+                errors.push(Error::Const(Message::with_default_message(
+                    ConstErrorParams::new(to_literal(const_value)),
+                )));
+            }
+        }
+
+        self.validate_composition(value, &mut errors);
+
+        match value {
+            Value::Number(number) => self.validate_number(number, &mut errors),
+            Value::String(string) => self.validate_string(string, &mut errors),
+            Value::Array(array) => {
+                let items = self.validate_array(array, &mut errors);
+                if !items.is_empty() {
+                    return Err(Errors::Array(ArrayErrors::new(errors, items)));
+                }
+            }
+            Value::Object(object) => {
+                let properties = self.validate_object(object, &mut errors);
+                if self.has_properties_keywords() && !(errors.is_empty() && properties.is_empty()) {
+                    return Err(Errors::Object(ObjectErrors::new(errors, properties)));
+                }
+            }
+            Value::Null | Value::Bool(_) => {}
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::NewType(errors))
+        }
+    }
+
+    fn validate_composition(&self, value: &Value, errors: &mut VecErrors) {
+        for schema in &self.all_of {
+            if let Err(schema_errors) = schema.validate(value) {
+                errors.extend(into_vec_errors(schema_errors));
+            }
+        }
+        if !self.any_of.is_empty() {
+            let schemas_errors = validate_schemas(&self.any_of, value);
+            if schemas_errors.iter().all(|errors| !errors.is_empty()) {
+                errors.push(Error::AnyOf(Message::with_default_message(
+                    AnyOfErrorParams::new(schemas_errors),
+                )));
+            }
+        }
+        if !self.one_of.is_empty() {
+            let params = OneOfErrorParams::new(validate_schemas(&self.one_of, value));
+            if params.valid_count() != 1 {
+                errors.push(Error::OneOf(Message::with_default_message(params)));
+            }
+        }
+        if let Some(schema) = &self.not {
+            if schema.validate(value).is_ok() {
+                errors.push(Error::Not(Message::with_default_message(NotErrorParams {})));
+            }
+        }
+    }
+
+    fn validate_number(&self, number: &serde_json::Number, errors: &mut VecErrors) {
+        if let Some(minimum) = &self.minimum {
+            push_error(
+                errors,
+                validate_number!(number, minimum, ValidateMinimum::validate_minimum),
+                Error::Minimum,
+            );
+        }
+        if let Some(maximum) = &self.maximum {
+            push_error(
+                errors,
+                validate_number!(number, maximum, ValidateMaximum::validate_maximum),
+                Error::Maximum,
+            );
+        }
+        if let Some(exclusive_minimum) = &self.exclusive_minimum {
+            push_error(
+                errors,
+                validate_number!(
+                    number,
+                    exclusive_minimum,
+                    ValidateExclusiveMinimum::validate_exclusive_minimum
+                ),
+                Error::ExclusiveMinimum,
+            );
+        }
+        if let Some(exclusive_maximum) = &self.exclusive_maximum {
+            push_error(
+                errors,
+                validate_number!(
+                    number,
+                    exclusive_maximum,
+                    ValidateExclusiveMaximum::validate_exclusive_maximum
+                ),
+                Error::ExclusiveMaximum,
+            );
+        }
+        if let Some(multiple_of) = &self.multiple_of {
+            push_error(
+                errors,
+                validate_number!(
+                    number,
+                    multiple_of,
+                    ValidateMultipleOf::validate_multiple_of
+                ),
+                Error::MultipleOf,
+            );
+        }
+    }
+
+    fn validate_string(&self, string: &String, errors: &mut VecErrors) {
+        if let Some(min_length) = self.min_length {
+            push_error(
+                errors,
+                ValidateMinLength::validate_min_length(string, min_length),
+                Error::MinLength,
+            );
+        }
+        if let Some(max_length) = self.max_length {
+            push_error(
+                errors,
+                ValidateMaxLength::validate_max_length(string, max_length),
+                Error::MaxLength,
+            );
+        }
+        if let Some(pattern) = &self.pattern {
+            push_error(
+                errors,
+                ValidatePattern::validate_pattern(string, pattern),
+                Error::Pattern,
+            );
+        }
+        if let Some(format) = self.format {
+            push_error(
+                errors,
+                ValidateFormat::validate_format(string.as_str(), format),
+                Error::Format,
+            );
+        }
+    }
+
+    fn validate_array(
+        &self,
+        array: &Vec<Value>,
+        errors: &mut VecErrors,
+    ) -> IndexMap<usize, Errors> {
+        if let Some(min_items) = self.min_items {
+            push_error(
+                errors,
+                ValidateMinItems::validate_min_items(array, min_items),
+                Error::MinItems,
+            );
+        }
+        if let Some(max_items) = self.max_items {
+            push_error(
+                errors,
+                ValidateMaxItems::validate_max_items(array, max_items),
+                Error::MaxItems,
+            );
+        }
+        if self.unique_items && !is_unique(array) {
+            push_error(errors, Err(UniqueItemsErrorParams {}), Error::UniqueItems);
+        }
+        if let Some(contains) = &self.contains {
+            let contains_count = array
+                .iter()
+                .filter(|item| contains.validate(item).is_ok())
+                .count();
+            match self.min_contains {
+                Some(min_contains) => push_error(
+                    errors,
+                    validate_min_contains(contains_count, min_contains),
+                    Error::MinContains,
+                ),
+                None => push_error(errors, validate_contains(contains_count), Error::Contains),
+            }
+            if let Some(max_contains) = self.max_contains {
+                push_error(
+                    errors,
+                    validate_max_contains(contains_count, max_contains),
+                    Error::MaxContains,
+                );
+            }
+        }
+
+        let mut items = IndexMap::new();
+        for (index, item) in array.iter().enumerate() {
+            if let Some(schema) = self.prefix_items.get(index).or(self.items.as_ref()) {
+                if let Err(item_errors) = schema.validate(item) {
+                    items.insert(index, item_errors);
+                }
+            }
+        }
+        items
+    }
+
+    fn validate_object(
+        &self,
+        object: &Map<String, Value>,
+        errors: &mut VecErrors,
+    ) -> IndexMap<Cow<'static, str>, Errors> {
+        if let Some(min_properties) = self.min_properties {
+            push_error(
+                errors,
+                ValidateMinProperties::validate_min_properties(object, min_properties),
+                Error::MinProperties,
+            );
+        }
+        if let Some(max_properties) = self.max_properties {
+            push_error(
+                errors,
+                ValidateMaxProperties::validate_max_properties(object, max_properties),
+                Error::MaxProperties,
+            );
+        }
+
+        let mut properties = IndexMap::new();
+        let dependent_required = self
+            .dependent_required
+            .iter()
+            .filter(|(name, _)| object.contains_key(name.as_str()))
+            .flat_map(|(_, dependents)| dependents);
+        for name in self.required.iter().chain(dependent_required) {
+            if !object.contains_key(name) && !properties.contains_key(name.as_str()) {
+                properties.insert(
+                    Cow::Owned(name.to_owned()),
+                    Errors::NewType(vec![Error::Required(Message::with_default_message(
+                        RequiredErrorParams {},
+                    ))]),
+                );
+            }
+        }
+        for (name, value) in object {
+            let name_errors = match &self.property_names {
+                Some(schema) => match schema.validate(&Value::String(name.to_owned())) {
+                    Ok(()) => vec![],
+                    Err(errors) => into_vec_errors(errors),
+                },
+                None => vec![],
+            };
+            let mut schemas = self
+                .properties
+                .get(name)
+                .into_iter()
+                .chain(
+                    self.pattern_properties
+                        .iter()
+                        .filter(|(pattern, _)| pattern.is_match(name))
+                        .map(|(_, schema)| schema),
+                )
+                .peekable();
+            let schemas = match (schemas.peek(), &self.additional_properties) {
+                (None, Some(schema)) => vec![schema],
+                _ => schemas.collect(),
+            };
+            let mut value_errors = schemas
+                .into_iter()
+                .filter_map(|schema| schema.validate(value).err())
+                .collect::<Vec<_>>();
+            let property_errors = match (name_errors.is_empty(), value_errors.len()) {
+                (true, 0) => continue,
+                (true, 1) => value_errors.remove(0),
+                _ => Errors::NewType(
+                    name_errors
+                        .into_iter()
+                        .chain(value_errors.into_iter().flat_map(into_vec_errors))
+                        .collect(),
+                ),
+            };
+            properties.insert(Cow::Owned(name.to_owned()), property_errors);
+        }
+        properties
+    }
+
+    fn has_properties_keywords(&self) -> bool {
+        !(self.properties.is_empty()
+            && self.pattern_properties.is_empty()
+            && self.required.is_empty()
+            && self.dependent_required.is_empty()
+            && self.additional_properties.is_none()
+            && self.property_names.is_none())
+    }
+}
+
+fn push_error<Params>(
+    errors: &mut VecErrors,
+    result: Result<(), Params>,
+    into_error: fn(Message<Params>) -> Error,
+) where
+    Params: ToDefaultMessage + 'static,
+{
+    if let Err(params) = result {
+        errors.push(into_error(Message::with_default_message(params)));
+    }
+}
+
+fn validate_schemas(schemas: &[Schema], value: &Value) -> Vec<VecErrors> {
+    schemas
+        .iter()
+        .map(|schema| match schema.validate(value) {
+            Ok(()) => vec![],
+            Err(errors) => into_vec_errors(errors),
+        })
+        .collect()
+}
+
+/// Errors of the value itself, as same as the field errors of `#[derive(Validate)]`.
+fn into_vec_errors(errors: Errors) -> VecErrors {
+    match errors {
+        Errors::Array(errors) => vec![Error::Items(errors)],
+        Errors::Object(errors) => vec![Error::Properties(errors)],
+        Errors::NewType(errors) => errors,
+    }
+}
+
+fn as_f64(number: &serde_json::Number) -> f64 {
+    number.as_f64().unwrap_or(f64::NAN)
+}
+
+fn is_type(value: &Value, type_name: &str) -> bool {
+    match type_name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "integer" => match value {
+            Value::Number(number) => {
+                number.is_i64() || number.is_u64() || as_f64(number).fract() == 0.0
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_unique(array: &[Value]) -> bool {
+    array
+        .iter()
+        .enumerate()
+        .all(|(index, item)| !array[..index].iter().any(|other| is_equal(other, item)))
+}
+
+/// Equality of the JSON values, where the numbers are equal by their values, like `1` and `1.0`.
+fn is_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => {
+            if left.is_f64() || right.is_f64() {
+                as_f64(left) == as_f64(right)
+            } else {
+                left == right
+            }
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right)
+                    .all(|(left, right)| is_equal(left, right))
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            left.len() == right.len()
+                && left.iter().all(
+                    |(key, left)| matches!(right.get(key), Some(right) if is_equal(left, right)),
+                )
+        }
+        _ => left == right,
+    }
+}
+
+fn to_literal(value: &Value) -> Literal {
+    match value {
+        Value::Null => Literal::Null,
+        Value::Bool(value) => Literal::Bool(*value),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(number), _) => Literal::from(number),
+            (_, Some(number)) => Literal::from(number),
+            _ => Literal::from(as_f64(number)),
+        },
+        Value::String(string) => Literal::from(string.to_owned()),
+        Value::Array(_) | Value::Object(_) => Literal::from(value.to_string()),
+    }
+}
+
+fn invalid_keyword(location: &str, expected: &'static str) -> SchemaError {
+    SchemaError::InvalidKeyword {
+        location: location.to_owned(),
+        expected,
+    }
+}
+
+fn get_types(value: &Value, location: &str) -> Result<Vec<&'static str>, SchemaError> {
+    let get_type = |value: &Value| {
+        value
+            .as_str()
+            .and_then(|name| TYPES.iter().find(|type_name| **type_name == name))
+            .copied()
+            .ok_or_else(|| invalid_keyword(location, "a type name or an array of type names"))
+    };
+
+    match value {
+        Value::Array(array) => array.iter().map(get_type).collect(),
+        _ => Ok(vec![get_type(value)?]),
+    }
+}
+
+fn get_number(value: &Value, location: &str) -> Result<serde_json::Number, SchemaError> {
+    match value {
+        Value::Number(number) => Ok(number.to_owned()),
+        _ => Err(invalid_keyword(location, "a number")),
+    }
+}
+
+fn get_positive_number(value: &Value, location: &str) -> Result<serde_json::Number, SchemaError> {
+    match value {
+        Value::Number(number) if as_f64(number) > 0.0 => Ok(number.to_owned()),
+        _ => Err(invalid_keyword(location, "a positive number")),
+    }
+}
+
+fn get_usize(value: &Value, location: &str) -> Result<usize, SchemaError> {
+    value
+        .as_u64()
+        .and_then(|number| usize::try_from(number).ok())
+        .ok_or_else(|| invalid_keyword(location, "a non-negative integer"))
+}
+
+fn get_bool(value: &Value, location: &str) -> Result<bool, SchemaError> {
+    value
+        .as_bool()
+        .ok_or_else(|| invalid_keyword(location, "a boolean"))
+}
+
+fn get_array<'a>(value: &'a Value, location: &str) -> Result<&'a Vec<Value>, SchemaError> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid_keyword(location, "an array"))
+}
+
+fn get_object<'a>(value: &'a Value, location: &str) -> Result<&'a Map<String, Value>, SchemaError> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid_keyword(location, "an object"))
+}
+
+fn get_schemas(value: &Value, location: &str) -> Result<Vec<Schema>, SchemaError> {
+    get_array(value, location)?
+        .iter()
+        .enumerate()
+        .map(|(index, schema)| compile(schema, &format!("{location}/{index}")))
+        .collect()
+}
+
+fn get_strings(value: &Value, location: &str) -> Result<Vec<String>, SchemaError> {
+    get_array(value, location)?
+        .iter()
+        .map(|item| {
+            item.as_str()
+                .map(ToOwned::to_owned)
+                .ok_or_else(|| invalid_keyword(location, "an array of strings"))
+        })
+        .collect()
+}
+
+fn get_pattern(value: &Value, location: &str) -> Result<Regex, SchemaError> {
+    let pattern = value
+        .as_str()
+        .ok_or_else(|| invalid_keyword(location, "a string"))?;
+
+    Regex::new(pattern).map_err(|source| SchemaError::InvalidPattern {
+        location: location.to_owned(),
+        source,
+    })
+}
+
+/// Unknown formats are ignored, as the format is an annotation by default.
+fn get_format(value: &Value, location: &str) -> Result<Option<Format>, SchemaError> {
+    let format = value
+        .as_str()
+        .ok_or_else(|| invalid_keyword(location, "a string"))?;
+
+    Ok(format.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_integer_type() {
+        let schema = Schema::new(&json!({ "type": "integer" })).unwrap();
+
+        assert!(schema.validate(&json!(1)).is_ok());
+        assert!(schema.validate(&json!(1.0)).is_ok());
+        assert!(schema.validate(&json!(1.5)).is_err());
+        assert!(schema.validate(&json!("1")).is_err());
+    }
+
+    #[test]
+    fn test_schema_compare_mixed_numbers() {
+        let schema = Schema::new(&json!({ "maximum": 1.5, "minimum": -1 })).unwrap();
+
+        assert!(schema.validate(&json!(1)).is_ok());
+        assert!(schema.validate(&json!(u64::MAX)).is_err());
+        assert!(schema.validate(&json!(-1.5)).is_err());
+    }
+
+    #[test]
+    fn test_schema_error_location() {
+        let error = Schema::new(&json!({
+            "properties": {
+                "a/b": { "minLength": -1 }
+            }
+        }))
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "the keyword of \"#/properties/a~1b/minLength\" must be a non-negative integer."
+        );
+    }
+}
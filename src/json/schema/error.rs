@@ -0,0 +1,23 @@
+/// Error of compiling the JSON Schema.
+///
+/// `location` is the JSON Pointer of the invalid part in the schema, like `#/properties/name/minLength`.
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("the schema of \"{location}\" must be an object or a boolean.")]
+    InvalidSchema { location: String },
+
+    #[error("the keyword of \"{location}\" must be {expected}.")]
+    InvalidKeyword {
+        location: String,
+        expected: &'static str,
+    },
+
+    #[error("the keyword of \"{location}\" is not supported.")]
+    UnsupportedKeyword { location: String },
+
+    #[error("the pattern of \"{location}\" is invalid: {source}")]
+    InvalidPattern {
+        location: String,
+        source: regex::Error,
+    },
+}
@@ -0,0 +1,466 @@
+//! [JSON Schema](https://json-schema.org/) generation.
+//!
+//! `#[derive(JsonSchema)]` reads the same `#[validate(...)]` attributes as `#[derive(Validate)]`,
+//! and exports them as the keywords of the [draft 2020-12](https://json-schema.org/draft/2020-12/schema).
+
+use serde_json::{json, Map, Value};
+
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Type which can describe itself as a JSON Schema.
+///
+/// ```rust
+/// use serde_valid::json::json;
+/// use serde_valid::JsonSchema;
+///
+/// #[derive(JsonSchema)]
+/// struct SampleStruct {
+///     #[validate(maximum = 100)]
+///     val: i32,
+/// }
+///
+/// assert_eq!(
+///     SampleStruct::json_schema(),
+///     json!({
+///         "type": "object",
+///         "properties": {
+///             "val": {
+///                 "type": "integer",
+///                 "maximum": 100
+///             }
+///         },
+///         "required": ["val"]
+///     })
+/// );
+/// ```
+pub trait JsonSchema {
+    /// Schema of the type, embeddable in the other schemas.
+    fn json_schema() -> Value;
+
+    /// Schema document of the type, with the `$schema` keyword.
+    fn json_schema_document() -> Value {
+        let mut schema = Self::json_schema();
+        if let Value::Object(object) = &mut schema {
+            object.insert(
+                "$schema".to_owned(),
+                Value::String(DRAFT_2020_12.to_owned()),
+            );
+        }
+        schema
+    }
+}
+
+/// Insert the validation keyword to the schema.
+///
+/// As same as validations of `#[derive(Validate)]`,
+/// the keywords except for array keywords and composition keywords
+/// are applied to the items of the array schema.
+pub fn insert_keyword(schema: &mut Value, keyword: &str, value: Value) {
+    if has_type(schema, "array") && !is_array_keyword(keyword) && !is_composition_keyword(keyword) {
+        if let Some(items) = schema.get_mut("items") {
+            insert_keyword(items, keyword, value);
+            return;
+        }
+    }
+
+    if let Value::Object(object) = schema {
+        let value = match (keyword, value) {
+            ("enum", Value::Array(mut candidates)) if has_type_in(object, "null") => {
+                candidates.push(Value::Null);
+                Value::Array(candidates)
+            }
+            (_, value) => value,
+        };
+        object.insert(keyword.to_owned(), value);
+    }
+}
+
+/// Insert the validation keyword to the `propertyNames` of the map schema.
+pub fn insert_property_names_keyword(schema: &mut Value, keyword: &str, value: Value) {
+    if let Value::Object(object) = schema {
+        let property_names = object.entry("propertyNames").or_insert_with(|| json!({}));
+        insert_keyword(property_names, keyword, value);
+    }
+}
+
+/// Insert the validation keyword to the `additionalProperties` of the map schema.
+pub fn insert_additional_properties_keyword(schema: &mut Value, keyword: &str, value: Value) {
+    if let Some(additional_properties) = schema.get_mut("additionalProperties") {
+        insert_keyword(additional_properties, keyword, value);
+    }
+}
+
+/// Insert the schema of the keys which match the pattern to the `patternProperties`.
+pub fn insert_pattern_property(schema: &mut Value, pattern: &str, value: Value) {
+    if let Value::Object(object) = schema {
+        if let Value::Object(pattern_properties) = object
+            .entry("patternProperties")
+            .or_insert_with(|| json!({}))
+        {
+            pattern_properties.insert(pattern.to_owned(), value);
+        }
+    }
+}
+
+/// Insert the schema of the key to the `properties`.
+pub fn insert_property(schema: &mut Value, name: &str, value: Value) {
+    if let Value::Object(object) = schema {
+        if let Value::Object(properties) = object.entry("properties").or_insert_with(|| json!({})) {
+            properties.insert(name.to_owned(), value);
+        }
+    }
+}
+
+/// Schema of the items of the array schema.
+pub fn items_schema(schema: &Value) -> Value {
+    schema.get("items").cloned().unwrap_or_else(|| json!({}))
+}
+
+/// Schema of the item at the position of the array schema.
+pub fn prefix_item_schema(schema: &Value, index: usize) -> Value {
+    schema
+        .get("prefixItems")
+        .and_then(|prefix_items| prefix_items.get(index))
+        .cloned()
+        .unwrap_or_else(|| items_schema(schema))
+}
+
+/// `prefixItems` of the array schema, whose items at the positions are replaced.
+pub fn prefix_items_schema(schema: &Value, items: Vec<(usize, Value)>) -> Value {
+    let len = schema
+        .get("prefixItems")
+        .and_then(Value::as_array)
+        .map(Vec::len)
+        .into_iter()
+        .chain(items.iter().map(|(index, _)| index + 1))
+        .max()
+        .unwrap_or_default();
+    let mut prefix_items = (0..len)
+        .map(|index| prefix_item_schema(schema, index))
+        .collect::<Vec<_>>();
+    for (index, item) in items {
+        prefix_items[index] = item;
+    }
+    Value::Array(prefix_items)
+}
+
+/// Schema of the values of the map schema.
+pub fn additional_properties_schema(schema: &Value) -> Value {
+    schema
+        .get("additionalProperties")
+        .cloned()
+        .unwrap_or_else(|| json!({}))
+}
+
+/// Schema of the composition keywords, which is the field schema with the keywords.
+///
+/// As same as `#[derive(Validate)]`, each schema validates the whole value of the field.
+pub fn composition_schema(schema: &Value, keywords: Vec<(&str, Value)>) -> Value {
+    let mut schema = schema.clone();
+    for (keyword, value) in keywords {
+        insert_keyword(&mut schema, keyword, value);
+    }
+    schema
+}
+
+fn is_array_keyword(keyword: &str) -> bool {
+    [
+        "minItems",
+        "maxItems",
+        "uniqueItems",
+        "prefixItems",
+        "contains",
+        "minContains",
+        "maxContains",
+    ]
+    .contains(&keyword)
+}
+
+fn is_composition_keyword(keyword: &str) -> bool {
+    ["allOf", "anyOf", "oneOf", "not"].contains(&keyword)
+}
+
+fn has_type(schema: &Value, type_name: &str) -> bool {
+    match schema {
+        Value::Object(object) => has_type_in(object, type_name),
+        _ => false,
+    }
+}
+
+fn has_type_in(object: &Map<String, Value>, type_name: &str) -> bool {
+    match object.get("type") {
+        Some(Value::String(name)) => name == type_name,
+        Some(Value::Array(names)) => names.iter().any(|name| name == type_name),
+        _ => false,
+    }
+}
+
+fn nullable(mut schema: Value) -> Value {
+    if let Value::Object(object) = &mut schema {
+        match object.get_mut("type") {
+            Some(Value::String(name)) => {
+                let name = std::mem::take(name);
+                object.insert("type".to_owned(), json!([name, "null"]));
+            }
+            Some(Value::Array(names)) if !names.iter().any(|name| name == "null") => {
+                names.push(json!("null"));
+            }
+            _ => {}
+        }
+    }
+    schema
+}
+
+macro_rules! impl_json_schema {
+    ($type_name:literal, $($type:ty),* $(,)?) => {
+        $(
+            impl JsonSchema for $type {
+                fn json_schema() -> Value {
+                    json!({ "type": $type_name })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!(
+    "integer",
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    std::num::NonZeroI8,
+    std::num::NonZeroI16,
+    std::num::NonZeroI32,
+    std::num::NonZeroI64,
+    std::num::NonZeroI128,
+    std::num::NonZeroIsize,
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroU128,
+    std::num::NonZeroUsize,
+);
+impl_json_schema!("number", f32, f64);
+impl_json_schema!("boolean", bool);
+impl_json_schema!(
+    "string",
+    char,
+    str,
+    String,
+    std::ffi::OsStr,
+    std::ffi::OsString,
+    std::path::Path,
+    std::path::PathBuf,
+);
+impl_json_schema!("null", ());
+
+impl<T> JsonSchema for &T
+where
+    T: JsonSchema + ?Sized,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Box<T>
+where
+    T: JsonSchema + ?Sized,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for std::borrow::Cow<'_, T>
+where
+    T: JsonSchema + ToOwned + ?Sized,
+{
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T> JsonSchema for Option<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        nullable(T::json_schema())
+    }
+}
+
+impl<T> JsonSchema for Vec<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+impl<T> JsonSchema for [T]
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+impl<T, const N: usize> JsonSchema for [T; N]
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({
+            "type": "array",
+            "items": T::json_schema(),
+            "minItems": N,
+            "maxItems": N,
+        })
+    }
+}
+
+impl<T, S> JsonSchema for std::collections::HashSet<T, S>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema(), "uniqueItems": true })
+    }
+}
+
+impl<T> JsonSchema for std::collections::BTreeSet<T>
+where
+    T: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema(), "uniqueItems": true })
+    }
+}
+
+impl<K, V, S> JsonSchema for std::collections::HashMap<K, V, S>
+where
+    V: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
+
+impl<K, V> JsonSchema for std::collections::BTreeMap<K, V>
+where
+    V: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
+
+impl<K, V, S> JsonSchema for indexmap::IndexMap<K, V, S>
+where
+    V: JsonSchema,
+{
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
+
+impl JsonSchema for Map<String, Value> {
+    fn json_schema() -> Value {
+        json!({ "type": "object" })
+    }
+}
+
+impl JsonSchema for Value {
+    fn json_schema() -> Value {
+        json!({})
+    }
+}
+
+macro_rules! impl_tuple_json_schema {
+    ($len:literal, $($T:ident),+) => {
+        impl<$($T),+> JsonSchema for ($($T,)+)
+        where
+            $($T: JsonSchema),+
+        {
+            fn json_schema() -> Value {
+                json!({
+                    "type": "array",
+                    "prefixItems": [$($T::json_schema()),+],
+                    "items": false,
+                    "minItems": $len,
+                    "maxItems": $len,
+                })
+            }
+        }
+    };
+}
+
+impl_tuple_json_schema!(1, T1);
+impl_tuple_json_schema!(2, T1, T2);
+impl_tuple_json_schema!(3, T1, T2, T3);
+impl_tuple_json_schema!(4, T1, T2, T3, T4);
+impl_tuple_json_schema!(5, T1, T2, T3, T4, T5);
+impl_tuple_json_schema!(6, T1, T2, T3, T4, T5, T6);
+impl_tuple_json_schema!(7, T1, T2, T3, T4, T5, T6, T7);
+impl_tuple_json_schema!(8, T1, T2, T3, T4, T5, T6, T7, T8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_schema_option_type() {
+        assert_eq!(
+            Option::<i32>::json_schema(),
+            json!({ "type": ["integer", "null"] })
+        );
+    }
+
+    #[test]
+    fn test_insert_keyword_to_array_items() {
+        let mut schema = Vec::<i32>::json_schema();
+        insert_keyword(&mut schema, "maximum", json!(10));
+        insert_keyword(&mut schema, "maxItems", json!(3));
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "array",
+                "items": { "type": "integer", "maximum": 10 },
+                "maxItems": 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_insert_enum_keyword_to_nullable() {
+        let mut schema = Option::<String>::json_schema();
+        insert_keyword(&mut schema, "enum", json!(["a", "b"]));
+
+        assert_eq!(
+            schema,
+            json!({ "type": ["string", "null"], "enum": ["a", "b", null] })
+        );
+    }
+
+    #[test]
+    fn test_json_schema_document() {
+        assert_eq!(
+            String::json_schema_document(),
+            json!({ "$schema": DRAFT_2020_12, "type": "string" })
+        );
+    }
+}
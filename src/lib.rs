@@ -48,6 +48,7 @@
 //! | String | `#[validate(max_length = 5)]` | [maxLength](https://json-schema.org/understanding-json-schema/reference/string.html#length) |
 //! | String | `#[validate(min_length = 5)]` | [minLength](https://json-schema.org/understanding-json-schema/reference/string.html#length) |
 //! | String | `#[validate(pattern = r"^\d{5}$")]` | [pattern](https://json-schema.org/understanding-json-schema/reference/string.html#regular-expressions) |
+//! | String | `#[validate(format = "email")]` | [format](https://json-schema.org/understanding-json-schema/reference/string.html#format) |
 //! | Numeric | `#[validate(maximum = 5)]` | [maximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range) |
 //! | Numeric | `#[validate(minimum = 5)]` | [minimum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range) |
 //! | Numeric | `#[validate(exclusive_maximum = 5)]` | [exclusiveMaximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range) |
@@ -55,10 +56,76 @@
 //! | Numeric | `#[validate(multiple_of = 5)]` | [multipleOf](https://json-schema.org/understanding-json-schema/reference/numeric.html#multiples) |
 //! | Object | `#[validate(max_properties = 5)]` | [maxProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size) |
 //! | Object | `#[validate(min_properties = 5)]` | [minProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size) |
+//! | Object | `#[validate(keys(pattern = r"^[a-z]+$"))]` | [propertyNames](https://json-schema.org/understanding-json-schema/reference/object.html#property-names) |
+//! | Object | `#[validate(values(minimum = 0))]` | [additionalProperties](https://json-schema.org/understanding-json-schema/reference/object.html#additional-properties) |
+//! | Object | `#[validate(pattern_properties("^x-" = custom(check_ext)))]` | [patternProperties](https://json-schema.org/understanding-json-schema/reference/object.html#pattern-properties) |
+//! | Object | `#[validate(additional_properties = false, allowed_keys("a", "b"))]` | [additionalProperties](https://json-schema.org/understanding-json-schema/reference/object.html#additional-properties) |
 //! | Array | `#[validate(max_items = 5)]` | [maxItems](https://json-schema.org/understanding-json-schema/reference/array.html#length) |
 //! | Array | `#[validate(min_items = 5)]` | [minItems](https://json-schema.org/understanding-json-schema/reference/array.html#length) |
 //! | Array | `#[validate(unique_items)]` | [uniqueItems](https://json-schema.org/understanding-json-schema/reference/array.html#unique_items) |
+//! | Array | `#[validate(contains(minimum = 10), min_contains = 1, max_contains = 3)]` | [contains](https://json-schema.org/understanding-json-schema/reference/array.html#contains) |
+//! | Array | `#[validate(prefix_items(0(max_length = 5), 1(minimum = 1)))]` | [prefixItems](https://json-schema.org/understanding-json-schema/reference/array.html#tuple-validation) |
 //! | Generic | `#[validate(enumerate(5, 10, 15))]` | [enum](https://json-schema.org/understanding-json-schema/reference/generic.html#enumerated-values) |
+//! | Generic | `#[validate(const_value = 5)]` | [const](https://json-schema.org/understanding-json-schema/reference/generic.html#constant-values) |
+//! | Composition | `#[validate(all_of(minimum = 1, maximum = 10))]` | [allOf](https://json-schema.org/understanding-json-schema/reference/combining.html#allof) |
+//! | Composition | `#[validate(any_of(pattern = r"^\d+$", max_length = 3))]` | [anyOf](https://json-schema.org/understanding-json-schema/reference/combining.html#anyof) |
+//! | Composition | `#[validate(one_of(minimum = 10, multiple_of = 2))]` | [oneOf](https://json-schema.org/understanding-json-schema/reference/combining.html#oneof) |
+//! | Composition | `#[validate(not(enumerate(1, 2, 3)))]` | [not](https://json-schema.org/understanding-json-schema/reference/combining.html#not) |
+//!
+//! The validators of each field which never succeed together,
+//! like `minimum = 10` with `maximum = 5`, `multiple_of = 0` or the `enumerate` values out of the range, are reported as the compile errors.
+//! The validators are compared in each group where they run, and `allow_contradiction` skips the validator from the check.
+//!
+//! ```rust,compile_fail
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(minimum = 10)]
+//!     #[validate(maximum = 5)]
+//!     val: i32,
+//! }
+//! ```
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(minimum = 10)]
+//!     #[validate(maximum = 5, allow_contradiction)]
+//!     val: i32,
+//! }
+//!
+//! assert!(SampleStruct { val: 7 }.validate().is_err());
+//! ```
+//!
+//! The limits, the patterns and the `enumerate` values accept the constants and the expressions as well as the literals.
+//! Only the literal patterns are checked at compile time;
+//! the other patterns are compiled at the first validation, and an invalid one is reported as the error of the field.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! const MAX_NAME_LENGTH: usize = 8;
+//! const NAME_PATTERN: &str = r"^[a-z]+$";
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(max_length = MAX_NAME_LENGTH)]
+//!     #[validate(pattern = NAME_PATTERN)]
+//!     name: String,
+//!     #[validate(maximum = u16::MAX as u32 / 2)]
+//!     score: u32,
+//! }
+//!
+//! let s = SampleStruct {
+//!     name: "abcdefghi".to_owned(),
+//!     score: 40000,
+//! };
+//!
+//! assert!(s.validate().is_err());
+//! ```
 //!
 //! ## Complete Constructor (Deserialization)
 //!
@@ -114,6 +181,68 @@
 //! );
 //! ```
 //!
+//! ## Flat Errors
+//!
+//! `Errors::iter_flat()` iterates all errors with the JSON Pointer of the invalid value,
+//! and `Errors::flatten()` converts them into the list of `{ "instanceLocation": ..., "message": ... }`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//! use serde_valid::json::json;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 100)]
+//!     val: Vec<i32>,
+//! }
+//!
+//! let errors = SampleStruct { val: vec![1, 101] }.validate().unwrap_err();
+//!
+//! assert_eq!(
+//!     serde_json::to_value(errors.flatten()).unwrap(),
+//!     json!([
+//!         {
+//!             "instanceLocation": "/val/1",
+//!             "message": "the number must be `<= 100`."
+//!         }
+//!     ])
+//! );
+//! ```
+//!
+//! ## Detailed Errors
+//!
+//! `Error::to_detailed()` and `Errors::to_detailed()` expose the keyword and the parameters of the validations,
+//! for the clients which format the messages by themselves.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//! use serde_valid::json::json;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 100)]
+//!     val: i32,
+//! }
+//!
+//! assert_eq!(
+//!     SampleStruct { val: 101 }.validate().unwrap_err().to_detailed(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": [
+//!                     {
+//!                         "keyword": "maximum",
+//!                         "params": { "maximum": 100 },
+//!                         "message": "the number must be `<= 100`."
+//!                     }
+//!                 ]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
 //! ## Custom Message
 //!
 //! For user custom message, Serde Valid provides `message_fn` or `message`.
@@ -129,7 +258,7 @@
 //! #[derive(Validate)]
 //! struct SampleStruct {
 //!     #[validate(min_items = 4, message_fn(min_error_message))]
-//!     #[validate(max_items = 2, message = "this is max custom message.")]
+//!     #[validate(max_items = 2, allow_contradiction, message = "this is max custom message.")]
 //!     val: Vec<i32>,
 //! }
 //!
@@ -152,6 +281,143 @@
 //! );
 //! ```
 //!
+//! ### Message Template
+//!
+//! The `message` can refer to the parameters of the validation by `{name}`,
+//! and to the field name in the errors, renamed by `#[serde(rename = ...)]`, by `{field}`.
+//! Use `{{` and `}}` to write the braces.
+//! The `message` of `contains(...)` can refer to both `{min_contains}` and `{max_contains}` written with it.
+//! Unknown placeholders are reported at compile time.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 10, message = "must be at most {maximum}.")]
+//!     val: i32,
+//!     #[validate(max_length = 4, message = "{field} is too long (max {max_length}).")]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     val: 11,
+//!     name: "abcde".to_owned(),
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["must be at most 10."]
+//!             },
+//!             "name": {
+//!                 "errors": ["name is too long (max 4)."]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! ### Message Formatter
+//!
+//! The messages can be also rendered at the output by `MessageFormatter`,
+//! which is picked by the caller, like the locale of the request.
+//! The formatter returns `None` to keep the message of the validator.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::validation::{Error, MessageFormatter};
+//! use serde_valid::Validate;
+//!
+//! struct Japanese;
+//!
+//! impl MessageFormatter for Japanese {
+//!     fn format_message(&self, error: &Error) -> Option<String> {
+//!         match error {
+//!             Error::Maximum(message) => Some(format!(
+//!                 "値は `{}` 以下でなければなりません。",
+//!                 message.params().maximum
+//!             )),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//! }
+//!
+//! let errors = SampleStruct { val: 11 }.validate().unwrap_err();
+//!
+//! assert_eq!(
+//!     serde_json::to_value(errors.formatted(&Japanese)).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["値は `10` 以下でなければなりません。"]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! ### Message Catalog
+//!
+//! `localize` re-renders every message of the errors by `MessageCatalog`,
+//! which finds the message by the keyword of the error, like `"maximum"`,
+//! or by `message_key` of the validator.
+//! The custom messages of `message` and `message_fn` are kept, unless the validator has `message_key`.
+//! The English catalog is bundled, and the Japanese catalog is available with the `ja` feature.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::validation::{Error, MessageCatalog};
+//! use serde_valid::Validate;
+//!
+//! struct MyCatalog;
+//!
+//! impl MessageCatalog for MyCatalog {
+//!     fn message(&self, key: &str, error: &Error) -> Option<String> {
+//!         match (key, error) {
+//!             ("name_too_long", Error::MaxLength(message)) => Some(format!(
+//!                 "the name must be at most {} characters.",
+//!                 message.params().max_length
+//!             )),
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(max_length = 4, message_key = "name_too_long")]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     name: "abcde".to_owned(),
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err().localize(&MyCatalog)).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "name": {
+//!                 "errors": ["the name must be at most 4 characters."]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
 //! ## Custom method
 //!
 //! You can use your custom validation using by `#[validate(custom)]`.
@@ -229,6 +495,367 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! If the rule needs the whole value, pass `self` to the rule function.
+//! `#[rule(...)]` on the enum accepts only `self`, and it is checked after the validations of the variant pass.
+//! The rule error is reported to the value itself by default, and `path = "field"` reports it to the field instead.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! fn check_period(start: &i32, end: &i32) -> Result<(), serde_valid::validation::Error> {
+//!     if start <= end {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "end must be after start.".to_owned(),
+//!         ))
+//!     }
+//! }
+//!
+//! fn check_shape(shape: &Shape) -> Result<(), serde_valid::validation::Error> {
+//!     match shape {
+//!         Shape::Period { start, end } => check_period(start, end),
+//!         Shape::Point(_) => Ok(()),
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[rule(check_period(start, end), path = "end")]
+//! struct Period {
+//!     start: i32,
+//!     end: i32,
+//! }
+//!
+//! #[derive(Validate)]
+//! #[rule(check_shape(self))]
+//! enum Shape {
+//!     Period { start: i32, end: i32 },
+//!     Point(i32),
+//! }
+//!
+//! assert_eq!(
+//!     serde_json::to_value(Period { start: 2, end: 1 }.validate().unwrap_err()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "end": {
+//!                 "errors": ["end must be after start."]
+//!             }
+//!         }
+//!     })
+//! );
+//!
+//! assert_eq!(
+//!     serde_json::to_value(Shape::Period { start: 2, end: 1 }.validate().unwrap_err()).unwrap(),
+//!     json!({
+//!         "errors": ["end must be after start."],
+//!         "properties": {}
+//!     })
+//! );
+//! assert!(Shape::Point(1).validate().is_ok());
+//! ```
+//!
+//! ## Field Comparison
+//!
+//! For the comparisons with the other field, like "end >= start" or "password == confirm",
+//! Serde Valid provides `minimum_field`, `maximum_field`, `exclusive_minimum_field`, `exclusive_maximum_field` and `eq_field`.
+//! The errors are reported under the annotated field, and `eq_field` refers to the other field by its serde name.
+//! The range comparisons are skipped when the referenced `Option` field is `None`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     start: i32,
+//!     #[validate(minimum_field = start)]
+//!     end: i32,
+//!     password: String,
+//!     #[validate(eq_field = password)]
+//!     confirm: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     start: 10,
+//!     end: 5,
+//!     password: "secret".to_owned(),
+//!     confirm: "secrets".to_owned(),
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "end": {
+//!                 "errors": ["the number must be `>= 10`."]
+//!             },
+//!             "confirm": {
+//!                 "errors": ["the value must be equal to the `password` field."]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! ## Validation Context
+//!
+//! If custom validations or rules need runtime data, use `#[validate(context = MyCtx)]`.
+//! The struct implements `ValidateWith<MyCtx>` instead of `Validate`,
+//! and `custom` and `rule` functions receive `&MyCtx` as the last argument.
+//! Nested `#[validate]` fields are validated with the same context.
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidateWith};
+//!
+//! struct Quota {
+//!     max_tags: usize,
+//! }
+//!
+//! fn tags_quota(tags: &Vec<String>, quota: &Quota) -> Result<(), serde_valid::validation::Error> {
+//!     if tags.len() <= quota.max_tags {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "too many tags.".to_owned(),
+//!         ))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[validate(context = Quota)]
+//! struct SampleStruct {
+//!     #[validate(custom(tags_quota))]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let s = SampleStruct {
+//!     tags: vec!["a".to_owned(), "b".to_owned()],
+//! };
+//!
+//! assert!(s.validate_with(&Quota { max_tags: 2 }).is_ok());
+//! assert!(s.validate_with(&Quota { max_tags: 1 }).is_err());
+//! ```
+//!
+//! ## Validation Groups
+//!
+//! If the validations depend on the use case, add `groups(...)` to the validator,
+//! and run the validators of the group by `validate_group`.
+//! The validators without `groups(...)` belong to `Group::DEFAULT`, which `validate` runs,
+//! and nested `#[validate]` fields are validated with the same group.
+//!
+//! ```rust
+//! use serde_valid::validation::Group;
+//! use serde_valid::Validate;
+//!
+//! const UPDATE: Group = Group::new("update");
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(minimum = 1, groups(update))]
+//!     id: u32,
+//!     #[validate(max_length = 4, groups(default, update))]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     id: 0,
+//!     name: "abc".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! assert!(s.validate_group(UPDATE).is_err());
+//! ```
+//!
+//! ## Fail Fast
+//!
+//! `is_valid` stops the validation at the first error, and returns only whether the value is valid without creating the errors.
+//! `validate_with_options` stops the validation when the errors reach the limit of `ValidateOptions`.
+//! The limit is shared with the nested `#[validate]` fields and the items of `Vec`, arrays, `Option` and maps.
+//!
+//! ```rust
+//! use serde_valid::validation::ValidateOptions;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(minimum = 0)]
+//!     val1: i32,
+//!     #[validate(min_length = 4)]
+//!     #[validate(pattern = r"^\d+$")]
+//!     val2: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     val1: -1,
+//!     val2: "abc".to_owned(),
+//! };
+//!
+//! assert!(!s.is_valid());
+//! assert_eq!(s.validate().unwrap_err().count(), 3);
+//! assert_eq!(
+//!     s.validate_with_options(ValidateOptions { fail_fast: false, max_errors: Some(2) })
+//!         .unwrap_err()
+//!         .count(),
+//!     2
+//! );
+//! ```
+//!
+//! ## Conditional Validation
+//!
+//! If the validator depends on the other fields, add `when = "..."` with the boolean expression,
+//! or `when(predicate)` with the function which takes `&Self`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! #[derive(PartialEq)]
+//! enum Kind {
+//!     Person,
+//!     Company,
+//! }
+//!
+//! fn is_company(s: &SampleStruct) -> bool {
+//!     s.kind == Kind::Company
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     kind: Kind,
+//!     #[validate(min_length = 1, when = "self.kind == Kind::Company")]
+//!     company_name: String,
+//!     #[validate(max_length = 13, when(is_company))]
+//!     tax_id: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     kind: Kind::Person,
+//!     company_name: "".to_owned(),
+//!     tax_id: "".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! ```
+//!
+//! `#[rule(dependent_required(field, dependent_fields...))]` requires the dependent `Option` fields
+//! when the `Option` field is `Some`, like `dependentRequired` of JSON Schema.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! #[rule(dependent_required(credit_card, billing_address))]
+//! struct SampleStruct {
+//!     credit_card: Option<String>,
+//!     billing_address: Option<String>,
+//! }
+//!
+//! let s = SampleStruct {
+//!     credit_card: Some("1234".to_owned()),
+//!     billing_address: None,
+//! };
+//!
+//! assert!(s.validate().is_err());
+//! ```
+//!
+//! ## Async Validation
+//!
+//! If custom validations or rules need to await, derive `AsyncValidate`
+//! and use `#[validate(custom_async(...))]` or `#[rule_async(...)]`.
+//! `validate_async` runs `validate` first, then awaits the async validators
+//! and nested `#[validate]` fields. It does not depend on a specific async runtime,
+//! and its future is `Send` as long as the async validators are.
+//!
+//! ```rust
+//! use serde_valid::{AsyncValidate, Validate};
+//!
+//! async fn unique_name(name: &String) -> Result<(), serde_valid::validation::Error> {
+//!     // e.g. look up the name in the database.
+//!     Ok(())
+//! }
+//!
+//! #[derive(Validate, AsyncValidate)]
+//! struct SampleStruct {
+//!     #[validate(min_length = 1)]
+//!     #[validate(custom_async(unique_name))]
+//!     name: String,
+//! }
+//!
+//! async fn run() {
+//!     let s = SampleStruct {
+//!         name: "alice".to_owned(),
+//!     };
+//!
+//!     assert!(s.validate_async().await.is_ok());
+//! }
+//! ```
+//!
+//! ## JSON Schema
+//!
+//! `#[derive(JsonSchema)]` exports the validations as a [draft 2020-12](https://json-schema.org/draft/2020-12/schema) JSON Schema, using the same attributes as `#[derive(Validate)]`.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::json::json;
+//! use serde_valid::{JsonSchema, Validate};
+//!
+//! #[derive(Deserialize, Validate, JsonSchema)]
+//! struct SampleStruct {
+//!     #[validate(min_length = 1)]
+//!     #[serde(rename = "userName")]
+//!     user_name: String,
+//!     #[validate(maximum = 120)]
+//!     age: Option<u8>,
+//! }
+//!
+//! assert_eq!(
+//!     SampleStruct::json_schema(),
+//!     json!({
+//!         "type": "object",
+//!         "properties": {
+//!             "userName": {
+//!                 "type": "string",
+//!                 "minLength": 1
+//!             },
+//!             "age": {
+//!                 "type": ["integer", "null"],
+//!                 "maximum": 120
+//!             }
+//!         },
+//!         "required": ["userName"]
+//!     })
+//! );
+//! ```
+//!
+//! The serde attributes are applied to the schema: `rename`, `rename_all`, `rename_all_fields`, `default`, `skip`,
+//! and the enum representations of `tag`, `content` and `untagged`.
+//! `#[serde(flatten)]` is not supported and fails to compile.
+//!
+//! ## JSON Schema Validation
+//!
+//! `serde_valid::json::Schema` validates `serde_json::Value` by a JSON Schema document, for the values which have no Rust types.
+//! The errors are reported in the same format as `#[derive(Validate)]`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::json::Schema;
+//!
+//! let schema = Schema::new(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "val": { "type": "integer", "maximum": 100 }
+//!     },
+//!     "required": ["val"]
+//! }))
+//! .unwrap();
+//!
+//! assert!(schema.validate(&json!({ "val": 10 })).is_ok());
+//! assert!(schema.validate(&json!({ "val": 1000 })).is_err());
+//! assert!(schema.validate(&json!({})).is_err());
+//! ```
+//!
 //! ## Validate Traits
 //!
 //! By implementing the validation trait, Your original type can uses Serde Valid validations.
@@ -265,26 +892,85 @@
 //! ```
 
 pub mod error;
+pub mod json_schema;
 mod traits;
 pub mod validation;
 
 pub use error::{
-    EnumerateErrorParams, Error, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
-    MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
-    MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
+    AnyOfErrorParams, ConstErrorParams, ContainsErrorParams, EnumerateErrorParams,
+    EqFieldErrorParams, Error, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
+    FormatErrorParams, MaxContainsErrorParams, MaxItemsErrorParams, MaxLengthErrorParams,
+    MaxPropertiesErrorParams, MaximumErrorParams, MinContainsErrorParams, MinItemsErrorParams,
+    MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams,
+    NotAllowedErrorParams, NotErrorParams, OneOfErrorParams, PatternErrorParams,
+    RequiredErrorParams, TypeErrorParams, UniqueItemsErrorParams,
 };
 use indexmap::IndexMap;
+pub use json_schema::JsonSchema;
+use std::collections::{BTreeMap, HashMap};
 use validation::ArrayErrors;
 pub use validation::{
-    ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
-    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
-    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
-    ValidateUniqueItems,
+    ValidateConst, ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum,
+    ValidateFormat, ValidateMaxItems, ValidateMaxLength, ValidateMaxProperties, ValidateMaximum,
+    ValidateMinItems, ValidateMinLength, ValidateMinProperties, ValidateMinimum,
+    ValidateMultipleOf, ValidatePattern, ValidateUniqueItems,
 };
 
 pub trait Validate {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
+
+    /// Validate with the validators of the group.
+    ///
+    /// The default implementation runs [`Validate::validate`] for [`Group::DEFAULT`] only.
+    ///
+    /// [`Group::DEFAULT`]: self::validation::Group::DEFAULT
+    fn validate_group(
+        &self,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        if group == self::validation::Group::DEFAULT {
+            self.validate()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validate with the options to stop the validation early.
+    ///
+    /// See [`ValidateOptions`](self::validation::ValidateOptions).
+    fn validate_with_options(
+        &self,
+        options: self::validation::ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group_with_options(self::validation::Group::DEFAULT, options)
+    }
+
+    /// Validate with the validators of the group and the options.
+    ///
+    /// The default implementation ignores the options and runs [`Validate::validate_group`].
+    fn validate_group_with_options(
+        &self,
+        group: self::validation::Group,
+        options: self::validation::ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let _ = options;
+        self.validate_group(group)
+    }
+
+    /// Whether the value is valid, stopping the validation at the first error.
+    ///
+    /// `#[derive(Validate)]` checks only the validity without creating the errors.
+    fn is_valid(&self) -> bool {
+        self.is_valid_group(self::validation::Group::DEFAULT)
+    }
+
+    /// Whether the value is valid for the validators of the group.
+    ///
+    /// The default implementation runs [`Validate::validate_group_with_options`] with fail-fast.
+    fn is_valid_group(&self, group: self::validation::Group) -> bool {
+        self.validate_group_with_options(group, self::validation::ValidateOptions::fail_fast())
+            .is_ok()
+    }
 }
 
 impl<T> Validate for Vec<T>
@@ -292,14 +978,175 @@ where
     T: Validate,
 {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group(self::validation::Group::DEFAULT)
+    }
+
+    fn validate_group(
+        &self,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group_with_options(group, Default::default())
+    }
+
+    fn validate_group_with_options(
+        &self,
+        group: self::validation::Group,
+        options: self::validation::ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items_with_options(self.iter(), group, options)
+    }
+
+    fn is_valid_group(&self, group: self::validation::Group) -> bool {
+        self.iter().all(|item| item.is_valid_group(group))
+    }
+}
+
+impl<T, const N: usize> Validate for [T; N]
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group(self::validation::Group::DEFAULT)
+    }
+
+    fn validate_group(
+        &self,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group_with_options(group, Default::default())
+    }
+
+    fn validate_group_with_options(
+        &self,
+        group: self::validation::Group,
+        options: self::validation::ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        validate_items_with_options(self.iter(), group, options)
+    }
+
+    fn is_valid_group(&self, group: self::validation::Group) -> bool {
+        self.iter().all(|item| item.is_valid_group(group))
+    }
+}
+
+impl<T> Validate for Option<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group(self::validation::Group::DEFAULT)
+    }
+
+    fn validate_group(
+        &self,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_group(group),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_group_with_options(
+        &self,
+        group: self::validation::Group,
+        options: self::validation::ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_group_with_options(group, options),
+            None => Ok(()),
+        }
+    }
+
+    fn is_valid_group(&self, group: self::validation::Group) -> bool {
+        match self {
+            Some(value) => value.is_valid_group(group),
+            None => true,
+        }
+    }
+}
+
+fn validate_items_with_options<'a, T>(
+    items: impl Iterator<Item = &'a T>,
+    group: self::validation::Group,
+    options: self::validation::ValidateOptions,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    T: Validate + 'a,
+{
+    let mut errors = IndexMap::new();
+    let mut reported = 0;
+    for (index, item) in items.enumerate() {
+        let item_options = options.remaining(|| reported);
+        if let Err(item_errors) = item.validate_group_with_options(group, item_options) {
+            if options.limit().is_some() {
+                reported += item_errors.count();
+            }
+            errors.insert(index, item_errors);
+
+            if options.is_exceeded(|| reported) {
+                break;
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Array(ArrayErrors::new(
+            vec![],
+            errors,
+        )))
+    }
+}
+
+/// Validation with the runtime context.
+///
+/// `#[derive(Validate)]` with `#[validate(context = MyCtx)]` implements `ValidateWith<MyCtx>`,
+/// and the other derived types implement `ValidateWith<Ctx>` for any `Ctx` by ignoring it.
+pub trait ValidateWith<Ctx: ?Sized> {
+    fn validate_with(&self, context: &Ctx) -> std::result::Result<(), self::validation::Errors>;
+
+    /// Validate with the validators of the group.
+    ///
+    /// The default implementation runs [`ValidateWith::validate_with`] for [`Group::DEFAULT`] only.
+    ///
+    /// [`Group::DEFAULT`]: self::validation::Group::DEFAULT
+    fn validate_group_with(
+        &self,
+        context: &Ctx,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        if group == self::validation::Group::DEFAULT {
+            self.validate_with(context)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T, Ctx> ValidateWith<Ctx> for Vec<T>
+where
+    T: ValidateWith<Ctx>,
+    Ctx: ?Sized,
+{
+    fn validate_with(&self, context: &Ctx) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group_with(context, self::validation::Group::DEFAULT)
+    }
+
+    fn validate_group_with(
+        &self,
+        context: &Ctx,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
         let mut items = IndexMap::new();
         for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
+            if let Err(errors) = item.validate_group_with(context, group) {
                 items.insert(index, errors);
             }
         }
 
-        if items.len() == 0 {
+        if items.is_empty() {
             Ok(())
         } else {
             Err(self::validation::Errors::Array(ArrayErrors::new(
@@ -310,19 +1157,28 @@ where
     }
 }
 
-impl<T, const N: usize> Validate for [T; N]
+impl<T, Ctx, const N: usize> ValidateWith<Ctx> for [T; N]
 where
-    T: Validate,
+    T: ValidateWith<Ctx>,
+    Ctx: ?Sized,
 {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+    fn validate_with(&self, context: &Ctx) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group_with(context, self::validation::Group::DEFAULT)
+    }
+
+    fn validate_group_with(
+        &self,
+        context: &Ctx,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
         let mut items = IndexMap::new();
         for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
+            if let Err(errors) = item.validate_group_with(context, group) {
                 items.insert(index, errors);
             }
         }
 
-        if items.len() == 0 {
+        if items.is_empty() {
             Ok(())
         } else {
             Err(self::validation::Errors::Array(ArrayErrors::new(
@@ -333,19 +1189,226 @@ where
     }
 }
 
-impl<T> Validate for Option<T>
+impl<T, Ctx> ValidateWith<Ctx> for Option<T>
 where
-    T: Validate,
+    T: ValidateWith<Ctx>,
+    Ctx: ?Sized,
 {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+    fn validate_with(&self, context: &Ctx) -> std::result::Result<(), self::validation::Errors> {
+        self.validate_group_with(context, self::validation::Group::DEFAULT)
+    }
+
+    fn validate_group_with(
+        &self,
+        context: &Ctx,
+        group: self::validation::Group,
+    ) -> std::result::Result<(), self::validation::Errors> {
         match self {
-            Some(value) => value.validate(),
+            Some(value) => value.validate_group_with(context, group),
             None => Ok(()),
         }
     }
 }
 
-pub use serde_valid_derive::Validate;
+/// Async validation.
+///
+/// `#[derive(AsyncValidate)]` awaits `#[validate(custom_async(...))]`, `#[rule_async(...)]`
+/// and the nested `#[validate]` fields after the synchronous [`Validate`] succeeds.
+///
+/// The futures are `Send`, so that they can be spawned on multi-threaded runtimes.
+pub trait AsyncValidate: Validate + Sync {
+    /// Run [`Validate::validate`], then await the async validators.
+    fn validate_async(
+        &self,
+    ) -> impl std::future::Future<Output = std::result::Result<(), self::validation::Errors>> + Send
+    {
+        async move {
+            self.validate()?;
+            self.validate_async_validators().await
+        }
+    }
+
+    /// Await only the async validators, including those of the nested fields.
+    fn validate_async_validators(
+        &self,
+    ) -> impl std::future::Future<Output = std::result::Result<(), self::validation::Errors>> + Send;
+}
+
+impl<T> AsyncValidate for Vec<T>
+where
+    T: AsyncValidate,
+{
+    async fn validate_async_validators(&self) -> std::result::Result<(), self::validation::Errors> {
+        let mut items = IndexMap::new();
+        for (index, item) in self.iter().enumerate() {
+            if let Err(errors) = item.validate_async_validators().await {
+                items.insert(index, errors);
+            }
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(self::validation::Errors::Array(ArrayErrors::new(
+                vec![],
+                items,
+            )))
+        }
+    }
+}
+
+impl<T, const N: usize> AsyncValidate for [T; N]
+where
+    T: AsyncValidate,
+{
+    async fn validate_async_validators(&self) -> std::result::Result<(), self::validation::Errors> {
+        let mut items = IndexMap::new();
+        for (index, item) in self.iter().enumerate() {
+            if let Err(errors) = item.validate_async_validators().await {
+                items.insert(index, errors);
+            }
+        }
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(self::validation::Errors::Array(ArrayErrors::new(
+                vec![],
+                items,
+            )))
+        }
+    }
+}
+
+impl<T> AsyncValidate for Option<T>
+where
+    T: AsyncValidate,
+{
+    async fn validate_async_validators(&self) -> std::result::Result<(), self::validation::Errors> {
+        match self {
+            Some(value) => value.validate_async_validators().await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validate the values of the map, and report the errors at the properties of the keys.
+macro_rules! impl_validate_map {
+    ($map:ident<K, V $(, $hasher:ident)?>) => {
+        impl<K, V $(, $hasher)?> Validate for $map<K, V $(, $hasher)?>
+        where
+            K: std::fmt::Display,
+            V: Validate,
+        {
+            fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+                self.validate_group(self::validation::Group::DEFAULT)
+            }
+
+            fn validate_group(
+                &self,
+                group: self::validation::Group,
+            ) -> std::result::Result<(), self::validation::Errors> {
+                self.validate_group_with_options(group, Default::default())
+            }
+
+            fn validate_group_with_options(
+                &self,
+                group: self::validation::Group,
+                options: self::validation::ValidateOptions,
+            ) -> std::result::Result<(), self::validation::Errors> {
+                let mut reported = 0;
+                collect_properties_errors(self.iter().map_while(|(key, value)| {
+                    if options.is_exceeded(|| reported) {
+                        return None;
+                    }
+
+                    let result =
+                        value.validate_group_with_options(group, options.remaining(|| reported));
+                    if let (Some(_), Err(errors)) = (options.limit(), &result) {
+                        reported += errors.count();
+                    }
+                    Some((key, result))
+                }))
+            }
+
+            fn is_valid_group(&self, group: self::validation::Group) -> bool {
+                self.values().all(|value| value.is_valid_group(group))
+            }
+        }
+
+        impl<K, V, Ctx $(, $hasher)?> ValidateWith<Ctx> for $map<K, V $(, $hasher)?>
+        where
+            K: std::fmt::Display,
+            V: ValidateWith<Ctx>,
+            Ctx: ?Sized,
+        {
+            fn validate_with(
+                &self,
+                context: &Ctx,
+            ) -> std::result::Result<(), self::validation::Errors> {
+                self.validate_group_with(context, self::validation::Group::DEFAULT)
+            }
+
+            fn validate_group_with(
+                &self,
+                context: &Ctx,
+                group: self::validation::Group,
+            ) -> std::result::Result<(), self::validation::Errors> {
+                collect_properties_errors(
+                    self.iter()
+                        .map(|(key, value)| (key, value.validate_group_with(context, group))),
+                )
+            }
+        }
+
+        impl<K, V $(, $hasher)?> AsyncValidate for $map<K, V $(, $hasher)?>
+        where
+            K: std::fmt::Display + Sync,
+            V: AsyncValidate,
+            $($hasher: Sync,)?
+        {
+            async fn validate_async_validators(
+                &self,
+            ) -> std::result::Result<(), self::validation::Errors> {
+                let mut results = vec![];
+                for (key, value) in self.iter() {
+                    results.push((key, value.validate_async_validators().await));
+                }
+
+                collect_properties_errors(results.into_iter())
+            }
+        }
+    };
+}
+
+impl_validate_map!(HashMap<K, V, S>);
+impl_validate_map!(BTreeMap<K, V>);
+impl_validate_map!(IndexMap<K, V, S>);
+
+fn collect_properties_errors<K>(
+    results: impl Iterator<Item = (K, std::result::Result<(), self::validation::Errors>)>,
+) -> std::result::Result<(), self::validation::Errors>
+where
+    K: std::fmt::Display,
+{
+    let properties = results
+        .filter_map(|(key, result)| {
+            result
+                .err()
+                .map(|errors| (std::borrow::Cow::Owned(key.to_string()), errors))
+        })
+        .collect::<IndexMap<_, _>>();
+
+    if properties.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Object(
+            self::validation::ObjectErrors::new(vec![], properties),
+        ))
+    }
+}
+
+pub use serde_valid_derive::{AsyncValidate, JsonSchema, Validate};
 
 pub mod json;
 #[cfg(feature = "toml")]
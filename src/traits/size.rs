@@ -5,7 +5,7 @@ pub trait Size {
     fn size(&self) -> usize;
 }
 
-impl<K, V> Size for HashMap<K, V> {
+impl<K, V, S> Size for HashMap<K, V, S> {
     fn size(&self) -> usize {
         self.len()
     }
@@ -17,6 +17,12 @@ impl<K, V> Size for BTreeMap<K, V> {
     }
 }
 
+impl<K, V, S> Size for indexmap::IndexMap<K, V, S> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
 impl Size for serde_json::Map<String, serde_json::Value> {
     fn size(&self) -> usize {
         self.len()
@@ -1,27 +1,41 @@
 mod array;
 mod error;
 mod generic;
+mod group;
 mod numeric;
 mod object;
+mod options;
 mod string;
+mod validity;
 
 use crate::{
-    EnumerateErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams, MinLengthErrorParams,
-    MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams,
+    ConstErrorParams, EnumerateErrorParams, ExclusiveMaximumErrorParams,
+    ExclusiveMinimumErrorParams, FormatErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams,
+    MaximumErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
+    MultipleOfErrorParams, PatternErrorParams,
 };
-pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
+pub use array::{
+    validate_contains, validate_max_contains, validate_min_contains, PrefixItem, ValidateContains,
+    ValidateMaxItems, ValidateMinItems, ValidateUniqueItems,
+};
+pub(crate) use error::escape_json_pointer;
+#[cfg(feature = "ja")]
+pub use error::JapaneseCatalog;
 pub use error::{
-    ArrayErrors, Composited, Error, Errors, IntoError, MapErrors, ObjectErrors, VecErrors,
+    ArrayErrors, Composited, DetailedError, EnglishCatalog, Error, Errors, FlatError, Formatted,
+    IntoError, MapErrors, MergeError, MessageCatalog, MessageFormatter, ObjectErrors, VecErrors,
 };
-pub use generic::ValidateEnumerate;
+pub use generic::{ValidateConst, ValidateEnumerate};
+pub use group::Group;
 pub use numeric::{
-    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum,
-    ValidateMultipleOf,
+    FieldLimit, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum,
+    ValidateMinimum, ValidateMultipleOf,
 };
-pub use object::{ValidateMaxProperties, ValidateMinProperties};
+pub use object::{ValidateEntries, ValidateMaxProperties, ValidateMinProperties};
+pub use options::{count_errors, ValidateOptions};
 pub use serde_valid_literal::{Literal, Number, Pattern};
-pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
+pub use string::{Format, ValidateFormat, ValidateMaxLength, ValidateMinLength, ValidatePattern};
+pub use validity::{PropertiesErrors, Validity};
 
 macro_rules! impl_composited_validation_1args {
     (
@@ -61,18 +75,9 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$ErrorParams>> {
-                    let mut errors = vec![];
-                    self.iter().for_each(|item| {
-                        item.$validate_composited_method($limit)
-                            .map_err(|error| errors.push(error))
-                            .ok();
-                    });
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    crate::validation::Composited::from_items(
+                        self.iter().map(|item| item.$validate_composited_method($limit)),
+                    )
                 }
             }
 
@@ -84,18 +89,9 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$ErrorParams>> {
-                    let mut errors = vec![];
-                    self.iter().for_each(|item| {
-                        item.$validate_composited_method($limit)
-                            .map_err(|error| errors.push(error))
-                            .ok();
-                    });
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    crate::validation::Composited::from_items(
+                        self.iter().map(|item| item.$validate_composited_method($limit)),
+                    )
                 }
             }
 
@@ -139,18 +135,10 @@ macro_rules! impl_composited_validation_1args {
                 &self,
                 limit: T,
             ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
-                let mut errors = vec![];
-                self.iter().for_each(|item| {
-                    item.$validate_composited_method(limit)
-                        .map_err(|error| errors.push(error))
-                        .ok();
-                });
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(crate::validation::Composited::Array(errors))
-                }
+                crate::validation::Composited::from_items(
+                    self.iter()
+                        .map(|item| item.$validate_composited_method(limit)),
+                )
             }
         }
 
@@ -163,18 +151,10 @@ macro_rules! impl_composited_validation_1args {
                 &self,
                 limit: T,
             ) -> Result<(), crate::validation::Composited<$ErrorParams>> {
-                let mut errors = vec![];
-                self.iter().for_each(|item| {
-                    item.$validate_composited_method(limit)
-                        .map_err(|error| errors.push(error))
-                        .ok();
-                });
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(crate::validation::Composited::Array(errors))
-                }
+                crate::validation::Composited::from_items(
+                    self.iter()
+                        .map(|item| item.$validate_composited_method(limit)),
+                )
             }
         }
 
@@ -285,6 +265,15 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedFormat {
+        fn validate_composited_format(
+            &self,
+            format: Format,
+        ) -> Result<(), Composited<FormatErrorParams>>;
+    }
+);
+
 impl_composited_validation_1args!(
     pub trait ValidateCompositedPattern {
         fn validate_composited_pattern(
@@ -322,3 +311,12 @@ impl_composited_validation_1args!(
         ) -> Result<(), Composited<EnumerateErrorParams>>;
     }
 );
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedConst<T> {
+        fn validate_composited_const(
+            &self,
+            const_value: T,
+        ) -> Result<(), Composited<ConstErrorParams>>;
+    }
+);
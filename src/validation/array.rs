@@ -1,9 +1,15 @@
+mod contains;
 mod max_items;
 mod min_items;
+mod prefix_items;
 mod unique_items;
 
+pub use contains::{
+    validate_contains, validate_max_contains, validate_min_contains, ValidateContains,
+};
 pub use max_items::ValidateMaxItems;
 pub use min_items::ValidateMinItems;
+pub use prefix_items::PrefixItem;
 pub use unique_items::ValidateUniqueItems;
 
 use crate::{MaxItemsErrorParams, MinItemsErrorParams};
@@ -0,0 +1,111 @@
+use crate::{ContainsErrorParams, MaxContainsErrorParams, MinContainsErrorParams};
+
+/// Contains validation of the array items.
+///
+/// The items which pass the validation of `contains(...)` are counted,
+/// and the count is checked by [`validate_contains`], [`validate_min_contains`]
+/// and [`validate_max_contains`].
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#contains>
+pub trait ValidateContains {
+    type Item;
+
+    /// The number of the matching items, or `None` if there is no array.
+    fn count_contains<F>(&self, is_match: F) -> Option<usize>
+    where
+        F: FnMut(&Self::Item) -> bool;
+}
+
+impl<T> ValidateContains for Vec<T> {
+    type Item = T;
+
+    fn count_contains<F>(&self, mut is_match: F) -> Option<usize>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        Some(self.iter().filter(|item| is_match(item)).count())
+    }
+}
+
+impl<T, const N: usize> ValidateContains for [T; N] {
+    type Item = T;
+
+    fn count_contains<F>(&self, mut is_match: F) -> Option<usize>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        Some(self.iter().filter(|item| is_match(item)).count())
+    }
+}
+
+impl<T> ValidateContains for Option<T>
+where
+    T: ValidateContains,
+{
+    type Item = T::Item;
+
+    fn count_contains<F>(&self, is_match: F) -> Option<usize>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.as_ref()
+            .and_then(|value| value.count_contains(is_match))
+    }
+}
+
+/// Without `min_contains`, at least one item must match.
+pub fn validate_contains(contains_count: usize) -> Result<(), ContainsErrorParams> {
+    if contains_count >= 1 {
+        Ok(())
+    } else {
+        Err(ContainsErrorParams {})
+    }
+}
+
+pub fn validate_min_contains(
+    contains_count: usize,
+    min_contains: usize,
+) -> Result<(), MinContainsErrorParams> {
+    if min_contains <= contains_count {
+        Ok(())
+    } else {
+        Err(MinContainsErrorParams::new(min_contains))
+    }
+}
+
+pub fn validate_max_contains(
+    contains_count: usize,
+    max_contains: usize,
+) -> Result<(), MaxContainsErrorParams> {
+    if contains_count <= max_contains {
+        Ok(())
+    } else {
+        Err(MaxContainsErrorParams::new(max_contains))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_contains_vec_type() {
+        assert_eq!(
+            ValidateContains::count_contains(&vec![1, 10, 20], |item| *item >= 10),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_count_contains_option_none() {
+        let items: Option<Vec<i32>> = None;
+        assert_eq!(items.count_contains(|item| *item >= 10), None);
+    }
+
+    #[test]
+    fn test_validate_contains_count() {
+        assert!(validate_contains(0).is_err());
+        assert!(validate_min_contains(1, 2).is_err());
+        assert!(validate_max_contains(2, 2).is_ok());
+    }
+}
@@ -0,0 +1,99 @@
+/// Item at the position of the tuple or the array, for `#[validate(prefix_items(...))]`.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#tuple-validation>
+pub trait PrefixItem<const INDEX: usize> {
+    type Item;
+
+    /// The item at `INDEX`, or `None` if there is no item.
+    fn prefix_item(&self) -> Option<&Self::Item>;
+}
+
+impl<T, const INDEX: usize> PrefixItem<INDEX> for Vec<T> {
+    type Item = T;
+
+    fn prefix_item(&self) -> Option<&Self::Item> {
+        self.get(INDEX)
+    }
+}
+
+impl<T, const N: usize, const INDEX: usize> PrefixItem<INDEX> for [T; N] {
+    type Item = T;
+
+    fn prefix_item(&self) -> Option<&Self::Item> {
+        self.get(INDEX)
+    }
+}
+
+impl<T, const INDEX: usize> PrefixItem<INDEX> for Option<T>
+where
+    T: PrefixItem<INDEX>,
+{
+    type Item = T::Item;
+
+    fn prefix_item(&self) -> Option<&Self::Item> {
+        self.as_ref().and_then(PrefixItem::<INDEX>::prefix_item)
+    }
+}
+
+macro_rules! impl_tuple_prefix_item {
+    ([$($T:ident),+], $index:tt, $Item:ident) => {
+        impl<$($T),+> PrefixItem<$index> for ($($T,)+) {
+            type Item = $Item;
+
+            fn prefix_item(&self) -> Option<&Self::Item> {
+                Some(&self.$index)
+            }
+        }
+    };
+}
+
+macro_rules! impl_tuple_prefix_items {
+    ($types:tt, $($index:tt => $Item:ident),+) => {
+        $(impl_tuple_prefix_item!($types, $index, $Item);)+
+    };
+}
+
+impl_tuple_prefix_items!([T1], 0 => T1);
+impl_tuple_prefix_items!([T1, T2], 0 => T1, 1 => T2);
+impl_tuple_prefix_items!([T1, T2, T3], 0 => T1, 1 => T2, 2 => T3);
+impl_tuple_prefix_items!([T1, T2, T3, T4], 0 => T1, 1 => T2, 2 => T3, 3 => T4);
+impl_tuple_prefix_items!(
+    [T1, T2, T3, T4, T5],
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5
+);
+impl_tuple_prefix_items!(
+    [T1, T2, T3, T4, T5, T6],
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6
+);
+impl_tuple_prefix_items!(
+    [T1, T2, T3, T4, T5, T6, T7],
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7
+);
+impl_tuple_prefix_items!(
+    [T1, T2, T3, T4, T5, T6, T7, T8],
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7, 7 => T8
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_item_tuple_type() {
+        let value = ("a".to_owned(), 1u32);
+        assert_eq!(PrefixItem::<0>::prefix_item(&value), Some(&"a".to_owned()));
+        assert_eq!(PrefixItem::<1>::prefix_item(&value), Some(&1));
+    }
+
+    #[test]
+    fn test_prefix_item_vec_type() {
+        assert_eq!(PrefixItem::<1>::prefix_item(&vec![1, 2]), Some(&2));
+        assert_eq!(PrefixItem::<2>::prefix_item(&vec![1, 2]), None);
+    }
+
+    #[test]
+    fn test_prefix_item_option_type() {
+        let value: Option<(i32, i32)> = None;
+        assert_eq!(PrefixItem::<0>::prefix_item(&value), None);
+    }
+}
@@ -1,19 +1,34 @@
 mod array_erros;
+mod catalog;
+mod detailed_error;
 mod error;
 mod errors;
+mod flat_error;
+mod formatter;
+mod merge_error;
 mod object_errors;
 
 use crate::error::ToDefaultMessage;
 pub use crate::error::{
-    EnumerateErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
-    Message, MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams,
-    MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
+    ConstErrorParams, EnumerateErrorParams, EqFieldErrorParams, ExclusiveMaximumErrorParams,
+    ExclusiveMinimumErrorParams, FormatErrorParams, MaxItemsErrorParams, MaxLengthErrorParams,
+    MaxPropertiesErrorParams, MaximumErrorParams, Message, MessageFn, MinItemsErrorParams,
+    MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams, MultipleOfErrorParams,
+    PatternErrorParams, UniqueItemsErrorParams,
 };
 pub use array_erros::ArrayErrors;
+#[cfg(feature = "ja")]
+pub use catalog::JapaneseCatalog;
+pub use catalog::{EnglishCatalog, MessageCatalog};
+pub use detailed_error::DetailedError;
 pub use error::Error;
+pub(crate) use errors::count_errors;
 pub use errors::Errors;
+pub(crate) use flat_error::escape_json_pointer;
+pub use flat_error::FlatError;
+pub use formatter::{Formatted, MessageFormatter};
 use indexmap::IndexMap;
+pub use merge_error::MergeError;
 pub use object_errors::ObjectErrors;
 
 pub type VecErrors = Vec<Error>;
@@ -25,31 +40,80 @@ pub enum Composited<ErrorParams> {
     Array(Vec<Composited<ErrorParams>>),
 }
 
+impl<ErrorParams> Composited<ErrorParams> {
+    /// Composite the results of the items.
+    ///
+    /// The valid items are kept as the empty `Composited::Array`,
+    /// so that the errors are reported at the index of the item.
+    pub(crate) fn from_items(
+        results: impl Iterator<Item = Result<(), Composited<ErrorParams>>>,
+    ) -> Result<(), Self> {
+        let mut has_errors = false;
+        let items = results
+            .map(|result| match result {
+                Ok(()) => Composited::Array(vec![]),
+                Err(error) => {
+                    has_errors = true;
+                    error
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if has_errors {
+            Err(Composited::Array(items))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Composited::Array(items) if items.is_empty())
+    }
+}
+
 pub trait IntoError<Params>: Sized
 where
-    Params: ToDefaultMessage,
+    Params: ToDefaultMessage + 'static,
 {
+    /// Convert into the error with the default message, which needs no allocation.
     fn into_error(self) -> Error {
-        self.into_error_by(Params::to_default_message)
+        self.into_error_of(&Message::with_default_message)
+    }
+
+    fn into_error_by<F>(self, format_fn: F) -> Error
+    where
+        F: Fn(&Params) -> String + Send + Sync + 'static,
+    {
+        self.into_error_with(std::sync::Arc::new(format_fn))
+    }
+
+    /// Convert into the error whose messages share the format function.
+    fn into_error_with(self, format_fn: MessageFn<Params>) -> Error {
+        self.into_error_of(&|params| Message::with_message_fn(params, format_fn.clone()))
     }
 
-    fn into_error_by(self, format_fn: fn(&Params) -> String) -> Error;
+    /// Convert into the error, creating the message of each parameters by `to_message`.
+    fn into_error_of(self, to_message: &dyn Fn(Params) -> Message<Params>) -> Error;
 }
 
 macro_rules! impl_into_error {
     ($ErrorType:ident) => {
         paste::paste! {
             impl IntoError<[<$ErrorType ErrorParams>]> for Composited<[<$ErrorType ErrorParams>]> {
-                fn into_error_by(self, format_fn: fn(&[<$ErrorType ErrorParams>]) -> String) -> Error {
+                fn into_error_of(
+                    self,
+                    to_message: &dyn Fn([<$ErrorType ErrorParams>]) -> Message<[<$ErrorType ErrorParams>]>,
+                ) -> Error {
                     match self {
-                        Composited::Single(single) => Error::$ErrorType(Message::new(single, format_fn)),
+                        Composited::Single(single) => Error::$ErrorType(to_message(single)),
                         Composited::Array(array) => Error::Items(ArrayErrors::new(
                             Vec::with_capacity(0),
                             array
                                 .into_iter()
                                 .enumerate()
+                                .filter(|(_, params)| !params.is_empty())
                                 .map(|(index, params)| {
-                                    (index, Errors::NewType(vec![params.into_error_by(format_fn)]))
+                                    (index, Errors::NewType(vec![params.into_error_of(to_message)]))
                                 })
                                 .collect::<IndexMap<_, _>>(),
                         )),
@@ -62,6 +126,8 @@ macro_rules! impl_into_error {
 
 // Global
 impl_into_error!(Enumerate);
+impl_into_error!(Const);
+impl_into_error!(EqField);
 
 // Numeric
 impl_into_error!(Maximum);
@@ -74,6 +140,7 @@ impl_into_error!(MultipleOf);
 impl_into_error!(MaxLength);
 impl_into_error!(MinLength);
 impl_into_error!(Pattern);
+impl_into_error!(Format);
 
 // Array
 impl_into_error!(MaxItems);
@@ -1,6 +1,8 @@
 use indexmap::IndexMap;
 
-use super::{Errors, VecErrors};
+use super::detailed_error::to_detailed_errors;
+use super::errors::{collect_flat_errors, count_errors};
+use super::{Error, Errors, MergeError, VecErrors};
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 pub struct ArrayErrors {
@@ -13,18 +15,50 @@ impl ArrayErrors {
         Self { errors, items }
     }
 
-    pub fn merge(mut self, other: ArrayErrors) -> Self {
+    pub(crate) fn count(&self) -> usize {
+        count_errors(&self.errors) + self.items.values().map(Errors::count).sum::<usize>()
+    }
+
+    /// Merge the errors of the other validation, like [`Errors::merge`].
+    pub fn merge(&mut self, other: ArrayErrors) -> Result<(), MergeError> {
+        self.merge_at(other, "")
+    }
+
+    pub(crate) fn merge_at(&mut self, other: ArrayErrors, pointer: &str) -> Result<(), MergeError> {
         self.errors.extend(other.errors);
 
+        let mut result = Ok(());
         for (index, item) in other.items {
-            match self.items.get_mut(&index) {
-                Some(errors) => errors.merge(item),
+            let merged = match self.items.get_mut(&index) {
+                Some(errors) => errors.merge_at(item, &format!("{pointer}/{index}")),
                 None => {
                     self.items.insert(index, item);
+                    Ok(())
                 }
             };
+            // Report the first conflict after merging all the other items.
+            result = result.and(merged);
+        }
+        result
+    }
+
+    /// Errors tree whose messages are replaced by [`DetailedError`](super::DetailedError).
+    pub fn to_detailed(&self) -> serde_json::Value {
+        serde_json::json!({
+            "errors": to_detailed_errors(&self.errors),
+            "items": self
+                .items
+                .iter()
+                .map(|(index, item)| (index.to_string(), item.to_detailed()))
+                .collect::<serde_json::Map<_, _>>(),
+        })
+    }
+
+    pub(crate) fn collect_flat<'a>(&'a self, pointer: &str, flat: &mut Vec<(String, &'a Error)>) {
+        collect_flat_errors(&self.errors, pointer, flat);
+        for (index, item) in &self.items {
+            item.collect_flat(&format!("{pointer}/{index}"), flat);
         }
-        return self;
     }
 }
 
@@ -0,0 +1,244 @@
+#[cfg(feature = "ja")]
+mod japanese;
+
+#[cfg(feature = "ja")]
+pub use japanese::JapaneseCatalog;
+
+use crate::error::ToDefaultMessage;
+
+use super::{Error, Errors};
+
+/// Catalog of the error messages, for the internationalization.
+///
+/// The key is `message_key` of the validator, like `#[validate(..., message_key = "...")]`,
+/// or the keyword of the error, like `"maximum"`.
+/// Returns `None` to keep the message of the validator.
+///
+/// Only the errors with the default message or `message_key` are looked up,
+/// so the custom messages of `message` and `message_fn` are kept.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::validation::{Error, MessageCatalog};
+/// use serde_valid::Validate;
+///
+/// struct MyCatalog;
+///
+/// impl MessageCatalog for MyCatalog {
+///     fn message(&self, key: &str, error: &Error) -> Option<String> {
+///         match (key, error) {
+///             ("name_too_long", Error::MaxLength(message)) => Some(format!(
+///                 "the name must be at most {} characters.",
+///                 message.params().max_length
+///             )),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct SampleStruct {
+///     #[validate(max_length = 4, message_key = "name_too_long")]
+///     name: String,
+/// }
+///
+/// let errors = SampleStruct {
+///     name: "abcde".to_owned(),
+/// }
+/// .validate()
+/// .unwrap_err()
+/// .localize(&MyCatalog);
+///
+/// assert_eq!(
+///     serde_json::to_value(errors).unwrap(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "name": {
+///                 "errors": ["the name must be at most 4 characters."]
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub trait MessageCatalog {
+    fn message(&self, key: &str, error: &Error) -> Option<String>;
+}
+
+/// Catalog of the default messages in English.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct EnglishCatalog;
+
+impl MessageCatalog for EnglishCatalog {
+    fn message(&self, key: &str, error: &Error) -> Option<String> {
+        if key == error.keyword() {
+            error.default_message()
+        } else {
+            None
+        }
+    }
+}
+
+/// Apply `$body` to the message of the error, or evaluate `$other` if the error has no message.
+macro_rules! match_message {
+    ($error:expr, $message:ident => $body:expr, _ => $other:expr) => {
+        match $error {
+            Error::Minimum($message) => $body,
+            Error::Maximum($message) => $body,
+            Error::ExclusiveMinimum($message) => $body,
+            Error::ExclusiveMaximum($message) => $body,
+            Error::MultipleOf($message) => $body,
+            Error::MinLength($message) => $body,
+            Error::MaxLength($message) => $body,
+            Error::Pattern($message) => $body,
+            Error::Format($message) => $body,
+            Error::MinItems($message) => $body,
+            Error::MaxItems($message) => $body,
+            Error::UniqueItems($message) => $body,
+            Error::Contains($message) => $body,
+            Error::MinContains($message) => $body,
+            Error::MaxContains($message) => $body,
+            Error::MinProperties($message) => $body,
+            Error::MaxProperties($message) => $body,
+            Error::Enumerate($message) => $body,
+            Error::Const($message) => $body,
+            Error::EqField($message) => $body,
+            Error::Type($message) => $body,
+            Error::Required($message) => $body,
+            Error::NotAllowed($message) => $body,
+            Error::AnyOf($message) => $body,
+            Error::OneOf($message) => $body,
+            Error::Not($message) => $body,
+            Error::Custom(_) | Error::Items(_) | Error::Properties(_) => $other,
+        }
+    };
+}
+
+impl Error {
+    /// Key of the message in [`MessageCatalog`], which is `message_key` of the validator or the keyword.
+    pub fn message_key(&self) -> &'static str {
+        match_message!(self, message => message.key().unwrap_or(self.keyword()), _ => self.keyword())
+    }
+
+    /// Set `message_key` of the error, including the errors of the items.
+    pub fn set_message_key(&mut self, key: &'static str) {
+        match_message!(self, message => message.set_key(key), _ => {
+            if let Error::Items(errors) = self {
+                errors
+                    .errors
+                    .iter_mut()
+                    .chain(errors.items.values_mut().flat_map(Errors::errors_mut))
+                    .for_each(|error| error.set_message_key(key));
+            }
+        })
+    }
+
+    /// Default message of the validation, ignoring the custom message.
+    pub fn default_message(&self) -> Option<String> {
+        match_message!(self, message => Some(message.params().to_default_message()), _ => None)
+    }
+
+    /// Re-render the messages by the catalog, including the nested errors.
+    pub(crate) fn localize_mut<C>(&mut self, catalog: &C)
+    where
+        C: MessageCatalog + ?Sized,
+    {
+        match self {
+            Error::Items(errors) => {
+                errors
+                    .errors
+                    .iter_mut()
+                    .for_each(|error| error.localize_mut(catalog));
+                errors
+                    .items
+                    .values_mut()
+                    .for_each(|errors| errors.localize_mut(catalog));
+            }
+            Error::Properties(errors) => {
+                errors
+                    .errors
+                    .iter_mut()
+                    .for_each(|error| error.localize_mut(catalog));
+                errors
+                    .properties
+                    .values_mut()
+                    .for_each(|errors| errors.localize_mut(catalog));
+            }
+            Error::AnyOf(message) => localize_schemas(&mut message.params_mut().errors, catalog),
+            Error::OneOf(message) => localize_schemas(&mut message.params_mut().errors, catalog),
+            _ => {}
+        }
+
+        let localizable = match_message!(self, message => message.is_default() || message.key().is_some(), _ => false);
+        if !localizable {
+            return;
+        }
+        if let Some(rendered) = catalog.message(self.message_key(), self) {
+            match_message!(self, message => message.set_message(rendered), _ => ())
+        }
+    }
+}
+
+fn localize_schemas<C>(schemas: &mut [super::VecErrors], catalog: &C)
+where
+    C: MessageCatalog + ?Sized,
+{
+    schemas
+        .iter_mut()
+        .flatten()
+        .for_each(|error| error.localize_mut(catalog));
+}
+
+impl Errors {
+    /// Re-render the messages in the errors by the catalog.
+    ///
+    /// The messages are looked up by the keyword and the parameters of the error,
+    /// or by `message_key` of the validator.
+    /// The custom messages without `message_key` are kept.
+    pub fn localize<C>(mut self, catalog: &C) -> Self
+    where
+        C: MessageCatalog + ?Sized,
+    {
+        self.localize_mut(catalog);
+        self
+    }
+
+    fn localize_mut<C>(&mut self, catalog: &C)
+    where
+        C: MessageCatalog + ?Sized,
+    {
+        match self {
+            Errors::Array(errors) => {
+                errors
+                    .errors
+                    .iter_mut()
+                    .for_each(|error| error.localize_mut(catalog));
+                errors
+                    .items
+                    .values_mut()
+                    .for_each(|errors| errors.localize_mut(catalog));
+            }
+            Errors::Object(errors) => {
+                errors
+                    .errors
+                    .iter_mut()
+                    .for_each(|error| error.localize_mut(catalog));
+                errors
+                    .properties
+                    .values_mut()
+                    .for_each(|errors| errors.localize_mut(catalog));
+            }
+            Errors::NewType(errors) => errors
+                .iter_mut()
+                .for_each(|error| error.localize_mut(catalog)),
+        }
+    }
+
+    fn errors_mut(&mut self) -> &mut super::VecErrors {
+        match self {
+            Errors::Array(errors) => &mut errors.errors,
+            Errors::Object(errors) => &mut errors.errors,
+            Errors::NewType(errors) => errors,
+        }
+    }
+}
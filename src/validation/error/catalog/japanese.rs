@@ -0,0 +1,113 @@
+use itertools::Itertools;
+
+use super::MessageCatalog;
+use crate::validation::Error;
+
+/// Catalog of the default messages in Japanese.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct JapaneseCatalog;
+
+impl MessageCatalog for JapaneseCatalog {
+    fn message(&self, key: &str, error: &Error) -> Option<String> {
+        if key != error.keyword() {
+            return None;
+        }
+
+        let message = match error {
+            Error::Minimum(message) => format!(
+                "数値は `>= {}` でなければなりません。",
+                message.params().minimum
+            ),
+            Error::Maximum(message) => format!(
+                "数値は `<= {}` でなければなりません。",
+                message.params().maximum
+            ),
+            Error::ExclusiveMinimum(message) => format!(
+                "数値は `> {}` でなければなりません。",
+                message.params().exclusive_minimum
+            ),
+            Error::ExclusiveMaximum(message) => format!(
+                "数値は `< {}` でなければなりません。",
+                message.params().exclusive_maximum
+            ),
+            Error::MultipleOf(message) => format!(
+                "値は `{}` の倍数でなければなりません。",
+                message.params().multiple_of
+            ),
+            Error::MinLength(message) => format!(
+                "値の長さは `>= {}` でなければなりません。",
+                message.params().min_length
+            ),
+            Error::MaxLength(message) => format!(
+                "値の長さは `<= {}` でなければなりません。",
+                message.params().max_length
+            ),
+            Error::Pattern(message) => format!(
+                "値はパターン \"{}\" に一致しなければなりません。",
+                message.params().pattern
+            ),
+            Error::Format(message) => format!(
+                "値はフォーマット \"{}\" に一致しなければなりません。",
+                message.params().format
+            ),
+            Error::MinItems(message) => format!(
+                "要素数は `>= {}` でなければなりません。",
+                message.params().min_items
+            ),
+            Error::MaxItems(message) => format!(
+                "要素数は `<= {}` でなければなりません。",
+                message.params().max_items
+            ),
+            Error::UniqueItems(_) => "要素は一意でなければなりません。".to_owned(),
+            Error::Contains(_) => "一致する要素を少なくとも1つ含まなければなりません。".to_owned(),
+            Error::MinContains(message) => format!(
+                "一致する要素の数は `>= {}` でなければなりません。",
+                message.params().min_contains
+            ),
+            Error::MaxContains(message) => format!(
+                "一致する要素の数は `<= {}` でなければなりません。",
+                message.params().max_contains
+            ),
+            Error::MinProperties(message) => format!(
+                "プロパティの数は `>= {}` でなければなりません。",
+                message.params().min_properties
+            ),
+            Error::MaxProperties(message) => format!(
+                "プロパティの数は `<= {}` でなければなりません。",
+                message.params().max_properties
+            ),
+            Error::Enumerate(message) => format!(
+                "値は [{}] のいずれかでなければなりません。",
+                message.params().enumerate.iter().join(", ")
+            ),
+            Error::Const(message) => format!(
+                "値は `{}` でなければなりません。",
+                message.params().const_value
+            ),
+            Error::EqField(message) => format!(
+                "値は `{}` フィールドと等しくなければなりません。",
+                message.params().eq_field
+            ),
+            Error::Type(message) => format!(
+                "値の型は {} でなければなりません。",
+                message
+                    .params()
+                    .types
+                    .iter()
+                    .map(|t| format!("`{}`", t))
+                    .join(" または ")
+            ),
+            Error::Required(_) => "プロパティは必須です。".to_owned(),
+            Error::NotAllowed(_) => "値は許可されていません。".to_owned(),
+            Error::AnyOf(_) => "値はいずれかのスキーマに一致しなければなりません。".to_owned(),
+            Error::OneOf(message) => format!(
+                "値はちょうど1つのスキーマに一致しなければなりませんが、{} 個に一致しました。",
+                message.params().valid_count()
+            ),
+            Error::Not(_) => "値はスキーマに一致してはなりません。".to_owned(),
+            Error::Custom(_) | Error::Items(_) | Error::Properties(_) => return None,
+        };
+
+        Some(message)
+    }
+}
@@ -0,0 +1,28 @@
+/// Error with the keyword and the parameters of the validation.
+///
+/// See [`Error::to_detailed`](super::Error::to_detailed).
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DetailedError {
+    pub keyword: &'static str,
+    pub params: serde_json::Value,
+    pub message: String,
+}
+
+impl DetailedError {
+    pub fn new(keyword: &'static str, params: serde_json::Value, message: String) -> Self {
+        Self {
+            keyword,
+            params,
+            message,
+        }
+    }
+}
+
+pub(crate) fn to_detailed_errors(errors: &super::VecErrors) -> serde_json::Value {
+    serde_json::Value::Array(
+        errors
+            .iter()
+            .map(|error| serde_json::to_value(error.to_detailed()).unwrap_or_default())
+            .collect(),
+    )
+}
@@ -1,11 +1,16 @@
 pub use crate::error::{
-    EnumerateErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
-    MaxItemsErrorParams, MaxLengthErrorParams, MaxPropertiesErrorParams, MaximumErrorParams,
-    Message, MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams,
-    MinimumErrorParams, MultipleOfErrorParams, PatternErrorParams, UniqueItemsErrorParams,
+    AnyOfErrorParams, ConstErrorParams, ContainsErrorParams, EnumerateErrorParams,
+    EqFieldErrorParams, ExclusiveMaximumErrorParams, ExclusiveMinimumErrorParams,
+    FormatErrorParams, MaxContainsErrorParams, MaxItemsErrorParams, MaxLengthErrorParams,
+    MaxPropertiesErrorParams, MaximumErrorParams, Message, MinContainsErrorParams,
+    MinItemsErrorParams, MinLengthErrorParams, MinPropertiesErrorParams, MinimumErrorParams,
+    MultipleOfErrorParams, NotAllowedErrorParams, NotErrorParams, OneOfErrorParams,
+    PatternErrorParams, RequiredErrorParams, TypeErrorParams, UniqueItemsErrorParams,
 };
 
-use super::{ArrayErrors, ObjectErrors};
+use crate::error::ToDefaultMessage;
+
+use super::{ArrayErrors, DetailedError, ObjectErrors};
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
@@ -42,6 +47,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Pattern(Message<PatternErrorParams>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Format(Message<FormatErrorParams>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinItems(Message<MinItemsErrorParams>),
@@ -54,6 +63,18 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     UniqueItems(Message<UniqueItemsErrorParams>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Contains(Message<ContainsErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MinContains(Message<MinContainsErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MaxContains(Message<MaxContainsErrorParams>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinProperties(Message<MinPropertiesErrorParams>),
@@ -66,6 +87,38 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Enumerate(Message<EnumerateErrorParams>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Const(Message<ConstErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    EqField(Message<EqFieldErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Type(Message<TypeErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Required(Message<RequiredErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    NotAllowed(Message<NotAllowedErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AnyOf(Message<AnyOfErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    OneOf(Message<OneOfErrorParams>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Not(Message<NotErrorParams>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
@@ -77,10 +130,123 @@ pub enum Error {
     Properties(ObjectErrors),
 }
 
+impl Error {
+    /// Keyword of the validation in JSON Schema, like `"minLength"`.
+    ///
+    /// The validations which JSON Schema does not have are named in camelCase too, like `"eqField"`.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Minimum(_) => "minimum",
+            Self::Maximum(_) => "maximum",
+            Self::ExclusiveMinimum(_) => "exclusiveMinimum",
+            Self::ExclusiveMaximum(_) => "exclusiveMaximum",
+            Self::MultipleOf(_) => "multipleOf",
+            Self::MinLength(_) => "minLength",
+            Self::MaxLength(_) => "maxLength",
+            Self::Pattern(_) => "pattern",
+            Self::Format(_) => "format",
+            Self::MinItems(_) => "minItems",
+            Self::MaxItems(_) => "maxItems",
+            Self::UniqueItems(_) => "uniqueItems",
+            Self::Contains(_) => "contains",
+            Self::MinContains(_) => "minContains",
+            Self::MaxContains(_) => "maxContains",
+            Self::MinProperties(_) => "minProperties",
+            Self::MaxProperties(_) => "maxProperties",
+            Self::Enumerate(_) => "enum",
+            Self::Const(_) => "const",
+            Self::EqField(_) => "eqField",
+            Self::Type(_) => "type",
+            Self::Required(_) => "required",
+            Self::NotAllowed(_) => "notAllowed",
+            Self::AnyOf(_) => "anyOf",
+            Self::OneOf(_) => "oneOf",
+            Self::Not(_) => "not",
+            Self::Custom(_) => "custom",
+            Self::Items(_) => "items",
+            Self::Properties(_) => "properties",
+        }
+    }
+
+    /// Error with the keyword and the parameters, for the clients which format the messages by themselves.
+    ///
+    /// ```rust
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::json;
+    ///
+    /// #[derive(Validate)]
+    /// struct SampleStruct(#[validate(maximum = 100)] i32);
+    ///
+    /// let errors = SampleStruct(101).validate().unwrap_err();
+    /// let (_, error) = errors.iter_flat().next().unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(error.to_detailed()).unwrap(),
+    ///     json!({
+    ///         "keyword": "maximum",
+    ///         "params": { "maximum": 100 },
+    ///         "message": "the number must be `<= 100`."
+    ///     })
+    /// );
+    /// ```
+    pub fn to_detailed(&self) -> DetailedError {
+        let params = match self {
+            Self::Minimum(message) => to_params(message),
+            Self::Maximum(message) => to_params(message),
+            Self::ExclusiveMinimum(message) => to_params(message),
+            Self::ExclusiveMaximum(message) => to_params(message),
+            Self::MultipleOf(message) => to_params(message),
+            Self::MinLength(message) => to_params(message),
+            Self::MaxLength(message) => to_params(message),
+            Self::Pattern(message) => to_params(message),
+            Self::Format(message) => to_params(message),
+            Self::MinItems(message) => to_params(message),
+            Self::MaxItems(message) => to_params(message),
+            Self::UniqueItems(message) => to_params(message),
+            Self::Contains(message) => to_params(message),
+            Self::MinContains(message) => to_params(message),
+            Self::MaxContains(message) => to_params(message),
+            Self::MinProperties(message) => to_params(message),
+            Self::MaxProperties(message) => to_params(message),
+            Self::Enumerate(message) => to_params(message),
+            Self::Const(message) => to_params(message),
+            Self::EqField(message) => to_params(message),
+            Self::Type(message) => to_params(message),
+            Self::Required(message) => to_params(message),
+            Self::NotAllowed(message) => to_params(message),
+            Self::AnyOf(message) => to_composition_params(&message.params().errors),
+            Self::OneOf(message) => to_composition_params(&message.params().errors),
+            Self::Not(message) => to_params(message),
+            Self::Custom(_) => serde_json::Value::Object(serde_json::Map::new()),
+            Self::Items(errors) => errors.to_detailed(),
+            Self::Properties(errors) => errors.to_detailed(),
+        };
+
+        DetailedError::new(self.keyword(), params, self.to_string())
+    }
+}
+
+fn to_params<Params>(message: &Message<Params>) -> serde_json::Value
+where
+    Params: ToDefaultMessage + serde::Serialize,
+{
+    serde_json::to_value(message.params()).unwrap_or(serde_json::Value::Null)
+}
+
+/// Parameters with the detailed errors of every schema.
+fn to_composition_params(errors: &[super::VecErrors]) -> serde_json::Value {
+    serde_json::json!({
+        "errors": errors
+            .iter()
+            .map(|errors| errors.iter().map(Error::to_detailed).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    })
+}
+
 fn serialize_error_message<T, S>(message: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     T: std::fmt::Display,
     S: serde::Serializer,
 {
-    serializer.serialize_str(&message.to_string())
+    serializer.collect_str(message)
 }
@@ -1,4 +1,5 @@
-use super::{ArrayErrors, ObjectErrors, VecErrors};
+use super::detailed_error::to_detailed_errors;
+use super::{ArrayErrors, Error, FlatError, MergeError, ObjectErrors, VecErrors};
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
@@ -10,47 +11,140 @@ pub enum Errors {
 }
 
 impl Errors {
-    pub fn merge(&mut self, other: Errors) {
+    /// Iterate all errors with the JSON Pointer of the invalid value.
+    ///
+    /// ```rust
+    /// use serde_valid::Validate;
+    ///
+    /// #[derive(Validate)]
+    /// struct SampleStruct {
+    ///     #[validate]
+    ///     items: Vec<SampleItem>,
+    /// }
+    ///
+    /// #[derive(Validate)]
+    /// struct SampleItem {
+    ///     #[validate(min_length = 1)]
+    ///     name: String,
+    /// }
+    ///
+    /// let s = SampleStruct {
+    ///     items: vec![SampleItem { name: "".to_owned() }],
+    /// };
+    /// let errors = s.validate().unwrap_err();
+    /// let (pointer, error) = errors.iter_flat().next().unwrap();
+    ///
+    /// assert_eq!(pointer, "/items/0/name");
+    /// assert_eq!(error.to_string(), "the length of the value must be `>= 1`.");
+    /// ```
+    pub fn iter_flat(&self) -> impl Iterator<Item = (String, &Error)> {
+        let mut flat = vec![];
+        self.collect_flat("", &mut flat);
+        flat.into_iter()
+    }
+
+    /// Flatten the errors as the "basic" output format of JSON Schema.
+    pub fn flatten(&self) -> Vec<FlatError> {
+        self.iter_flat()
+            .map(|(pointer, error)| FlatError::new(pointer, error.to_string()))
+            .collect()
+    }
+
+    /// Errors tree whose messages are replaced by [`DetailedError`](super::DetailedError).
+    pub fn to_detailed(&self) -> serde_json::Value {
         match self {
-            Errors::Array(a) => match other {
-                Errors::Array(b) => {
-                    a.errors.extend(b.errors);
+            Errors::Array(errors) => errors.to_detailed(),
+            Errors::Object(errors) => errors.to_detailed(),
+            Errors::NewType(errors) => serde_json::json!({ "errors": to_detailed_errors(errors) }),
+        }
+    }
 
-                    for (index, item) in b.items {
-                        match a.items.get_mut(&index) {
-                            Some(errors) => errors.merge(item),
-                            None => {
-                                a.items.insert(index, item);
-                            }
-                        };
-                    }
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(errors) => {
-                    a.errors.extend(errors.into_iter());
-                }
-            },
-            Errors::NewType(a) => match other {
-                Errors::Array(b) => {
-                    a.extend(b.errors);
-                    *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(b) => {
-                    a.extend(b);
-                }
-            },
-            Errors::Object(_) => {
-                unimplemented!("Object not support yet.")
+    /// The number of the errors, which equals to the length of [`Errors::iter_flat`].
+    pub fn count(&self) -> usize {
+        match self {
+            Errors::Array(errors) => errors.count(),
+            Errors::Object(errors) => errors.count(),
+            Errors::NewType(errors) => count_errors(errors),
+        }
+    }
+
+    pub(crate) fn collect_flat<'a>(&'a self, pointer: &str, flat: &mut Vec<(String, &'a Error)>) {
+        match self {
+            Errors::Array(errors) => errors.collect_flat(pointer, flat),
+            Errors::Object(errors) => errors.collect_flat(pointer, flat),
+            Errors::NewType(errors) => collect_flat_errors(errors, pointer, flat),
+        }
+    }
+
+    /// Merge the errors of the other validation.
+    ///
+    /// Returns [`MergeError`] if the one is [`Errors::Array`] and the other is [`Errors::Object`]
+    /// at the same location. Even in that case, all the other errors are merged,
+    /// and only the conflicted errors of `other` are dropped.
+    pub fn merge(&mut self, other: Errors) -> Result<(), MergeError> {
+        self.merge_at(other, "")
+    }
+
+    pub(crate) fn merge_at(&mut self, other: Errors, pointer: &str) -> Result<(), MergeError> {
+        match (&mut *self, other) {
+            (Errors::Array(a), Errors::Array(b)) => a.merge_at(b, pointer),
+            (Errors::Array(a), Errors::NewType(b)) => {
+                a.errors.extend(b);
+                Ok(())
+            }
+            (Errors::Object(a), Errors::Object(b)) => a.merge_at(b, pointer),
+            (Errors::Object(a), Errors::NewType(b)) => {
+                a.errors.extend(b);
+                Ok(())
+            }
+            (Errors::NewType(a), Errors::Array(b)) => {
+                let mut errors = std::mem::take(a);
+                errors.extend(b.errors);
+                *self = Errors::Array(ArrayErrors::new(errors, b.items));
+                Ok(())
+            }
+            (Errors::NewType(a), Errors::Object(b)) => {
+                let mut errors = std::mem::take(a);
+                errors.extend(b.errors);
+                *self = Errors::Object(ObjectErrors::new(errors, b.properties));
+                Ok(())
             }
+            (Errors::NewType(a), Errors::NewType(b)) => {
+                a.extend(b);
+                Ok(())
+            }
+            (Errors::Array(_), Errors::Object(_)) | (Errors::Object(_), Errors::Array(_)) => {
+                Err(MergeError::new(pointer.to_owned()))
+            }
+        }
+    }
+}
+
+pub(crate) fn collect_flat_errors<'a>(
+    errors: &'a VecErrors,
+    pointer: &str,
+    flat: &mut Vec<(String, &'a Error)>,
+) {
+    for error in errors {
+        match error {
+            Error::Items(errors) => errors.collect_flat(pointer, flat),
+            Error::Properties(errors) => errors.collect_flat(pointer, flat),
+            _ => flat.push((pointer.to_owned(), error)),
         }
     }
 }
 
+pub(crate) fn count_errors<'a>(errors: impl IntoIterator<Item = &'a Error>) -> usize {
+    errors
+        .into_iter()
+        .map(|error| match error {
+            Error::Items(errors) => errors.count(),
+            Error::Properties(errors) => errors.count(),
+            _ => 1,
+        })
+        .sum()
+}
+
 pub fn serialize<T>(errors: &VecErrors, serializer: T) -> Result<T::Ok, T::Error>
 where
     T: serde::ser::Serializer,
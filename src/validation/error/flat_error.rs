@@ -0,0 +1,42 @@
+/// Error with the location of the invalid value,
+/// as same as the ["basic" output format](https://json-schema.org/draft/2020-12/json-schema-core.html#name-basic) of JSON Schema.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlatError {
+    /// JSON Pointer of the invalid value, like `/items/3/name`.
+    pub instance_location: String,
+    pub message: String,
+}
+
+impl FlatError {
+    pub fn new(instance_location: String, message: String) -> Self {
+        Self {
+            instance_location,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for FlatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.instance_location, self.message)
+    }
+}
+
+/// Escape the reference token of JSON Pointer.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc6901#section-3>
+pub(crate) fn escape_json_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_json_pointer() {
+        assert_eq!(escape_json_pointer("a/b~c"), "a~1b~0c");
+        assert_eq!(escape_json_pointer("~1"), "~01");
+    }
+}
@@ -0,0 +1,224 @@
+use serde::ser::SerializeMap;
+
+use super::{ArrayErrors, Error, Errors, FlatError, ObjectErrors};
+
+/// Formatter of the error messages, rendering the messages at the output instead of the validation.
+///
+/// Returns `None` to keep the message of the validator.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::validation::Error;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct SampleStruct {
+///     #[validate(maximum = 10)]
+///     val: i32,
+/// }
+///
+/// let errors = SampleStruct { val: 11 }.validate().unwrap_err();
+/// let formatter = |error: &Error| match error {
+///     Error::Maximum(message) => Some(format!("must be at most {}.", message.params().maximum)),
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(errors.formatted(&formatter)).unwrap(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["must be at most 10."]
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub trait MessageFormatter {
+    fn format_message(&self, error: &Error) -> Option<String>;
+}
+
+impl<F> MessageFormatter for F
+where
+    F: Fn(&Error) -> Option<String>,
+{
+    fn format_message(&self, error: &Error) -> Option<String> {
+        self(error)
+    }
+}
+
+impl Error {
+    /// Format the message by the formatter, or by the validator if the formatter returns `None`.
+    pub fn format_with<F>(&self, formatter: &F) -> String
+    where
+        F: MessageFormatter + ?Sized,
+    {
+        formatter
+            .format_message(self)
+            .unwrap_or_else(|| self.to_string())
+    }
+}
+
+impl Errors {
+    /// Errors whose messages are rendered by the formatter when serialized or displayed.
+    pub fn formatted<'a, F>(&'a self, formatter: &'a F) -> Formatted<'a, Errors, F>
+    where
+        F: MessageFormatter + ?Sized,
+    {
+        Formatted::new(self, formatter)
+    }
+
+    /// Flatten the errors whose messages are rendered by the formatter.
+    pub fn flatten_with<F>(&self, formatter: &F) -> Vec<FlatError>
+    where
+        F: MessageFormatter + ?Sized,
+    {
+        self.iter_flat()
+            .map(|(pointer, error)| FlatError::new(pointer, error.format_with(formatter)))
+            .collect()
+    }
+}
+
+/// View of the errors with the [`MessageFormatter`].
+pub struct Formatted<'a, T: ?Sized, F: ?Sized> {
+    value: &'a T,
+    formatter: &'a F,
+}
+
+impl<'a, T: ?Sized, F: ?Sized> Formatted<'a, T, F> {
+    fn new(value: &'a T, formatter: &'a F) -> Self {
+        Self { value, formatter }
+    }
+
+    fn with<U: ?Sized>(&self, value: &'a U) -> Formatted<'a, U, F> {
+        Formatted::new(value, self.formatter)
+    }
+}
+
+impl<F> serde::Serialize for Formatted<'_, Errors, F>
+where
+    F: MessageFormatter + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.value {
+            Errors::Array(errors) => self.with(errors).serialize(serializer),
+            Errors::Object(errors) => self.with(errors).serialize(serializer),
+            Errors::NewType(errors) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("errors", &self.with(errors.as_slice()))?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<F> serde::Serialize for Formatted<'_, ArrayErrors, F>
+where
+    F: MessageFormatter + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("errors", &self.with(self.value.errors.as_slice()))?;
+        map.serialize_entry(
+            "items",
+            &FormattedMap(
+                self.value
+                    .items
+                    .iter()
+                    .map(|(index, errors)| (index, self.with(errors))),
+            ),
+        )?;
+        map.end()
+    }
+}
+
+impl<F> serde::Serialize for Formatted<'_, ObjectErrors, F>
+where
+    F: MessageFormatter + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("errors", &self.with(self.value.errors.as_slice()))?;
+        map.serialize_entry(
+            "properties",
+            &FormattedMap(
+                self.value
+                    .properties
+                    .iter()
+                    .map(|(name, errors)| (name, self.with(errors))),
+            ),
+        )?;
+        map.end()
+    }
+}
+
+impl<F> serde::Serialize for Formatted<'_, [Error], F>
+where
+    F: MessageFormatter + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.value.iter().map(|error| self.with(error)))
+    }
+}
+
+impl<F> serde::Serialize for Formatted<'_, Error, F>
+where
+    F: MessageFormatter + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.value {
+            Error::Items(errors) => self.with(errors).serialize(serializer),
+            Error::Properties(errors) => self.with(errors).serialize(serializer),
+            error => serializer.serialize_str(&error.format_with(self.formatter)),
+        }
+    }
+}
+
+impl<F> std::fmt::Display for Formatted<'_, Errors, F>
+where
+    F: MessageFormatter + ?Sized,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json_string = match self.value {
+            Errors::NewType(errors) => serde_json::to_string(&self.with(errors.as_slice())),
+            _ => serde_json::to_string(self),
+        };
+        match json_string {
+            Ok(json_string) => write!(f, "{}", json_string),
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
+
+/// Serialize the entries lazily, without collecting them into the map.
+struct FormattedMap<I>(I);
+
+impl<K, V, I> serde::Serialize for FormattedMap<I>
+where
+    K: serde::Serialize,
+    V: serde::Serialize,
+    I: Iterator<Item = (K, V)> + Clone,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.0.clone())
+    }
+}
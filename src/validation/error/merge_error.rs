@@ -0,0 +1,13 @@
+/// Error of merging [`Errors`](super::Errors) whose shapes are conflicted.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("cannot merge the array errors and the object errors at \"{instance_location}\".")]
+pub struct MergeError {
+    /// JSON Pointer of the conflicted value.
+    pub instance_location: String,
+}
+
+impl MergeError {
+    pub fn new(instance_location: String) -> Self {
+        Self { instance_location }
+    }
+}
@@ -1,17 +1,78 @@
+use std::borrow::Cow;
+
 use indexmap::IndexMap;
 
-use super::{Errors, VecErrors};
+use super::detailed_error::to_detailed_errors;
+use super::errors::{collect_flat_errors, count_errors};
+use super::{escape_json_pointer, Error, Errors, MergeError, VecErrors};
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 pub struct ObjectErrors {
     pub errors: VecErrors,
-    pub properties: IndexMap<&'static str, Errors>,
+    /// The errors of the properties, keyed by the property names.
+    ///
+    /// The keys are owned for the names known only at runtime,
+    /// like the keys of maps and the properties of [`crate::json::Schema`].
+    pub properties: IndexMap<Cow<'static, str>, Errors>,
 }
 
 impl ObjectErrors {
-    pub fn new(errors: VecErrors, properties: IndexMap<&'static str, Errors>) -> Self {
+    pub fn new(errors: VecErrors, properties: IndexMap<Cow<'static, str>, Errors>) -> Self {
         Self { errors, properties }
     }
+
+    pub(crate) fn count(&self) -> usize {
+        count_errors(&self.errors) + self.properties.values().map(Errors::count).sum::<usize>()
+    }
+
+    /// Merge the errors of the other validation, like [`Errors::merge`].
+    pub fn merge(&mut self, other: ObjectErrors) -> Result<(), MergeError> {
+        self.merge_at(other, "")
+    }
+
+    pub(crate) fn merge_at(
+        &mut self,
+        other: ObjectErrors,
+        pointer: &str,
+    ) -> Result<(), MergeError> {
+        self.errors.extend(other.errors);
+
+        let mut result = Ok(());
+        for (name, property) in other.properties {
+            let merged = match self.properties.get_mut(&name) {
+                Some(errors) => errors.merge_at(
+                    property,
+                    &format!("{pointer}/{}", escape_json_pointer(&name)),
+                ),
+                None => {
+                    self.properties.insert(name, property);
+                    Ok(())
+                }
+            };
+            // Report the first conflict after merging all the other properties.
+            result = result.and(merged);
+        }
+        result
+    }
+
+    /// Errors tree whose messages are replaced by [`DetailedError`](super::DetailedError).
+    pub fn to_detailed(&self) -> serde_json::Value {
+        serde_json::json!({
+            "errors": to_detailed_errors(&self.errors),
+            "properties": self
+                .properties
+                .iter()
+                .map(|(name, property)| (name.to_string(), property.to_detailed()))
+                .collect::<serde_json::Map<_, _>>(),
+        })
+    }
+
+    pub(crate) fn collect_flat<'a>(&'a self, pointer: &str, flat: &mut Vec<(String, &'a Error)>) {
+        collect_flat_errors(&self.errors, pointer, flat);
+        for (name, property) in &self.properties {
+            property.collect_flat(&format!("{pointer}/{}", escape_json_pointer(name)), flat);
+        }
+    }
 }
 
 impl std::fmt::Display for ObjectErrors {
@@ -0,0 +1,29 @@
+/// Group of the validators.
+///
+/// The validators without `groups(...)` belong to [`Group::DEFAULT`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Group(&'static str);
+
+impl Group {
+    pub const DEFAULT: Group = Group("default");
+
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl std::fmt::Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
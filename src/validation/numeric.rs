@@ -1,11 +1,13 @@
 mod exclusive_maximum;
 mod exclusive_minimum;
+mod field_limit;
 mod maximum;
 mod minimum;
 mod multiple_of;
 
 pub use exclusive_maximum::ValidateExclusiveMaximum;
 pub use exclusive_minimum::ValidateExclusiveMinimum;
+pub use field_limit::FieldLimit;
 pub use maximum::ValidateMaximum;
 pub use minimum::ValidateMinimum;
 pub use multiple_of::ValidateMultipleOf;
@@ -0,0 +1,55 @@
+/// Limit of the range validation by the value of the other field, like `minimum_field = start`.
+///
+/// The `Option` field gives no limit when it is `None`, and the validation is skipped.
+pub trait FieldLimit {
+    type Limit;
+
+    fn field_limit(&self) -> Option<Self::Limit>;
+}
+
+macro_rules! impl_field_limit {
+    ($type:ty) => {
+        impl FieldLimit for $type {
+            type Limit = $type;
+
+            fn field_limit(&self) -> Option<Self::Limit> {
+                Some(*self)
+            }
+        }
+
+        impl FieldLimit for Option<$type> {
+            type Limit = $type;
+
+            fn field_limit(&self) -> Option<Self::Limit> {
+                *self
+            }
+        }
+    };
+}
+
+impl_field_limit!(i8);
+impl_field_limit!(i16);
+impl_field_limit!(i32);
+impl_field_limit!(i64);
+impl_field_limit!(i128);
+impl_field_limit!(isize);
+impl_field_limit!(u8);
+impl_field_limit!(u16);
+impl_field_limit!(u32);
+impl_field_limit!(u64);
+impl_field_limit!(u128);
+impl_field_limit!(usize);
+impl_field_limit!(std::num::NonZeroI8);
+impl_field_limit!(std::num::NonZeroI16);
+impl_field_limit!(std::num::NonZeroI32);
+impl_field_limit!(std::num::NonZeroI64);
+impl_field_limit!(std::num::NonZeroI128);
+impl_field_limit!(std::num::NonZeroIsize);
+impl_field_limit!(std::num::NonZeroU8);
+impl_field_limit!(std::num::NonZeroU16);
+impl_field_limit!(std::num::NonZeroU32);
+impl_field_limit!(std::num::NonZeroU64);
+impl_field_limit!(std::num::NonZeroU128);
+impl_field_limit!(std::num::NonZeroUsize);
+impl_field_limit!(f32);
+impl_field_limit!(f64);
@@ -1,5 +1,7 @@
+mod entries;
 mod max_properties;
 mod min_properties;
 
+pub use entries::ValidateEntries;
 pub use max_properties::ValidateMaxProperties;
 pub use min_properties::ValidateMinProperties;
@@ -0,0 +1,132 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+
+use crate::validation::{Errors, ObjectErrors, VecErrors};
+
+/// Validation of the keys and the values of the map, for `#[validate(keys(...), values(...))]`,
+/// `#[validate(pattern_properties(...))]` and `#[validate(additional_properties = false)]`.
+///
+/// The errors of the entry are reported at the property of the key.
+pub trait ValidateEntries {
+    type Key: ?Sized;
+    type Value: ?Sized;
+
+    fn validate_entries<F>(&self, validate_entry: F) -> Result<(), ObjectErrors>
+    where
+        F: FnMut(&Self::Key, &Self::Value) -> VecErrors;
+}
+
+macro_rules! impl_validate_entries {
+    ($map:ident<K, V $(, $hasher:ident)?>) => {
+        impl<K, V $(, $hasher)?> ValidateEntries for $map<K, V $(, $hasher)?>
+        where
+            K: Display,
+        {
+            type Key = K;
+            type Value = V;
+
+            fn validate_entries<F>(&self, validate_entry: F) -> Result<(), ObjectErrors>
+            where
+                F: FnMut(&Self::Key, &Self::Value) -> VecErrors,
+            {
+                collect_entries_errors(self.iter(), validate_entry)
+            }
+        }
+    };
+}
+
+impl_validate_entries!(HashMap<K, V, S>);
+impl_validate_entries!(BTreeMap<K, V>);
+impl_validate_entries!(IndexMap<K, V, S>);
+
+impl ValidateEntries for serde_json::Map<String, serde_json::Value> {
+    type Key = String;
+    type Value = serde_json::Value;
+
+    fn validate_entries<F>(&self, validate_entry: F) -> Result<(), ObjectErrors>
+    where
+        F: FnMut(&Self::Key, &Self::Value) -> VecErrors,
+    {
+        collect_entries_errors(self.iter(), validate_entry)
+    }
+}
+
+impl<T> ValidateEntries for Option<T>
+where
+    T: ValidateEntries,
+{
+    type Key = T::Key;
+    type Value = T::Value;
+
+    fn validate_entries<F>(&self, validate_entry: F) -> Result<(), ObjectErrors>
+    where
+        F: FnMut(&Self::Key, &Self::Value) -> VecErrors,
+    {
+        match self {
+            Some(map) => map.validate_entries(validate_entry),
+            None => Ok(()),
+        }
+    }
+}
+
+fn collect_entries_errors<'a, K, V, F>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+    mut validate_entry: F,
+) -> Result<(), ObjectErrors>
+where
+    K: Display + ?Sized + 'a,
+    V: ?Sized + 'a,
+    F: FnMut(&K, &V) -> VecErrors,
+{
+    let properties = entries
+        .filter_map(|(key, value)| {
+            let errors = validate_entry(key, value);
+            (!errors.is_empty()).then(|| (Cow::Owned(key.to_string()), Errors::NewType(errors)))
+        })
+        .collect::<IndexMap<_, _>>();
+
+    if properties.is_empty() {
+        Ok(())
+    } else {
+        Err(ObjectErrors::new(vec![], properties))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Error;
+
+    fn validate_positive(_: &String, value: &i32) -> VecErrors {
+        if *value > 0 {
+            vec![]
+        } else {
+            vec![Error::Custom("not positive.".to_owned())]
+        }
+    }
+
+    #[test]
+    fn test_validate_entries_hash_map_type() {
+        let map = HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]);
+        assert!(map.validate_entries(validate_positive).is_ok());
+    }
+
+    #[test]
+    fn test_validate_entries_btree_map_type_is_err() {
+        let map = BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 0)]);
+        let errors = map.validate_entries(validate_positive).unwrap_err();
+        assert_eq!(
+            errors.properties.keys().collect::<Vec<_>>(),
+            vec![&Cow::<str>::Borrowed("b")]
+        );
+    }
+
+    #[test]
+    fn test_validate_entries_option_none() {
+        let map: Option<IndexMap<String, i32>> = None;
+        assert!(map.validate_entries(validate_positive).is_ok());
+    }
+}
@@ -0,0 +1,126 @@
+use super::{PropertiesErrors, VecErrors};
+
+/// Options to stop the validation early.
+///
+/// ```rust
+/// use serde_valid::{validation::ValidateOptions, Validate};
+///
+/// #[derive(Validate)]
+/// struct SampleStruct {
+///     #[validate(minimum = 0)]
+///     #[validate(maximum = 10)]
+///     val1: i32,
+///     #[validate(min_length = 4)]
+///     val2: String,
+/// }
+///
+/// let s = SampleStruct {
+///     val1: -1,
+///     val2: "abc".to_owned(),
+/// };
+///
+/// assert_eq!(s.validate().unwrap_err().count(), 2);
+/// assert_eq!(
+///     s.validate_with_options(ValidateOptions::fail_fast())
+///         .unwrap_err()
+///         .count(),
+///     1
+/// );
+/// assert!(!s.is_valid());
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ValidateOptions {
+    /// Stop the validation at the first error.
+    pub fail_fast: bool,
+
+    /// Stop the validation when the number of errors reaches the limit.
+    ///
+    /// `Some(0)` is treated as `Some(1)`, because the failed validation reports at least one error.
+    pub max_errors: Option<usize>,
+}
+
+impl ValidateOptions {
+    pub fn fail_fast() -> Self {
+        Self {
+            fail_fast: true,
+            max_errors: None,
+        }
+    }
+
+    pub fn max_errors(max_errors: usize) -> Self {
+        Self {
+            fail_fast: false,
+            max_errors: Some(max_errors),
+        }
+    }
+
+    /// The maximum number of errors to report, or `None` if unlimited.
+    pub fn limit(&self) -> Option<usize> {
+        if self.fail_fast {
+            Some(1)
+        } else {
+            self.max_errors.map(|max_errors| max_errors.max(1))
+        }
+    }
+
+    /// Whether the reported errors reach the limit.
+    ///
+    /// `count` is called only if the errors are limited.
+    pub fn is_exceeded(&self, count: impl FnOnce() -> usize) -> bool {
+        match self.limit() {
+            Some(limit) => count() >= limit,
+            None => false,
+        }
+    }
+
+    /// The options for the rest of the validation after the errors were reported.
+    ///
+    /// `count` is called only if the errors are limited.
+    pub fn remaining(&self, count: impl FnOnce() -> usize) -> Self {
+        match self.limit() {
+            Some(limit) => Self {
+                fail_fast: false,
+                max_errors: Some(limit.saturating_sub(count()).max(1)),
+            },
+            None => *self,
+        }
+    }
+}
+
+/// Count the errors collected by `#[derive(Validate)]`.
+#[doc(hidden)]
+pub fn count_errors(errors: &VecErrors, properties_errors: &impl PropertiesErrors) -> usize {
+    super::error::count_errors(errors) + properties_errors.count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_options_limit() {
+        assert_eq!(ValidateOptions::default().limit(), None);
+        assert_eq!(ValidateOptions::fail_fast().limit(), Some(1));
+        assert_eq!(ValidateOptions::max_errors(3).limit(), Some(3));
+        assert_eq!(ValidateOptions::max_errors(0).limit(), Some(1));
+    }
+
+    #[test]
+    fn test_validate_options_is_exceeded() {
+        assert!(!ValidateOptions::default().is_exceeded(|| unreachable!()));
+        assert!(!ValidateOptions::max_errors(3).is_exceeded(|| 2));
+        assert!(ValidateOptions::max_errors(3).is_exceeded(|| 3));
+    }
+
+    #[test]
+    fn test_validate_options_remaining() {
+        assert_eq!(
+            ValidateOptions::max_errors(3).remaining(|| 1),
+            ValidateOptions::max_errors(2)
+        );
+        assert_eq!(
+            ValidateOptions::default().remaining(|| unreachable!()),
+            ValidateOptions::default()
+        );
+    }
+}
@@ -0,0 +1,84 @@
+mod date_time;
+mod email;
+mod hostname;
+mod ip;
+mod uri;
+mod uuid;
+
+use crate::FormatErrorParams;
+use date_time::{is_date, is_date_time, is_time};
+use email::is_email;
+use hostname::is_hostname;
+use ip::{is_ipv4, is_ipv6};
+use uri::is_uri;
+use uuid::is_uuid;
+
+pub use serde_valid_literal::Format;
+
+fn is_valid_format(format: Format, value: &str) -> bool {
+    match format {
+        Format::DateTime => is_date_time(value),
+        Format::Date => is_date(value),
+        Format::Time => is_time(value),
+        Format::Email => is_email(value),
+        Format::Hostname => is_hostname(value),
+        Format::Ipv4 => is_ipv4(value),
+        Format::Ipv6 => is_ipv6(value),
+        Format::Uri => is_uri(value),
+        Format::Uuid => is_uuid(value),
+    }
+}
+
+/// Format validation of the string.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/string.html#format>
+pub trait ValidateFormat {
+    fn validate_format(&self, format: Format) -> Result<(), FormatErrorParams>;
+}
+
+macro_rules! impl_validate_string_format {
+    ($type:ty) => {
+        impl ValidateFormat for $type {
+            fn validate_format(&self, format: Format) -> Result<(), FormatErrorParams> {
+                if is_valid_format(format, self.as_ref()) {
+                    Ok(())
+                } else {
+                    Err(FormatErrorParams::new(format))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_string_format!(str);
+impl_validate_string_format!(&str);
+impl_validate_string_format!(String);
+impl_validate_string_format!(std::borrow::Cow<'_, str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_validate_string_format_str_type() {
+        assert!(ValidateFormat::validate_format("2020-09-10", Format::Date).is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_string_type() {
+        assert!(
+            ValidateFormat::validate_format(&String::from("example.com"), Format::Hostname).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_string_format_cow_str_type() {
+        assert!(ValidateFormat::validate_format(&Cow::from("::1"), Format::Ipv6).is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_is_false() {
+        assert!(ValidateFormat::validate_format("example.com", Format::Email).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+/// `full-date` of [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6).
+pub fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if !value.is_ascii() || bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+
+    match (
+        parse_digits(&value[0..4]),
+        parse_digits(&value[5..7]),
+        parse_digits(&value[8..10]),
+    ) {
+        (Some(year), Some(month), Some(day)) => {
+            (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day)
+        }
+        _ => false,
+    }
+}
+
+/// `full-time` of [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6).
+pub fn is_time(value: &str) -> bool {
+    if !value.is_ascii() {
+        return false;
+    }
+    let (partial_time, time_offset) = match value.rfind(['Z', 'z', '+', '-']) {
+        Some(index) => value.split_at(index),
+        None => return false,
+    };
+
+    match (
+        parse_partial_time(partial_time),
+        parse_time_offset(time_offset),
+    ) {
+        (Some((hour, minute, second)), Some(offset)) => {
+            // The leap second is allowed only at the end of the day in UTC.
+            second < 60
+                || ((hour * 60 + minute) as i32 - offset).rem_euclid(24 * 60) == 23 * 60 + 59
+        }
+        _ => false,
+    }
+}
+
+/// `date-time` of [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6).
+pub fn is_date_time(value: &str) -> bool {
+    if !value.is_ascii() || value.len() < 11 {
+        return false;
+    }
+
+    let (date, time) = value.split_at(10);
+    is_date(date) && (time.starts_with('T') || time.starts_with('t')) && is_time(&time[1..])
+}
+
+fn parse_partial_time(value: &str) -> Option<(u32, u32, u32)> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return None;
+    }
+
+    let hour = parse_digits(&value[0..2]).filter(|hour| *hour <= 23)?;
+    let minute = parse_digits(&value[3..5]).filter(|minute| *minute <= 59)?;
+    let second = parse_digits(&value[6..8]).filter(|second| *second <= 60)?;
+
+    match &value[8..] {
+        "" => Some((hour, minute, second)),
+        secfrac => match secfrac.strip_prefix('.') {
+            Some(digits) if is_digits(digits) => Some((hour, minute, second)),
+            _ => None,
+        },
+    }
+}
+
+/// Offset from UTC in minutes.
+fn parse_time_offset(value: &str) -> Option<i32> {
+    if value == "Z" || value == "z" {
+        return Some(0);
+    }
+
+    let bytes = value.as_bytes();
+    if bytes.len() != 6 || bytes[3] != b':' {
+        return None;
+    }
+    let sign = match bytes[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let hour = parse_digits(&value[1..3]).filter(|hour| *hour <= 23)?;
+    let minute = parse_digits(&value[4..6]).filter(|minute| *minute <= 59)?;
+
+    Some(sign * (hour * 60 + minute) as i32)
+}
+
+// `u32::is_multiple_of` needs Rust 1.87.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn parse_digits(value: &str) -> Option<u32> {
+    if is_digits(value) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_date() {
+        assert!(is_date("2020-02-29"));
+        assert!(!is_date("2021-02-29"));
+        assert!(!is_date("2020-13-01"));
+        assert!(!is_date("2020-1-01"));
+    }
+
+    #[test]
+    fn test_is_time() {
+        assert!(is_time("08:30:06Z"));
+        assert!(is_time("08:30:06.283185+09:00"));
+        assert!(is_time("23:59:60Z"));
+        assert!(is_time("15:59:60-08:00"));
+        assert!(!is_time("22:59:60Z"));
+        assert!(!is_time("08:30:06"));
+        assert!(!is_time("24:00:00Z"));
+        assert!(!is_time("08:30:06.Z"));
+    }
+
+    #[test]
+    fn test_is_date_time() {
+        assert!(is_date_time("1963-06-19T08:30:06.283185Z"));
+        assert!(is_date_time("1963-06-19t08:30:06z"));
+        assert!(!is_date_time("1963-06-19 08:30:06Z"));
+        assert!(!is_date_time("1963-06-1908:30:06Z"));
+    }
+}
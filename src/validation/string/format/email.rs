@@ -0,0 +1,79 @@
+use super::{is_hostname, is_ipv4, is_ipv6};
+
+/// `Mailbox` of [RFC 5321](https://www.rfc-editor.org/rfc/rfc5321#section-4.1.2).
+pub fn is_email(value: &str) -> bool {
+    match value.rsplit_once('@') {
+        Some((local_part, domain)) => is_local_part(local_part) && is_domain(domain),
+        None => false,
+    }
+}
+
+fn is_local_part(value: &str) -> bool {
+    if value.is_empty() || value.len() > 64 {
+        return false;
+    }
+
+    if value.starts_with('"') {
+        is_quoted_string(value)
+    } else {
+        value
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+    }
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+fn is_quoted_string(value: &str) -> bool {
+    if value.len() < 2 || !value.ends_with('"') {
+        return false;
+    }
+
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if (' '..='~').contains(&escaped) => {}
+                _ => return false,
+            },
+            '"' => return false,
+            c if (' '..='~').contains(&c) => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+fn is_domain(value: &str) -> bool {
+    match value
+        .strip_prefix('[')
+        .and_then(|literal| literal.strip_suffix(']'))
+    {
+        Some(address_literal) => match address_literal.strip_prefix("IPv6:") {
+            Some(ipv6) => is_ipv6(ipv6),
+            None => is_ipv4(address_literal),
+        },
+        None => is_hostname(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_email() {
+        assert!(is_email("joe.bloggs@example.com"));
+        assert!(is_email("\"joe bloggs\"@example.com"));
+        assert!(is_email("\"joe@bloggs\"@example.com"));
+        assert!(is_email("joe.bloggs@[127.0.0.1]"));
+        assert!(is_email("joe.bloggs@[IPv6:::1]"));
+        assert!(!is_email("joe.bloggs"));
+        assert!(!is_email(".joe.bloggs@example.com"));
+        assert!(!is_email("joe..bloggs@example.com"));
+        assert!(!is_email("joe.bloggs@-example.com"));
+        assert!(!is_email("joe.bloggs@[::1]"));
+    }
+}
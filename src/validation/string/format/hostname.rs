@@ -0,0 +1,26 @@
+/// Hostname of [RFC 1123](https://www.rfc-editor.org/rfc/rfc1123#section-2.1).
+pub fn is_hostname(value: &str) -> bool {
+    !value.is_empty() && value.len() <= 253 && value.split('.').all(is_label)
+}
+
+fn is_label(label: &str) -> bool {
+    (1..=63).contains(&label.len())
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_hostname() {
+        assert!(is_hostname("www.example.com"));
+        assert!(is_hostname("1host"));
+        assert!(!is_hostname("-example.com"));
+        assert!(!is_hostname("example..com"));
+        assert!(!is_hostname("ex_ample.com"));
+        assert!(!is_hostname(&"a".repeat(64)));
+    }
+}
@@ -0,0 +1,33 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// Dotted-quad IPv4 address of [RFC 2673](https://www.rfc-editor.org/rfc/rfc2673#section-3.2).
+pub fn is_ipv4(value: &str) -> bool {
+    Ipv4Addr::from_str(value).is_ok()
+}
+
+/// IPv6 address of [RFC 4291](https://www.rfc-editor.org/rfc/rfc4291#section-2.2).
+pub fn is_ipv6(value: &str) -> bool {
+    Ipv6Addr::from_str(value).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ipv4() {
+        assert!(is_ipv4("192.168.0.1"));
+        assert!(!is_ipv4("192.168.0"));
+        assert!(!is_ipv4("256.0.0.1"));
+        assert!(!is_ipv4("087.10.0.1"));
+    }
+
+    #[test]
+    fn test_is_ipv6() {
+        assert!(is_ipv6("::1"));
+        assert!(is_ipv6("2001:db8::ff00:42:8329"));
+        assert!(!is_ipv6("12345::"));
+        assert!(!is_ipv6("192.168.0.1"));
+    }
+}
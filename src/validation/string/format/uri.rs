@@ -0,0 +1,129 @@
+use super::is_ipv6;
+
+/// URI of [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3).
+pub fn is_uri(value: &str) -> bool {
+    let (scheme, rest) = match value.split_once(':') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let (rest, fragment) = split_off(rest, '#');
+    let (hier_part, query) = split_off(rest, '?');
+
+    is_scheme(scheme)
+        && is_hier_part(hier_part)
+        && query.into_iter().all(is_query_or_fragment)
+        && fragment.into_iter().all(is_query_or_fragment)
+}
+
+fn split_off(value: &str, delimiter: char) -> (&str, Option<&str>) {
+    match value.split_once(delimiter) {
+        Some((value, rest)) => (value, Some(rest)),
+        None => (value, None),
+    }
+}
+
+fn is_scheme(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+}
+
+fn is_hier_part(value: &str) -> bool {
+    match value.strip_prefix("//") {
+        Some(rest) => {
+            let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+            is_authority(authority) && is_path(path)
+        }
+        None => is_path(value),
+    }
+}
+
+fn is_authority(value: &str) -> bool {
+    let (userinfo, host_port) = match value.rsplit_once('@') {
+        Some((userinfo, host_port)) => (Some(userinfo), host_port),
+        None => (None, value),
+    };
+    let (host, port) = match host_port.rfind(':') {
+        Some(index) if !host_port[index..].contains(']') => {
+            (&host_port[..index], Some(&host_port[index + 1..]))
+        }
+        _ => (host_port, None),
+    };
+
+    userinfo.into_iter().all(|userinfo| {
+        is_encoded(userinfo, |c| {
+            is_unreserved(c) || is_sub_delim(c) || c == ':'
+        })
+    }) && port
+        .into_iter()
+        .all(|port| port.chars().all(|c| c.is_ascii_digit()))
+        && is_host(host)
+}
+
+fn is_host(value: &str) -> bool {
+    match value
+        .strip_prefix('[')
+        .and_then(|literal| literal.strip_suffix(']'))
+    {
+        Some(ip_literal) => is_ipv6(ip_literal),
+        None => is_encoded(value, |c| is_unreserved(c) || is_sub_delim(c)),
+    }
+}
+
+fn is_path(value: &str) -> bool {
+    is_encoded(value, |c| is_pchar(c) || c == '/')
+}
+
+fn is_query_or_fragment(value: &str) -> bool {
+    is_encoded(value, |c| is_pchar(c) || c == '/' || c == '?')
+}
+
+fn is_pchar(c: char) -> bool {
+    is_unreserved(c) || is_sub_delim(c) || c == ':' || c == '@'
+}
+
+fn is_unreserved(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-._~".contains(c)
+}
+
+fn is_sub_delim(c: char) -> bool {
+    "!$&'()*+,;=".contains(c)
+}
+
+/// Check the characters, allowing the percent-encoded octets.
+fn is_encoded(value: &str, is_allowed: impl Fn(char) -> bool) -> bool {
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            let is_hex = |c: Option<char>| c.is_some_and(|c| c.is_ascii_hexdigit());
+            if !(is_hex(chars.next()) && is_hex(chars.next())) {
+                return false;
+            }
+        } else if !is_allowed(c) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_uri() {
+        assert!(is_uri("http://foo.bar/?baz=qux#quux"));
+        assert!(is_uri(
+            "https://user:pass@[2001:db8::7]:8080/c=GB?objectClass?one"
+        ));
+        assert!(is_uri("mailto:John.Doe@example.com"));
+        assert!(is_uri(
+            "urn:oasis:names:specification:docbook:dtd:xml:4.1.2"
+        ));
+        assert!(is_uri("http://example.com/%E2%82%AC"));
+        assert!(!is_uri("//foo.bar/?baz=qux#quux"));
+        assert!(!is_uri("http:// shouldfail.com"));
+        assert!(!is_uri("http://example.com/%zz"));
+        assert!(!is_uri("1http://example.com"));
+    }
+}
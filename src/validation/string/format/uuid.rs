@@ -0,0 +1,21 @@
+/// UUID of [RFC 4122](https://www.rfc-editor.org/rfc/rfc4122#section-3).
+pub fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_uuid() {
+        assert!(is_uuid("2eb8aa08-aa98-11ea-b4aa-73b441d16380"));
+        assert!(is_uuid("2EB8AA08-AA98-11EA-B4AA-73B441D16380"));
+        assert!(!is_uuid("2eb8aa08aa9811eab4aa73b441d16380"));
+        assert!(!is_uuid("2eb8aa08-aa98-11ea-b4aa-73b441d1638g"));
+    }
+}
@@ -0,0 +1,123 @@
+use super::{Error, Errors, MapErrors};
+
+/// Destination of the errors of the fields in `#[derive(Validate)]`.
+///
+/// [`MapErrors`] collects the errors, and [`Validity`] records only whether the value is valid
+/// without creating the errors, for [`Validate::is_valid`].
+///
+/// [`Validate::is_valid`]: crate::Validate::is_valid
+#[doc(hidden)]
+pub trait PropertiesErrors {
+    /// Push the error of the field, which `error` creates only if the errors are collected.
+    fn push_error(&mut self, field: &'static str, error: impl FnOnce() -> Error);
+
+    /// Validate the nested field by `validate`, or check it by `is_valid` without the errors.
+    fn validate_field(
+        &mut self,
+        field: &'static str,
+        validate: impl FnOnce() -> Result<(), Errors>,
+        is_valid: impl FnOnce() -> bool,
+    );
+
+    /// Number of the errors of the field.
+    fn field_count(&self, field: &'static str) -> usize;
+
+    /// Set the message key to the errors of the field, skipping the first `start` errors.
+    fn set_message_key(&mut self, field: &'static str, start: usize, key: &'static str);
+
+    /// Number of the errors of all the fields.
+    fn count(&self) -> usize;
+}
+
+impl PropertiesErrors for MapErrors {
+    fn push_error(&mut self, field: &'static str, error: impl FnOnce() -> Error) {
+        self.entry(field).or_default().push(error());
+    }
+
+    fn validate_field(
+        &mut self,
+        field: &'static str,
+        validate: impl FnOnce() -> Result<(), Errors>,
+        _is_valid: impl FnOnce() -> bool,
+    ) {
+        match validate() {
+            Ok(()) => {}
+            Err(Errors::Object(object_errors)) => self
+                .entry(field)
+                .or_default()
+                .push(Error::Properties(object_errors)),
+            Err(Errors::Array(array_errors)) => self
+                .entry(field)
+                .or_default()
+                .push(Error::Items(array_errors)),
+            Err(Errors::NewType(new_type_errors)) => {
+                self.entry(field).or_default().extend(new_type_errors)
+            }
+        }
+    }
+
+    fn field_count(&self, field: &'static str) -> usize {
+        self.get(field).map_or(0, Vec::len)
+    }
+
+    fn set_message_key(&mut self, field: &'static str, start: usize, key: &'static str) {
+        if let Some(errors) = self.get_mut(field) {
+            errors
+                .iter_mut()
+                .skip(start)
+                .for_each(|error| error.set_message_key(key));
+        }
+    }
+
+    fn count(&self) -> usize {
+        super::error::count_errors(self.values().flatten())
+    }
+}
+
+/// Whether the fields are valid, which [`Validate::is_valid`] uses instead of [`MapErrors`].
+///
+/// [`Validate::is_valid`]: crate::Validate::is_valid
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct Validity {
+    valid: bool,
+}
+
+impl Validity {
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+impl Default for Validity {
+    fn default() -> Self {
+        Self { valid: true }
+    }
+}
+
+impl PropertiesErrors for Validity {
+    fn push_error(&mut self, _field: &'static str, _error: impl FnOnce() -> Error) {
+        self.valid = false;
+    }
+
+    fn validate_field(
+        &mut self,
+        _field: &'static str,
+        _validate: impl FnOnce() -> Result<(), Errors>,
+        is_valid: impl FnOnce() -> bool,
+    ) {
+        if !is_valid() {
+            self.valid = false;
+        }
+    }
+
+    fn field_count(&self, _field: &'static str) -> usize {
+        0
+    }
+
+    fn set_message_key(&mut self, _field: &'static str, _start: usize, _key: &'static str) {}
+
+    fn count(&self) -> usize {
+        usize::from(!self.valid)
+    }
+}
@@ -12,7 +12,7 @@ fn items_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 5)]
-        #[validate(max_items = 2)]
+        #[validate(max_items = 2, allow_contradiction)]
         #[validate]
         val: Vec<TestChildStruct>,
     }
@@ -0,0 +1,288 @@
+use serde_json::json;
+use serde_valid::validation::{Error, Group};
+use serde_valid::{AsyncValidate, Validate};
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Returns `Pending` once before completing, like a real I/O future.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            context.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+async fn unique_name(name: &String) -> Result<(), Error> {
+    YieldOnce(false).await;
+    if name == "taken" {
+        Err(Error::Custom("the name is already taken.".to_owned()))
+    } else {
+        Ok(())
+    }
+}
+
+async fn check_password(name: &String, password: &String) -> Result<(), Error> {
+    YieldOnce(false).await;
+    if name == password {
+        Err(Error::Custom(
+            "the password must not be the name.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Validate, AsyncValidate)]
+#[rule_async(check_password(name, password))]
+struct TestUser {
+    #[validate(min_length = 1)]
+    #[validate(custom_async(unique_name))]
+    name: String,
+    password: String,
+}
+
+#[test]
+fn async_validate_is_ok() {
+    let s = TestUser {
+        name: "alice".to_owned(),
+        password: "secret".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+    assert!(block_on(s.validate_async()).is_ok());
+}
+
+#[test]
+fn async_validate_custom_async_is_err() {
+    let s = TestUser {
+        name: "taken".to_owned(),
+        password: "secret".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["the name is already taken."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn async_validate_rule_async_is_err() {
+    let s = TestUser {
+        name: "alice".to_owned(),
+        password: "alice".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": ["the password must not be the name."],
+            "properties": {}
+        })
+    );
+}
+
+#[test]
+fn async_validate_skips_async_validators_when_sync_validators_fail() {
+    let s = TestUser {
+        name: "".to_owned(),
+        password: "".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["the length of the value must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn async_validate_nested_struct_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestTeam {
+        #[validate]
+        members: Vec<TestUser>,
+        #[validate]
+        leader: Option<TestUser>,
+    }
+
+    let s = TestTeam {
+        members: vec![
+            TestUser {
+                name: "alice".to_owned(),
+                password: "secret".to_owned(),
+            },
+            TestUser {
+                name: "taken".to_owned(),
+                password: "secret".to_owned(),
+            },
+        ],
+        leader: None,
+    };
+
+    assert_eq!(
+        serde_json::to_value(&block_on(s.validate_async()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "members": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {
+                                    "errors": ["the name is already taken."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn async_validate_unnamed_struct_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestName(#[validate(custom_async(unique_name))] String);
+
+    assert!(block_on(TestName("alice".to_owned()).validate_async()).is_ok());
+    assert_eq!(
+        serde_json::to_value(&block_on(TestName("taken".to_owned()).validate_async()).unwrap_err())
+            .unwrap(),
+        json!({
+            "errors": ["the name is already taken."]
+        })
+    );
+}
+
+#[test]
+fn async_validate_enum_is_err() {
+    #[derive(Validate, AsyncValidate)]
+    enum TestEnum {
+        Named {
+            #[validate(custom_async(unique_name))]
+            name: String,
+        },
+        Unit,
+    }
+
+    assert!(block_on(TestEnum::Unit.validate_async()).is_ok());
+    assert!(block_on(
+        TestEnum::Named {
+            name: "taken".to_owned()
+        }
+        .validate_async()
+    )
+    .is_err());
+}
+
+#[test]
+fn async_validate_custom_async_with_groups() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate(custom_async(unique_name), groups(create))]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "taken".to_owned(),
+    };
+
+    assert!(s.validate_group(Group::new("create")).is_ok());
+    assert!(block_on(s.validate_async()).is_ok());
+}
+
+#[test]
+fn async_validate_future_is_send() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let s = TestUser {
+        name: "alice".to_owned(),
+        password: "secret".to_owned(),
+    };
+
+    let future = s.validate_async();
+    assert_send(&future);
+    assert!(block_on(future).is_ok());
+}
+
+#[test]
+fn async_validate_nested_struct_runs_sync_validators_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLED: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_called(_: &i32) -> Result<(), Error> {
+        CALLED.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    #[derive(Validate, AsyncValidate)]
+    struct TestItem {
+        #[validate(custom(count_called))]
+        val: i32,
+    }
+
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate]
+        item: TestItem,
+        #[validate]
+        items: Vec<TestItem>,
+    }
+
+    let s = TestStruct {
+        item: TestItem { val: 1 },
+        items: vec![TestItem { val: 2 }, TestItem { val: 3 }],
+    };
+
+    assert!(block_on(s.validate_async()).is_ok());
+    assert_eq!(CALLED.load(Ordering::SeqCst), 3);
+}
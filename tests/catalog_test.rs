@@ -0,0 +1,166 @@
+use serde_json::json;
+use serde_valid::validation::{EnglishCatalog, Error, MessageCatalog};
+use serde_valid::Validate;
+
+struct TestCatalog;
+
+impl MessageCatalog for TestCatalog {
+    fn message(&self, key: &str, error: &Error) -> Option<String> {
+        match (key, error) {
+            ("name_too_short", Error::MinLength(message)) => Some(format!(
+                "the name must be at least {} characters.",
+                message.params().min_length
+            )),
+            ("maximum", Error::Maximum(message)) => {
+                Some(format!("must be at most {}.", message.params().maximum))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    val: i32,
+    #[validate(min_length = 4, message_key = "name_too_short")]
+    #[validate(max_length = 8)]
+    name: String,
+    #[validate(min_length = 2, message_key = "name_too_short")]
+    tags: Vec<String>,
+    #[validate]
+    items: Vec<TestItem>,
+}
+
+#[derive(Validate)]
+struct TestItem {
+    #[validate(maximum = 0, message = "custom message.")]
+    val: i32,
+}
+
+fn invalid_struct() -> TestStruct {
+    TestStruct {
+        val: 11,
+        name: "abc".to_owned(),
+        tags: vec!["a".to_owned()],
+        items: vec![TestItem { val: 1 }],
+    }
+}
+
+#[test]
+fn localize_by_keyword_and_message_key() {
+    let errors = invalid_struct()
+        .validate()
+        .unwrap_err()
+        .localize(&TestCatalog);
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["must be at most 10."]
+                },
+                "name": {
+                    "errors": ["the name must be at least 4 characters."]
+                },
+                "tags": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["the name must be at least 2 characters."]
+                        }
+                    }
+                },
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["custom message."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_key_keeps_message_without_catalog_entry() {
+    let errors = invalid_struct().validate().unwrap_err();
+    let (_, error) = errors
+        .iter_flat()
+        .find(|(pointer, _)| pointer == "/name")
+        .unwrap();
+
+    assert_eq!(error.message_key(), "name_too_short");
+    assert_eq!(error.to_string(), "the length of the value must be `>= 4`.");
+
+    let errors = errors.localize(&EnglishCatalog);
+    let (_, error) = errors
+        .iter_flat()
+        .find(|(pointer, _)| pointer == "/name")
+        .unwrap();
+
+    assert_eq!(error.to_string(), "the length of the value must be `>= 4`.");
+}
+
+#[test]
+fn english_catalog_renders_default_messages() {
+    let errors = invalid_struct()
+        .validate()
+        .unwrap_err()
+        .localize(&EnglishCatalog);
+    let (_, error) = errors
+        .iter_flat()
+        .find(|(pointer, _)| pointer == "/val")
+        .unwrap();
+
+    assert_eq!(error.to_string(), "the number must be `<= 10`.");
+}
+
+#[test]
+fn localize_keeps_custom_messages() {
+    let errors = invalid_struct()
+        .validate()
+        .unwrap_err()
+        .localize(&EnglishCatalog)
+        .localize(&TestCatalog);
+    let (_, error) = errors
+        .iter_flat()
+        .find(|(pointer, _)| pointer == "/items/0/val")
+        .unwrap();
+
+    assert_eq!(error.to_string(), "custom message.");
+}
+
+#[cfg(feature = "ja")]
+#[test]
+fn japanese_catalog() {
+    use serde_valid::validation::JapaneseCatalog;
+
+    let errors = invalid_struct()
+        .validate()
+        .unwrap_err()
+        .localize(&JapaneseCatalog);
+
+    assert_eq!(
+        errors
+            .flatten()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "/val: 数値は `<= 10` でなければなりません。",
+            "/name: the length of the value must be `>= 4`.",
+            "/tags/0: the length of the value must be `>= 2`.",
+            "/items/0/val: custom message.",
+        ]
+    );
+}
@@ -8,7 +8,7 @@ fn sample_rule(_val: &i32) -> Result<(), serde_valid::validation::Error> {
 #[rule(sample_rule(int_value))]
 struct TestStruct<'a> {
     // Generic validator
-    #[validate(enumerate(5, 10, 15))]
+    #[validate(enumerate(5, 10, 15), allow_contradiction)]
     // Numeric validator
     #[validate(multiple_of = 5)]
     #[validate(minimum = 5)]
@@ -18,7 +18,7 @@ struct TestStruct<'a> {
     int_value: i32,
 
     // Generic validator
-    #[validate(enumerate(5.0, 10.0, 15.0))]
+    #[validate(enumerate(5.0, 10.0, 15.0), allow_contradiction)]
     // Numeric validator
     #[validate(multiple_of = 5.0)]
     #[validate(minimum = 5.0)]
@@ -44,7 +44,7 @@ struct TestStruct<'a> {
     str_value: &'a str,
 
     // Generic validator
-    #[validate(enumerate(5, 10, 15))]
+    #[validate(enumerate(5, 10, 15), allow_contradiction)]
     // Numeric validator
     #[validate(multiple_of = 5)]
     #[validate(minimum = 5)]
@@ -78,7 +78,7 @@ struct TestStruct<'a> {
 #[derive(Debug, Validate)]
 struct TestInnerStruct<'a> {
     // Generic validator
-    #[validate(enumerate(5, 10, 15))]
+    #[validate(enumerate(5, 10, 15), allow_contradiction)]
     // Numeric validator
     #[validate(multiple_of = 5)]
     #[validate(minimum = 5)]
@@ -88,7 +88,7 @@ struct TestInnerStruct<'a> {
     inner_int_value: i32,
 
     // Generic validator
-    #[validate(enumerate(5.0, 10.0, 15.0))]
+    #[validate(enumerate(5.0, 10.0, 15.0), allow_contradiction)]
     // Numeric validator
     #[validate(multiple_of = 5.0)]
     #[validate(minimum = 5.0)]
@@ -114,7 +114,7 @@ struct TestInnerStruct<'a> {
     inner_str_value: &'a str,
 
     // Generic validator
-    #[validate(enumerate(5, 10, 15))]
+    #[validate(enumerate(5, 10, 15), allow_contradiction)]
     // Numeric validator
     #[validate(multiple_of = 5)]
     #[validate(minimum = 5)]
@@ -0,0 +1,263 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::{JsonSchema, Validate};
+
+#[test]
+fn any_of_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", max_length = 3))]
+        val: String,
+    }
+
+    assert!(TestStruct {
+        val: "12345".to_owned()
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        val: "abc".to_owned()
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn any_of_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", max_length = 3))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "abcd".to_owned(),
+    };
+
+    let errors = s.validate().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the value must match at least one of the schemas."]
+                }
+            }
+        })
+    );
+
+    let (_, error) = errors.iter_flat().next().unwrap();
+    assert_eq!(
+        serde_json::to_value(error.to_detailed()).unwrap(),
+        json!({
+            "keyword": "anyOf",
+            "params": {
+                "errors": [
+                    [{
+                        "keyword": "pattern",
+                        "params": { "pattern": r"^\d+$" },
+                        "message": r#"the value must match the pattern of "^\d+$"."#
+                    }],
+                    [{
+                        "keyword": "maxLength",
+                        "params": { "max_length": 3 },
+                        "message": "the length of the value must be `<= 3`."
+                    }]
+                ]
+            },
+            "message": "the value must match at least one of the schemas."
+        })
+    );
+}
+
+#[test]
+fn one_of_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(one_of(minimum = 10, multiple_of = 2))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 11 }.validate().is_ok());
+    assert!(TestStruct { val: 2 }.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&TestStruct { val: 12 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the value must match exactly one of the schemas, but matched 2."]
+                }
+            }
+        })
+    );
+    assert!(TestStruct { val: 3 }.validate().is_err());
+}
+
+#[test]
+fn not_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not(enumerate(1, 2, 3)))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 4 }.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&TestStruct { val: 2 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the value must not match the schema."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn all_of_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(all_of(minimum = 1, maximum = 10))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 5 }.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&TestStruct { val: 11 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_composition_with_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            any_of(const_value = "", all_of(min_length = 2, not(const_value = "no"))),
+            message = "invalid value."
+        )]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "".to_owned() }.validate().is_ok());
+    assert!(TestStruct {
+        val: "yes".to_owned()
+    }
+    .validate()
+    .is_ok());
+    assert_eq!(
+        serde_json::to_value(
+            &TestStruct {
+                val: "no".to_owned()
+            }
+            .validate()
+            .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["invalid value."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn composition_vec_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(maximum = 0, minimum = 10))]
+        val: Vec<i32>,
+    }
+
+    // Each schema validates all the items.
+    assert!(TestStruct { val: vec![-1, -2] }.validate().is_ok());
+    assert!(TestStruct { val: vec![10, 11] }.validate().is_ok());
+    assert!(TestStruct { val: vec![-1, 10] }.validate().is_err());
+}
+
+#[derive(Serialize, Validate, JsonSchema)]
+struct TestSchemaStruct {
+    #[validate(any_of(pattern = r"^\d+$", max_length = 3))]
+    code: String,
+    #[validate(one_of(minimum = 10, multiple_of = 2))]
+    #[validate(not(enumerate(11, 13)))]
+    val: i32,
+    #[validate(any_of(maximum = 0, minimum = 10))]
+    vals: Vec<i32>,
+}
+
+#[test]
+fn composition_json_schema() {
+    assert_eq!(
+        TestSchemaStruct::json_schema()["properties"],
+        json!({
+            "code": {
+                "type": "string",
+                "anyOf": [
+                    { "type": "string", "pattern": r"^\d+$" },
+                    { "type": "string", "maxLength": 3 }
+                ]
+            },
+            "val": {
+                "type": "integer",
+                "oneOf": [
+                    { "type": "integer", "minimum": 10 },
+                    { "type": "integer", "multipleOf": 2 }
+                ],
+                "not": { "type": "integer", "enum": [11, 13] }
+            },
+            "vals": {
+                "type": "array",
+                "items": { "type": "integer" },
+                "anyOf": [
+                    { "type": "array", "items": { "type": "integer", "maximum": 0 } },
+                    { "type": "array", "items": { "type": "integer", "minimum": 10 } }
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+fn composition_schema_same_errors_as_derive() {
+    let schema = Schema::new(&TestSchemaStruct::json_schema()).unwrap();
+
+    for s in [
+        TestSchemaStruct {
+            code: "abcd".to_owned(),
+            val: 12,
+            vals: vec![],
+        },
+        TestSchemaStruct {
+            code: "1234".to_owned(),
+            val: 13,
+            vals: vec![-1, 10],
+        },
+    ] {
+        assert_eq!(
+            serde_json::to_value(
+                &schema
+                    .validate(&serde_json::to_value(&s).unwrap())
+                    .unwrap_err()
+            )
+            .unwrap(),
+            serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::validation::Group;
+use serde_valid::{JsonSchema, Validate};
+
+#[derive(Debug, PartialEq, Serialize, JsonSchema)]
+enum Kind {
+    Person,
+    Company,
+}
+
+fn is_company(customer: &TestCustomer) -> bool {
+    customer.kind == Kind::Company
+}
+
+#[derive(Serialize, Validate, JsonSchema)]
+struct TestCustomer {
+    kind: Kind,
+    #[validate(min_length = 1, when = "self.kind == Kind::Company")]
+    company_name: String,
+    #[validate(max_length = 4, when(is_company))]
+    #[validate(min_length = 2)]
+    tax_id: String,
+}
+
+#[test]
+fn when_is_false() {
+    let s = TestCustomer {
+        kind: Kind::Person,
+        company_name: "".to_owned(),
+        tax_id: "123456".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn when_is_true() {
+    let s = TestCustomer {
+        kind: Kind::Company,
+        company_name: "".to_owned(),
+        tax_id: "123456".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "company_name": {
+                    "errors": ["the length of the value must be `>= 1`."]
+                },
+                "tax_id": {
+                    "errors": ["the length of the value must be `<= 4`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_does_not_guard_other_validators() {
+    let s = TestCustomer {
+        kind: Kind::Person,
+        company_name: "".to_owned(),
+        tax_id: "1".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tax_id": {
+                    "errors": ["the length of the value must be `>= 2`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_with_groups() {
+    #[derive(Validate)]
+    struct TestStruct {
+        enabled: bool,
+        #[validate(maximum = 10, groups(update), when = "self.enabled")]
+        val: i32,
+    }
+
+    let update = Group::new("update");
+    let s = TestStruct {
+        enabled: true,
+        val: 11,
+    };
+    assert!(s.validate().is_ok());
+    assert!(s.validate_group(update).is_err());
+
+    let s = TestStruct {
+        enabled: false,
+        val: 11,
+    };
+    assert!(s.validate_group(update).is_ok());
+}
+
+#[test]
+fn when_enum_variant() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            strict: bool,
+            #[validate(
+                maximum = 10,
+                when = "matches!(self, Self::Named { strict: true, .. })"
+            )]
+            val: i32,
+        },
+    }
+
+    assert!(TestEnum::Named {
+        strict: false,
+        val: 11
+    }
+    .validate()
+    .is_ok());
+    assert!(TestEnum::Named {
+        strict: true,
+        val: 11
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn when_is_not_json_schema_keyword() {
+    assert_eq!(
+        TestCustomer::json_schema()["properties"]["tax_id"],
+        json!({
+            "type": "string",
+            "minLength": 2
+        })
+    );
+}
+
+#[derive(Serialize, Validate, JsonSchema)]
+#[rule(dependent_required(credit_card, billing_address, billing_zip))]
+struct TestPayment {
+    credit_card: Option<String>,
+    #[serde(rename = "billingAddress")]
+    billing_address: Option<String>,
+    billing_zip: Option<String>,
+}
+
+#[test]
+fn dependent_required_is_ok() {
+    let s = TestPayment {
+        credit_card: None,
+        billing_address: None,
+        billing_zip: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestPayment {
+        credit_card: Some("1234".to_owned()),
+        billing_address: Some("Tokyo".to_owned()),
+        billing_zip: Some("100-0001".to_owned()),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn dependent_required_is_err() {
+    let s = TestPayment {
+        credit_card: Some("1234".to_owned()),
+        billing_address: None,
+        billing_zip: Some("100-0001".to_owned()),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "billingAddress": {
+                    "errors": ["the property is required."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn dependent_required_json_schema() {
+    assert_eq!(
+        TestPayment::json_schema()["dependentRequired"],
+        json!({
+            "credit_card": ["billingAddress", "billing_zip"]
+        })
+    );
+}
+
+#[test]
+fn dependent_required_schema_validation() {
+    let schema = Schema::new(&TestPayment::json_schema()).unwrap();
+
+    assert!(schema.validate(&json!({})).is_ok());
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&json!({ "credit_card": "1234", "billing_zip": "100-0001" }))
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "billingAddress": {
+                    "errors": ["the property is required."]
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,185 @@
+use serde_json::json;
+use serde_valid::{JsonSchema, Validate};
+use std::borrow::Cow;
+
+#[test]
+fn const_value_integer_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 2)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 2 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_float_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 0.5)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: 0.5 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_bool_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = true)]
+        val: bool,
+    }
+
+    let s = TestStruct { val: true };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_char_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 'a')]
+        val: char,
+    }
+
+    let s = TestStruct { val: 'a' };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_string_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(const_value = "v1")]
+        val1: String,
+        #[validate(const_value = "v1")]
+        val2: &'a str,
+        #[validate(const_value = "v1")]
+        val3: Cow<'a, str>,
+    }
+
+    let s = TestStruct {
+        val1: String::from("v1"),
+        val2: "v1",
+        val3: Cow::from("v1"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_vec_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 1)]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct { val: vec![1, 1] };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_option_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn const_value_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 1)]
+        val: u8,
+    }
+
+    let s = TestStruct { val: 2 };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn const_value_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("v2"),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the value must be exactly `v1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn const_value_custom_err_message_fn() {
+    fn error_message(_params: &serde_valid::ConstErrorParams) -> String {
+        "this is custom message.".to_string()
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = 1, message_fn(error_message))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 2 };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["this is custom message."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn const_value_json_schema() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct TestStruct {
+        #[validate(const_value = "v1")]
+        version: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema()["properties"]["version"],
+        json!({ "type": "string", "const": "v1" })
+    );
+}
+
+#[test]
+fn const_value_schema_validation() {
+    let schema = serde_valid::json::Schema::new(&json!({ "const": "v1" })).unwrap();
+
+    assert!(schema.validate(&json!("v1")).is_ok());
+    assert_eq!(
+        serde_json::to_value(&schema.validate(&json!("v2")).unwrap_err()).unwrap(),
+        json!({ "errors": ["the value must be exactly `v1`."] })
+    );
+}
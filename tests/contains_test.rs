@@ -0,0 +1,224 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::{JsonSchema, Validate};
+
+#[test]
+fn contains_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10))]
+        val: Vec<i32>,
+    }
+
+    assert!(TestStruct { val: vec![1, 10] }.validate().is_ok());
+}
+
+#[test]
+fn contains_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10))]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct { val: vec![1, 2] };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the items must contain at least one matching item."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn min_contains_and_max_contains_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10), min_contains = 2, max_contains = 3)]
+        val: [i32; 4],
+    }
+
+    assert!(TestStruct {
+        val: [1, 10, 20, 30]
+    }
+    .validate()
+    .is_ok());
+
+    let errors = TestStruct { val: [1, 2, 3, 10] }.validate().unwrap_err();
+    let (_, error) = errors.iter_flat().next().unwrap();
+    assert_eq!(
+        serde_json::to_value(error.to_detailed()).unwrap(),
+        json!({
+            "keyword": "minContains",
+            "params": { "min_contains": 2 },
+            "message": "the number of the matching items must be `>= 2`."
+        })
+    );
+
+    let errors = TestStruct {
+        val: [10, 20, 30, 40],
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        errors
+            .iter_flat()
+            .map(|(pointer, error)| (pointer, error.to_string()))
+            .collect::<Vec<_>>(),
+        vec![(
+            "/val".to_owned(),
+            "the number of the matching items must be `<= 3`.".to_owned()
+        )]
+    );
+}
+
+#[test]
+fn min_contains_zero_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(minimum = 10), min_contains = 0, max_contains = 1)]
+        val: Vec<i32>,
+    }
+
+    assert!(TestStruct { val: vec![] }.validate().is_ok());
+    assert!(TestStruct { val: vec![10, 11] }.validate().is_err());
+}
+
+fn is_primary(address: &Address) -> Result<(), serde_valid::validation::Error> {
+    if address.primary {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "not primary.".to_owned(),
+        ))
+    }
+}
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(min_length = 1)]
+    city: String,
+    primary: bool,
+}
+
+#[test]
+fn contains_custom_with_message_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        #[validate(
+            contains(custom(is_primary)),
+            max_contains = 1,
+            message = "exactly one primary address is required."
+        )]
+        addresses: Option<Vec<Address>>,
+    }
+
+    assert!(TestStruct { addresses: None }.validate().is_ok());
+    assert!(TestStruct {
+        addresses: Some(vec![
+            Address {
+                city: "Tokyo".to_owned(),
+                primary: false,
+            },
+            Address {
+                city: "Osaka".to_owned(),
+                primary: true,
+            },
+        ])
+    }
+    .validate()
+    .is_ok());
+
+    let s = TestStruct {
+        addresses: Some(vec![Address {
+            city: "".to_owned(),
+            primary: false,
+        }]),
+    };
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "addresses": {
+                    "errors": ["exactly one primary address is required."],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "city": {
+                                    "errors": ["the length of the value must be `>= 1`."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[derive(Debug, Serialize, Validate, JsonSchema)]
+struct TestSchemaStruct {
+    #[validate(max_items = 5)]
+    #[validate(contains(minimum = 10), min_contains = 2, max_contains = 3)]
+    val: Vec<i32>,
+    #[validate(contains(pattern = "^[a-z]+$"))]
+    names: Vec<String>,
+}
+
+#[test]
+fn contains_json_schema() {
+    assert_eq!(
+        TestSchemaStruct::json_schema()["properties"],
+        json!({
+            "val": {
+                "type": "array",
+                "items": { "type": "integer" },
+                "maxItems": 5,
+                "contains": { "type": "integer", "minimum": 10 },
+                "minContains": 2,
+                "maxContains": 3
+            },
+            "names": {
+                "type": "array",
+                "items": { "type": "string" },
+                "contains": { "type": "string", "pattern": "^[a-z]+$" }
+            }
+        })
+    );
+}
+
+#[test]
+fn contains_schema_same_errors_as_derive() {
+    let schema = Schema::new(&TestSchemaStruct::json_schema()).unwrap();
+
+    for s in [
+        TestSchemaStruct {
+            val: vec![1, 10],
+            names: vec!["A".to_owned()],
+        },
+        TestSchemaStruct {
+            val: vec![10, 11, 12, 13, 14, 15],
+            names: vec!["a".to_owned()],
+        },
+    ] {
+        assert_eq!(
+            serde_json::to_value(
+                &schema
+                    .validate(&serde_json::to_value(&s).unwrap())
+                    .unwrap_err()
+            )
+            .unwrap(),
+            serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+        );
+    }
+}
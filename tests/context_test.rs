@@ -0,0 +1,254 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateWith};
+
+struct Quota {
+    max_tags: usize,
+    reserved_names: Vec<&'static str>,
+}
+
+fn tags_quota(tags: &Vec<String>, quota: &Quota) -> Result<(), serde_valid::validation::Error> {
+    if tags.len() <= quota.max_tags {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "too many tags.".to_owned(),
+        ))
+    }
+}
+
+fn not_reserved(name: &String, quota: &Quota) -> Result<(), serde_valid::validation::Error> {
+    if quota.reserved_names.contains(&name.as_str()) {
+        Err(serde_valid::validation::Error::Custom(
+            "the name is reserved.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn default_quota() -> Quota {
+    Quota {
+        max_tags: 1,
+        reserved_names: vec!["admin"],
+    }
+}
+
+#[test]
+fn context_custom_is_ok() {
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    struct TestStruct {
+        #[validate(custom(tags_quota))]
+        #[validate(max_items = 3)]
+        tags: Vec<String>,
+    }
+
+    let s = TestStruct {
+        tags: vec!["a".to_owned()],
+    };
+    assert!(s.validate_with(&default_quota()).is_ok());
+}
+
+#[test]
+fn context_custom_err_message() {
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    struct TestStruct {
+        #[validate(custom(tags_quota))]
+        tags: Vec<String>,
+    }
+
+    let s = TestStruct {
+        tags: vec!["a".to_owned(), "b".to_owned()],
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate_with(&default_quota()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tags": {
+                    "errors": ["too many tags."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn context_rule() {
+    fn rule(
+        name: &String,
+        tags: &Vec<String>,
+        quota: &Quota,
+    ) -> Result<(), serde_valid::validation::Error> {
+        if tags.contains(name) || tags.len() < quota.max_tags {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "the name must be in tags.".to_owned(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    #[rule(rule(name, tags))]
+    struct TestStruct {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    let s = TestStruct {
+        name: "a".to_owned(),
+        tags: vec!["b".to_owned()],
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate_with(&default_quota()).unwrap_err()).unwrap(),
+        json!({
+            "errors": ["the name must be in tags."],
+            "properties": {}
+        })
+    );
+}
+
+#[test]
+fn context_unnamed_struct_rule() {
+    fn rule(val: &i32, quota: &Quota) -> Result<(), serde_valid::validation::Error> {
+        if *val as usize <= quota.max_tags {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "over the quota.".to_owned(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    #[rule(rule(0))]
+    struct TestStruct(i32, #[validate(custom(not_reserved))] String);
+
+    assert!(TestStruct(1, "a".to_owned())
+        .validate_with(&default_quota())
+        .is_ok());
+    assert!(TestStruct(2, "a".to_owned())
+        .validate_with(&default_quota())
+        .is_err());
+}
+
+#[test]
+fn context_forward_to_nested_fields() {
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    struct TestStruct {
+        #[validate]
+        users: Vec<TestUser>,
+        #[validate]
+        owner: Option<TestUser>,
+        #[validate]
+        plain: TestPlain,
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    struct TestUser {
+        #[validate(custom(not_reserved))]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    struct TestPlain {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let s = TestStruct {
+        users: vec![
+            TestUser {
+                name: "alice".to_owned(),
+            },
+            TestUser {
+                name: "admin".to_owned(),
+            },
+        ],
+        owner: Some(TestUser {
+            name: "admin".to_owned(),
+        }),
+        plain: TestPlain { val: 11 },
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate_with(&default_quota()).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "users": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {
+                                    "errors": ["the name is reserved."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "owner": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": ["the name is reserved."]
+                        }
+                    }
+                },
+                "plain": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["the number must be `<= 10`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn context_enum() {
+    #[derive(Validate)]
+    #[validate(context = Quota)]
+    enum TestEnum {
+        Named {
+            #[validate(custom(not_reserved))]
+            name: String,
+        },
+        Unnamed(#[validate(custom(tags_quota))] Vec<String>),
+    }
+
+    assert!(TestEnum::Named {
+        name: "admin".to_owned()
+    }
+    .validate_with(&default_quota())
+    .is_err());
+    assert!(TestEnum::Unnamed(vec!["a".to_owned()])
+        .validate_with(&default_quota())
+        .is_ok());
+}
+
+#[test]
+fn validate_with_without_context() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 11 };
+    assert!(s.validate_with(&default_quota()).is_err());
+    assert!(s.validate_with(&()).is_err());
+}
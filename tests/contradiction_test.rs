@@ -0,0 +1,47 @@
+use serde_valid::Validate;
+
+#[test]
+fn contradiction_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        #[validate(maximum = 10)]
+        #[validate(enumerate(0, 5, 10))]
+        val: i32,
+        // The validators in the different groups never run together.
+        #[validate(minimum = 10, groups(strict))]
+        #[validate(maximum = 5)]
+        grouped_val: i32,
+    }
+
+    assert!(TestStruct {
+        val: 5,
+        grouped_val: 5
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn contradiction_allow_contradiction() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(min_length = 5)]
+            #[validate(max_length = 2, allow_contradiction)]
+            val: String,
+        },
+        Unnamed(
+            #[validate(minimum = 10)]
+            #[validate(maximum = 5, allow_contradiction)]
+            i32,
+        ),
+    }
+
+    assert!(TestEnum::Named {
+        val: "abc".to_owned()
+    }
+    .validate()
+    .is_err());
+    assert!(TestEnum::Unnamed(7).validate().is_err());
+}
@@ -0,0 +1,144 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn detailed_error_keyword_and_params() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        val1: i32,
+        #[validate(exclusive_maximum = 1.5)]
+        val2: f64,
+        #[validate(max_length = 2)]
+        #[validate(pattern = r"^\d+$")]
+        val3: String,
+        #[validate(enumerate(1, 2))]
+        val4: i32,
+        #[validate(unique_items)]
+        val5: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val1: -1,
+        val2: 1.5,
+        val3: "abc".to_owned(),
+        val4: 3,
+        val5: vec![1, 1],
+    };
+
+    assert_eq!(
+        s.validate()
+            .unwrap_err()
+            .iter_flat()
+            .map(|(_, error)| serde_json::to_value(error.to_detailed()).unwrap())
+            .collect::<Vec<_>>(),
+        vec![
+            json!({
+                "keyword": "minimum",
+                "params": { "minimum": 0 },
+                "message": "the number must be `>= 0`."
+            }),
+            json!({
+                "keyword": "exclusiveMaximum",
+                "params": { "exclusive_maximum": 1.5 },
+                "message": "the number must be `< 1.5`."
+            }),
+            json!({
+                "keyword": "maxLength",
+                "params": { "max_length": 2 },
+                "message": "the length of the value must be `<= 2`."
+            }),
+            json!({
+                "keyword": "pattern",
+                "params": { "pattern": r"^\d+$" },
+                "message": r#"the value must match the pattern of "^\d+$"."#
+            }),
+            json!({
+                "keyword": "enum",
+                "params": { "enumerate": [1, 2] },
+                "message": "the value must be in [1, 2]."
+            }),
+            json!({
+                "keyword": "uniqueItems",
+                "params": {},
+                "message": "items must be unique."
+            }),
+        ]
+    );
+}
+
+#[test]
+fn detailed_custom_error() {
+    fn custom_validation(_val: &i32) -> Result<(), serde_valid::validation::Error> {
+        Err(serde_valid::validation::Error::Custom(
+            "custom error.".to_owned(),
+        ))
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(custom_validation))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 1 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_detailed(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        {
+                            "keyword": "custom",
+                            "params": {},
+                            "message": "custom error."
+                        }
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn detailed_errors_tree() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_items = 1)]
+        #[validate(maximum = 10)]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct { val: vec![1, 11] };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_detailed(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        {
+                            "keyword": "maxItems",
+                            "params": { "max_items": 1 },
+                            "message": "the length of the items must be `<= 1`."
+                        }
+                    ],
+                    "items": {
+                        "1": {
+                            "errors": [
+                                {
+                                    "keyword": "maximum",
+                                    "params": { "maximum": 10 },
+                                    "message": "the number must be `<= 10`."
+                                }
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
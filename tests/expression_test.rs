@@ -0,0 +1,148 @@
+use serde_json::json;
+use serde_valid::{JsonSchema, Validate};
+
+const MAX_NAME_LENGTH: usize = 4;
+const MIN_TAGS: usize = 1;
+const CODE_PATTERN: &str = r"^\d+$";
+const LEVEL_LOW: i32 = 1;
+const LEVEL_HIGH: i32 = 3;
+
+mod limits {
+    pub const MAX_SCORE: u32 = u16::MAX as u32 / 2;
+}
+
+#[derive(Validate, JsonSchema)]
+struct TestStruct {
+    #[validate(max_length = MAX_NAME_LENGTH)]
+    name: String,
+    #[validate(maximum = u16::MAX as u32 / 2)]
+    #[validate(exclusive_maximum = limits::MAX_SCORE + 1)]
+    score: u32,
+    #[validate(pattern = CODE_PATTERN)]
+    code: String,
+    #[validate(enumerate(LEVEL_LOW, 2, LEVEL_HIGH))]
+    level: i32,
+    #[validate(min_items = MIN_TAGS)]
+    #[validate(max_items = MIN_TAGS * 2)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn expression_limits_is_ok() {
+    let s = TestStruct {
+        name: "abcd".to_owned(),
+        score: limits::MAX_SCORE,
+        code: "123".to_owned(),
+        level: LEVEL_HIGH,
+        tags: vec!["a".to_owned()],
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn expression_limits_is_err() {
+    let s = TestStruct {
+        name: "abcde".to_owned(),
+        score: limits::MAX_SCORE + 1,
+        code: "abc".to_owned(),
+        level: 4,
+        tags: vec![],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["the length of the value must be `<= 4`."]
+                },
+                "score": {
+                    "errors": [
+                        "the number must be `<= 32767`.",
+                        "the number must be `< 32768`."
+                    ]
+                },
+                "code": {
+                    "errors": ["the value must match the pattern of \"^\\d+$\"."]
+                },
+                "level": {
+                    "errors": ["the value must be in [1, 2, 3]."]
+                },
+                "tags": {
+                    "errors": ["the length of the items must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn expression_const_value() {
+    const EXPECTED: &str = "fixed";
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(const_value = EXPECTED)]
+        val: &'static str,
+    }
+
+    assert!(TestStruct { val: "fixed" }.validate().is_ok());
+    assert!(TestStruct { val: "other" }.validate().is_err());
+}
+
+#[test]
+fn expression_json_schema() {
+    assert_eq!(
+        TestStruct::json_schema()["properties"],
+        json!({
+            "name": {
+                "type": "string",
+                "maxLength": 4
+            },
+            "score": {
+                "type": "integer",
+                "maximum": 32767,
+                "exclusiveMaximum": 32768
+            },
+            "code": {
+                "type": "string",
+                "pattern": "^\\d+$"
+            },
+            "level": {
+                "type": "integer",
+                "enum": [1, 2, 3]
+            },
+            "tags": {
+                "type": "array",
+                "items": {
+                    "type": "string"
+                },
+                "minItems": 1,
+                "maxItems": 2
+            }
+        })
+    );
+}
+
+#[test]
+fn expression_invalid_pattern_is_err() {
+    const INVALID_PATTERN: &str = r"^(\d+$";
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = INVALID_PATTERN)]
+        code: String,
+    }
+
+    let errors = TestStruct {
+        code: "123".to_owned(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let (pointer, error) = errors.iter_flat().next().unwrap();
+    assert_eq!(pointer, "/code");
+    assert!(error.to_string().starts_with("the pattern is invalid: "));
+}
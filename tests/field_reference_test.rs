@@ -0,0 +1,243 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn range_field_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: i32,
+        #[validate(minimum_field = start)]
+        #[validate(exclusive_maximum_field = limit)]
+        end: i32,
+        limit: i32,
+    }
+
+    let s = TestStruct {
+        start: 1,
+        end: 1,
+        limit: 2,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn range_field_is_err() {
+    #[derive(Deserialize, Validate)]
+    struct TestStruct {
+        #[validate(maximum_field = limit)]
+        start: i32,
+        #[validate(exclusive_minimum_field = start)]
+        #[serde(rename = "endAt")]
+        end: i32,
+        limit: i32,
+    }
+
+    let s = TestStruct {
+        start: 5,
+        end: 5,
+        limit: 4,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "start": {
+                    "errors": ["the number must be `<= 4`."]
+                },
+                "endAt": {
+                    "errors": ["the number must be `> 5`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn range_field_vec_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        min: u32,
+        #[validate(minimum_field = min)]
+        vals: Vec<u32>,
+    }
+
+    let s = TestStruct {
+        min: 2,
+        vals: vec![3, 1],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["the number must be `>= 2`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn range_field_option_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: Option<i32>,
+        #[validate(minimum_field = start)]
+        end: Option<i32>,
+        #[validate(maximum_field = start)]
+        before: i32,
+    }
+
+    // The validations are skipped when the field or the referenced field is `None`.
+    assert!(TestStruct {
+        start: None,
+        end: Some(1),
+        before: 10,
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        start: Some(1),
+        end: None,
+        before: 1,
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn range_field_option_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: Option<i32>,
+        #[validate(minimum_field = start)]
+        end: Option<i32>,
+        #[validate(maximum_field = start)]
+        before: i32,
+    }
+
+    let s = TestStruct {
+        start: Some(5),
+        end: Some(4),
+        before: 6,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {
+                    "errors": ["the number must be `>= 5`."]
+                },
+                "before": {
+                    "errors": ["the number must be `<= 5`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn eq_field_is_err() {
+    #[derive(Deserialize, Validate)]
+    struct TestStruct {
+        #[serde(rename = "passWord")]
+        password: String,
+        #[validate(eq_field = password)]
+        confirm: String,
+    }
+
+    let ok = TestStruct {
+        password: "secret".to_owned(),
+        confirm: "secret".to_owned(),
+    };
+    assert!(ok.validate().is_ok());
+
+    let s = TestStruct {
+        password: "secret".to_owned(),
+        confirm: "other".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "confirm": {
+                    "errors": ["the value must be equal to the `passWord` field."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn field_reference_custom_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: i32,
+        #[validate(minimum_field = start, message = "{field} must be at least {minimum}.")]
+        end: i32,
+        password: String,
+        #[validate(eq_field = password, message = "{field} must match {eq_field}.")]
+        confirm: String,
+    }
+
+    let s = TestStruct {
+        start: 5,
+        end: 4,
+        password: "secret".to_owned(),
+        confirm: "other".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {
+                    "errors": ["end must be at least 5."]
+                },
+                "confirm": {
+                    "errors": ["confirm must match password."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn field_reference_enum_variant() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Range {
+            start: i32,
+            #[validate(minimum_field = start)]
+            end: i32,
+        },
+    }
+
+    assert!(TestEnum::Range { start: 1, end: 2 }.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(TestEnum::Range { start: 3, end: 2 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {
+                    "errors": ["the number must be `>= 3`."]
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,147 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn iter_flat_pointers() {
+    #[derive(Deserialize, Validate)]
+    struct TestStruct {
+        #[validate(max_items = 2)]
+        #[validate]
+        items: Vec<TestItem>,
+        #[validate(maximum = 10)]
+        vals: Vec<i32>,
+        #[validate]
+        #[serde(rename = "a/b")]
+        child: TestItem,
+    }
+
+    #[derive(Deserialize, Validate)]
+    struct TestItem {
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    let s = TestStruct {
+        items: vec![
+            TestItem {
+                name: "a".to_owned(),
+            },
+            TestItem {
+                name: "".to_owned(),
+            },
+            TestItem {
+                name: "".to_owned(),
+            },
+        ],
+        vals: vec![1, 11],
+        child: TestItem {
+            name: "".to_owned(),
+        },
+    };
+
+    assert_eq!(
+        s.validate()
+            .unwrap_err()
+            .iter_flat()
+            .map(|(pointer, error)| (pointer, error.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "/items".to_owned(),
+                "the length of the items must be `<= 2`.".to_owned()
+            ),
+            (
+                "/items/1/name".to_owned(),
+                "the length of the value must be `>= 1`.".to_owned()
+            ),
+            (
+                "/items/2/name".to_owned(),
+                "the length of the value must be `>= 1`.".to_owned()
+            ),
+            (
+                "/vals/1".to_owned(),
+                "the number must be `<= 10`.".to_owned()
+            ),
+            (
+                "/a~1b/name".to_owned(),
+                "the length of the value must be `>= 1`.".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn flatten_serialize() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        #[validate(unique_items)]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: 11,
+        vals: vec![1, 1],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().flatten()).unwrap(),
+        json!([
+            {
+                "instanceLocation": "/val",
+                "message": "the number must be `<= 10`."
+            },
+            {
+                "instanceLocation": "/vals",
+                "message": "items must be unique."
+            }
+        ])
+    );
+}
+
+#[test]
+fn flatten_new_type_root() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(maximum = 10)] i32);
+
+    let s = TestStruct(11);
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().flatten()).unwrap(),
+        json!([
+            {
+                "instanceLocation": "",
+                "message": "the number must be `<= 10`."
+            }
+        ])
+    );
+}
+
+#[test]
+fn flatten_nested_vec_item_index() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        vals: Vec<Vec<i32>>,
+    }
+
+    let s = TestStruct {
+        vals: vec![vec![1, 2], vec![3, 11, 12]],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().flatten()).unwrap(),
+        json!([
+            {
+                "instanceLocation": "/vals/1/1",
+                "message": "the number must be `<= 10`."
+            },
+            {
+                "instanceLocation": "/vals/1/2",
+                "message": "the number must be `<= 10`."
+            }
+        ])
+    );
+}
@@ -0,0 +1,187 @@
+use serde_json::json;
+use serde_valid::{JsonSchema, Validate, ValidateFormat};
+use std::borrow::Cow;
+
+#[test]
+fn format_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        email: String,
+        #[validate(format = "date-time")]
+        created_at: String,
+        #[validate(format = "uuid")]
+        id: String,
+    }
+
+    let s = TestStruct {
+        email: String::from("user@example.com"),
+        created_at: String::from("2020-09-10T12:34:56.789+09:00"),
+        id: String::from("123e4567-e89b-12d3-a456-426614174000"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "ipv4")]
+        val: &'a str,
+    }
+
+    let s = TestStruct { val: "127.0.0.1" };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_cow_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "uri")]
+        val: Cow<'a, str>,
+    }
+
+    let s = TestStruct {
+        val: Cow::from("https://example.com/path?query#fragment"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_vec_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "hostname")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("example.com"), String::from("localhost")],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_option_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date")]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "time")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("25:00:00Z"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn format_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![
+            String::from("user@example.com"),
+            String::from("example.com"),
+        ],
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["the value must match the format of \"email\"."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn format_custom_err_message() {
+    fn error_message(_params: &serde_valid::FormatErrorParams) -> String {
+        "this is custom message.".to_string()
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "ipv6", message_fn(error_message))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("127.0.0.1"),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["this is custom message."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn format_trait() {
+    assert!(ValidateFormat::validate_format(
+        "user@[127.0.0.1]",
+        serde_valid::validation::Format::Email
+    )
+    .is_ok());
+}
+
+#[test]
+fn format_json_schema() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema()["properties"]["val"],
+        json!({ "type": "string", "format": "email" })
+    );
+}
+
+#[test]
+fn format_schema_validation() {
+    let schema = serde_valid::json::Schema::new(&json!({
+        "type": "string",
+        "format": "date"
+    }))
+    .unwrap();
+
+    assert!(schema.validate(&json!("2020-02-29")).is_ok());
+    assert!(schema.validate(&json!("2021-02-29")).is_err());
+}
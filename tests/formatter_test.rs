@@ -0,0 +1,143 @@
+use serde_json::json;
+use serde_valid::validation::{Error, FlatError, IntoError, MessageFormatter};
+use serde_valid::{Validate, ValidateMaximum};
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    val: i32,
+    #[validate(min_length = 4, message = "too short.")]
+    name: String,
+    #[validate]
+    items: Vec<TestItem>,
+}
+
+#[derive(Validate)]
+struct TestItem {
+    #[validate(minimum = 0)]
+    val: i32,
+}
+
+fn invalid_struct() -> TestStruct {
+    TestStruct {
+        val: 11,
+        name: "abc".to_owned(),
+        items: vec![TestItem { val: -1 }],
+    }
+}
+
+struct Locale(&'static str);
+
+impl MessageFormatter for Locale {
+    fn format_message(&self, error: &Error) -> Option<String> {
+        match (self.0, error) {
+            ("ja", Error::Maximum(message)) => Some(format!(
+                "値は `{}` 以下でなければなりません。",
+                message.params().maximum
+            )),
+            ("ja", Error::Minimum(message)) => Some(format!(
+                "値は `{}` 以上でなければなりません。",
+                message.params().minimum
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn formatted_without_formats_is_same_as_serialize() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(errors.formatted(&Locale("en"))).unwrap(),
+        serde_json::to_value(&errors).unwrap()
+    );
+    assert_eq!(
+        errors.formatted(&Locale("en")).to_string(),
+        errors.to_string()
+    );
+}
+
+#[test]
+fn formatted_by_locale() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(errors.formatted(&Locale("ja"))).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["値は `10` 以下でなければなりません。"]
+                },
+                "name": {
+                    "errors": ["too short."]
+                },
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["値は `0` 以上でなければなりません。"]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn flatten_with_formatter() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        errors.flatten_with(&|error: &Error| Some(error.keyword().to_owned())),
+        vec![
+            FlatError::new("/val".to_owned(), "maximum".to_owned()),
+            FlatError::new("/name".to_owned(), "minLength".to_owned()),
+            FlatError::new("/items/0/val".to_owned(), "minimum".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn into_error_by_capturing_closure() {
+    let unit = "km".to_owned();
+    let error = 11
+        .validate_maximum(10)
+        .map_err(|params| {
+            serde_valid::validation::Composited::Single(params)
+                .into_error_by(move |params| format!("must be <= {}{unit}.", params.maximum))
+        })
+        .unwrap_err();
+
+    assert_eq!(error.to_string(), "must be <= 10km.");
+}
+
+#[test]
+fn default_message_has_no_message_fn() {
+    let errors = invalid_struct().validate().unwrap_err();
+    let messages = errors
+        .iter_flat()
+        .map(|(pointer, error)| match error {
+            Error::Maximum(message) => (pointer, message.message_fn().is_none()),
+            Error::MinLength(message) => (pointer, message.message_fn().is_none()),
+            Error::Minimum(message) => (pointer, message.message_fn().is_none()),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        vec![
+            ("/val".to_owned(), true),
+            ("/name".to_owned(), false),
+            ("/items/0/val".to_owned(), true),
+        ]
+    );
+}
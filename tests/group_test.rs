@@ -0,0 +1,247 @@
+use serde_json::json;
+use serde_valid::validation::{Error, Group};
+use serde_valid::{JsonSchema, Validate, ValidateWith};
+
+const CREATE: Group = Group::new("create");
+const UPDATE: Group = Group::new("update");
+
+fn must_be_none(id: &Option<u32>) -> Result<(), Error> {
+    match id {
+        Some(_) => Err(Error::Custom("the id is not allowed.".to_owned())),
+        None => Ok(()),
+    }
+}
+
+fn must_be_some(id: &Option<u32>) -> Result<(), Error> {
+    match id {
+        Some(_) => Ok(()),
+        None => Err(Error::Custom("the id is required.".to_owned())),
+    }
+}
+
+#[derive(Validate)]
+struct TestUser {
+    #[validate(custom(must_be_none), groups(create))]
+    #[validate(custom(must_be_some), groups(update))]
+    #[validate(minimum = 1, groups(update))]
+    id: Option<u32>,
+    #[validate(min_length = 1)]
+    #[validate(max_length = 4, groups(default, update))]
+    name: String,
+}
+
+#[test]
+fn group_validate_runs_default_group() {
+    let s = TestUser {
+        id: Some(0),
+        name: "abcde".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["the length of the value must be `<= 4`."]
+                }
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        serde_json::to_value(&s.validate_group(Group::DEFAULT).unwrap_err()).unwrap()
+    );
+}
+
+#[test]
+fn group_create() {
+    let s = TestUser {
+        id: Some(1),
+        name: "".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate_group(CREATE).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {
+                    "errors": ["the id is not allowed."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn group_update() {
+    let s = TestUser {
+        id: None,
+        name: "abcde".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate_group(UPDATE).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {
+                    "errors": ["the id is required."]
+                },
+                "name": {
+                    "errors": ["the length of the value must be `<= 4`."]
+                }
+            }
+        })
+    );
+    assert!(TestUser {
+        id: Some(1),
+        name: "abc".to_owned(),
+    }
+    .validate_group(UPDATE)
+    .is_ok());
+}
+
+#[test]
+fn group_nested_fields() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        users: Vec<TestUser>,
+        #[validate]
+        owner: Option<TestUser>,
+    }
+
+    let s = TestStruct {
+        users: vec![
+            TestUser {
+                id: None,
+                name: "a".to_owned(),
+            },
+            TestUser {
+                id: Some(1),
+                name: "b".to_owned(),
+            },
+        ],
+        owner: Some(TestUser {
+            id: Some(2),
+            name: "c".to_owned(),
+        }),
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&s.validate_group(CREATE).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "users": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "id": {
+                                    "errors": ["the id is not allowed."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "owner": {
+                    "errors": [],
+                    "properties": {
+                        "id": {
+                            "errors": ["the id is not allowed."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn group_rule_runs_default_group() {
+    fn rule(_val: &i32) -> Result<(), Error> {
+        Err(Error::Custom("rule error.".to_owned()))
+    }
+
+    #[derive(Validate)]
+    #[rule(rule(val))]
+    struct TestStruct {
+        val: i32,
+    }
+
+    let s = TestStruct { val: 1 };
+    assert!(s.validate().is_err());
+    assert!(s.validate_group(UPDATE).is_ok());
+}
+
+#[test]
+fn group_with_context() {
+    struct Limit(usize);
+
+    fn max_len(name: &String, limit: &Limit) -> Result<(), Error> {
+        if name.len() <= limit.0 {
+            Ok(())
+        } else {
+            Err(Error::Custom("the name is too long.".to_owned()))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = Limit)]
+    struct TestStruct {
+        #[validate(custom(max_len), groups(update))]
+        name: String,
+        #[validate]
+        user: TestUser,
+    }
+
+    let s = TestStruct {
+        name: "abc".to_owned(),
+        user: TestUser {
+            id: None,
+            name: "a".to_owned(),
+        },
+    };
+
+    assert!(s.validate_with(&Limit(2)).is_ok());
+    assert_eq!(
+        serde_json::to_value(&s.validate_group_with(&Limit(2), UPDATE).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["the name is too long."]
+                },
+                "user": {
+                    "errors": [],
+                    "properties": {
+                        "id": {
+                            "errors": ["the id is required."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn group_json_schema_ignores_groups() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct TestStruct {
+        #[validate(minimum = 1, groups(update))]
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema()["properties"]["val"],
+        json!({ "type": "integer", "maximum": 10 })
+    );
+}
@@ -178,7 +178,7 @@ fn items_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 4, message_fn(min_error_message))]
-        #[validate(max_items = 2, message_fn(max_error_message))]
+        #[validate(max_items = 2, allow_contradiction, message_fn(max_error_message))]
         val: Vec<i32>,
     }
 
@@ -206,7 +206,11 @@ fn items_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 4, message = "this is min custom message.")]
-        #[validate(max_items = 2, message = "this is max custom message.")]
+        #[validate(
+            max_items = 2,
+            allow_contradiction,
+            message = "this is max custom message."
+        )]
         val: Vec<i32>,
     }
 
@@ -0,0 +1,512 @@
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::{JsonSchema, Validate};
+
+#[test]
+fn json_schema_numeric_keywords() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        #[validate(maximum = 10)]
+        val1: i32,
+        #[validate(exclusive_minimum = 0.0)]
+        #[validate(exclusive_maximum = 1.0)]
+        #[validate(multiple_of = 0.25)]
+        val2: f64,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val1": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 10
+                },
+                "val2": {
+                    "type": "number",
+                    "exclusiveMinimum": 0.0,
+                    "exclusiveMaximum": 1.0,
+                    "multipleOf": 0.25
+                }
+            },
+            "required": ["val1", "val2"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_string_keywords() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        #[validate(max_length = 5)]
+        #[validate(pattern = r"^\d+$")]
+        #[validate(enumerate("1", "12", "123"))]
+        val: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {
+                    "type": "string",
+                    "minLength": 1,
+                    "maxLength": 5,
+                    "pattern": r"^\d+$",
+                    "enum": ["1", "12", "123"]
+                }
+            },
+            "required": ["val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_array_keywords_and_items() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(min_items = 1)]
+        #[validate(max_items = 3)]
+        #[validate(unique_items)]
+        #[validate(maximum = 10)]
+        val: Vec<i32>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {
+                    "type": "array",
+                    "items": {
+                        "type": "integer",
+                        "maximum": 10
+                    },
+                    "minItems": 1,
+                    "maxItems": 3,
+                    "uniqueItems": true
+                }
+            },
+            "required": ["val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_object_keywords() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        #[validate(min_properties = 1)]
+        #[validate(max_properties = 2)]
+        val: std::collections::HashMap<String, bool>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {
+                    "type": "object",
+                    "additionalProperties": { "type": "boolean" },
+                    "minProperties": 1,
+                    "maxProperties": 2
+                }
+            },
+            "required": ["val"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_serde_attributes() {
+    #[derive(Deserialize, JsonSchema)]
+    struct TestStruct {
+        #[serde(rename = "value")]
+        val: i32,
+        #[serde(default)]
+        with_default: i32,
+        #[serde(skip)]
+        skipped: i32,
+        optional: Option<String>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "value": { "type": "integer" },
+                "with_default": { "type": "integer" },
+                "optional": { "type": ["string", "null"] }
+            },
+            "required": ["value"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_serde_rename_all() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        first_name: String,
+        #[serde(rename = "last")]
+        last_name: String,
+    }
+
+    let schema = TestStruct::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "type": "object",
+            "properties": {
+                "firstName": { "type": "string" },
+                "last": { "type": "string" }
+            },
+            "required": ["firstName", "last"]
+        })
+    );
+
+    let value = serde_json::to_value(TestStruct {
+        first_name: "a".to_owned(),
+        last_name: "b".to_owned(),
+    })
+    .unwrap();
+    assert!(Schema::new(&schema).unwrap().validate(&value).is_ok());
+}
+
+#[test]
+fn json_schema_serde_rename_all_pascal_case() {
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "PascalCase")]
+    struct TestStruct {
+        first_name: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "FirstName": { "type": "string" }
+            },
+            "required": ["FirstName"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_nested_struct() {
+    #[derive(Validate, JsonSchema)]
+    struct TestStruct {
+        #[validate]
+        #[validate(max_items = 2)]
+        inner: Vec<TestInnerStruct>,
+    }
+
+    #[derive(Validate, JsonSchema)]
+    struct TestInnerStruct {
+        #[validate(minimum = 1)]
+        val: u32,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "inner": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "val": { "type": "integer", "minimum": 1 }
+                        },
+                        "required": ["val"]
+                    },
+                    "maxItems": 2
+                }
+            },
+            "required": ["inner"]
+        })
+    );
+}
+
+#[test]
+fn json_schema_unnamed_struct() {
+    #[derive(JsonSchema)]
+    struct TestNewType(#[validate(max_length = 3)] String);
+
+    #[derive(JsonSchema)]
+    struct TestTupleStruct(#[validate(minimum = 0)] i32, String);
+
+    assert_eq!(
+        TestNewType::json_schema(),
+        json!({ "type": "string", "maxLength": 3 })
+    );
+    assert_eq!(
+        TestTupleStruct::json_schema(),
+        json!({
+            "type": "array",
+            "prefixItems": [
+                { "type": "integer", "minimum": 0 },
+                { "type": "string" }
+            ],
+            "items": false,
+            "minItems": 2,
+            "maxItems": 2
+        })
+    );
+}
+
+#[test]
+fn json_schema_enum() {
+    #[derive(Deserialize, JsonSchema)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+        NewType(#[validate(min_length = 1)] String),
+        #[serde(rename = "unit")]
+        Unit,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Named": {
+                            "type": "object",
+                            "properties": {
+                                "val": { "type": "integer", "maximum": 10 }
+                            },
+                            "required": ["val"]
+                        }
+                    },
+                    "required": ["Named"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "NewType": { "type": "string", "minLength": 1 }
+                    },
+                    "required": ["NewType"],
+                    "additionalProperties": false
+                },
+                { "const": "unit" }
+            ]
+        })
+    );
+}
+
+#[test]
+fn json_schema_unit_variants_enum() {
+    #[derive(JsonSchema)]
+    enum TestEnum {
+        A,
+        B,
+    }
+
+    assert_eq!(TestEnum::json_schema(), json!({ "enum": ["A", "B"] }));
+}
+
+#[test]
+fn json_schema_internally_tagged_enum() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum TestEnum {
+        NamedVariant {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+        UnitVariant,
+    }
+
+    let schema = TestEnum::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "oneOf": [
+                {
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": { "type": { "const": "named_variant" } },
+                            "required": ["type"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "val": { "type": "integer", "maximum": 10 }
+                            },
+                            "required": ["val"]
+                        }
+                    ]
+                },
+                {
+                    "type": "object",
+                    "properties": { "type": { "const": "unit_variant" } },
+                    "required": ["type"]
+                }
+            ]
+        })
+    );
+
+    let schema = Schema::new(&schema).unwrap();
+    for value in [TestEnum::NamedVariant { val: 1 }, TestEnum::UnitVariant] {
+        let value = serde_json::to_value(value).unwrap();
+        assert!(schema.validate(&value).is_ok());
+    }
+    assert!(schema
+        .validate(&json!({ "NamedVariant": { "val": 1 } }))
+        .is_err());
+}
+
+#[test]
+fn json_schema_adjacently_tagged_enum() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(tag = "t", content = "c", rename_all_fields = "camelCase")]
+    enum TestEnum {
+        Named { inner_val: i32 },
+        NewType(String),
+        Unit,
+    }
+
+    let schema = TestEnum::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "t": { "const": "Named" },
+                        "c": {
+                            "type": "object",
+                            "properties": {
+                                "innerVal": { "type": "integer" }
+                            },
+                            "required": ["innerVal"]
+                        }
+                    },
+                    "required": ["t", "c"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "t": { "const": "NewType" },
+                        "c": { "type": "string" }
+                    },
+                    "required": ["t", "c"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "properties": { "t": { "const": "Unit" } },
+                    "required": ["t"]
+                }
+            ]
+        })
+    );
+
+    let schema = Schema::new(&schema).unwrap();
+    for value in [
+        TestEnum::Named { inner_val: 1 },
+        TestEnum::NewType("a".to_owned()),
+        TestEnum::Unit,
+    ] {
+        let value = serde_json::to_value(value).unwrap();
+        assert!(schema.validate(&value).is_ok());
+    }
+}
+
+#[test]
+fn json_schema_untagged_enum() {
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    #[serde(untagged)]
+    enum TestEnum {
+        Named { val: i32 },
+        NewType(String),
+        Unit,
+    }
+
+    let schema = TestEnum::json_schema();
+    assert_eq!(
+        schema,
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "val": { "type": "integer" }
+                    },
+                    "required": ["val"]
+                },
+                { "type": "string" },
+                { "type": "null" }
+            ]
+        })
+    );
+
+    let schema = Schema::new(&schema).unwrap();
+    for value in [
+        TestEnum::Named { val: 1 },
+        TestEnum::NewType("a".to_owned()),
+        TestEnum::Unit,
+    ] {
+        let value = serde_json::to_value(value).unwrap();
+        assert!(schema.validate(&value).is_ok());
+    }
+}
+
+#[test]
+fn json_schema_unit_variants_enum_rename_all() {
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+    enum TestEnum {
+        FirstValue,
+        #[serde(rename = "second")]
+        SecondValue,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({ "enum": ["FIRST-VALUE", "second"] })
+    );
+}
+
+#[test]
+fn json_schema_document() {
+    #[derive(JsonSchema)]
+    struct TestStruct {
+        val: bool,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema_document(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "val": { "type": "boolean" }
+            },
+            "required": ["val"]
+        })
+    );
+}
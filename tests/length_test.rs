@@ -282,7 +282,11 @@ fn length_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message_fn(custom_min_error_message))]
-        #[validate(max_length = 3, message_fn(custom_max_error_message))]
+        #[validate(
+            max_length = 3,
+            allow_contradiction,
+            message_fn(custom_max_error_message)
+        )]
         val: String,
     }
 
@@ -312,7 +316,11 @@ fn length_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message = "this is min custom message.")]
-        #[validate(max_length = 3, message = "this is max custom message.")]
+        #[validate(
+            max_length = 3,
+            allow_contradiction,
+            message = "this is max custom message."
+        )]
         val: String,
     }
 
@@ -342,7 +350,11 @@ fn length_vec_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message = "this is min custom message.")]
-        #[validate(max_length = 3, message = "this is max custom message.")]
+        #[validate(
+            max_length = 3,
+            allow_contradiction,
+            message = "this is max custom message."
+        )]
         val: Vec<String>,
     }
 
@@ -0,0 +1,245 @@
+use std::collections::{BTreeMap, HashMap};
+
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::{JsonSchema, Validate};
+
+#[derive(Debug, Serialize, Validate, JsonSchema)]
+struct Item {
+    #[validate(minimum = 0)]
+    count: i32,
+}
+
+#[test]
+fn hash_map_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        items: HashMap<String, Item>,
+    }
+
+    let s = TestStruct {
+        items: HashMap::from([("a".to_owned(), Item { count: 1 })]),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn hash_map_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        items: HashMap<String, Item>,
+    }
+
+    let s = TestStruct {
+        items: HashMap::from([
+            ("a".to_owned(), Item { count: 1 }),
+            ("b".to_owned(), Item { count: -1 }),
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "items": {
+                    "errors": [],
+                    "properties": {
+                        "b": {
+                            "errors": [],
+                            "properties": {
+                                "count": {
+                                    "errors": ["the number must be `>= 0`."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn btree_map_is_err_in_key_order() {
+    let map = BTreeMap::from([
+        (2, Item { count: -2 }),
+        (1, Item { count: -1 }),
+        (3, Item { count: 3 }),
+    ]);
+
+    let errors = map.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter_flat()
+            .map(|(pointer, _)| pointer.to_string())
+            .collect::<Vec<_>>(),
+        vec!["/1/count", "/2/count"]
+    );
+}
+
+#[test]
+fn index_map_option_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        items: Option<IndexMap<String, Item>>,
+    }
+
+    assert!(TestStruct { items: None }.validate().is_ok());
+
+    let s = TestStruct {
+        items: Some(IndexMap::from([("a/b".to_owned(), Item { count: -1 })])),
+    };
+    let errors = s.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter_flat()
+            .map(|(pointer, _)| pointer.to_string())
+            .collect::<Vec<_>>(),
+        vec!["/items/a~1b/count"]
+    );
+}
+
+#[test]
+fn keys_and_values_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(keys(pattern = r"^[a-z]+$"), values(minimum = 0))]
+        scores: HashMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        scores: HashMap::from([("alice".to_owned(), 1), ("bob".to_owned(), 0)]),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn keys_and_values_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(keys(pattern = r"^[a-z]+$", max_length = 5), values(minimum = 0))]
+        scores: IndexMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        scores: IndexMap::from([
+            ("alice".to_owned(), -1),
+            ("Bob".to_owned(), 1),
+            ("charlie".to_owned(), -1),
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "scores": {
+                    "errors": [],
+                    "properties": {
+                        "alice": {
+                            "errors": ["the number must be `>= 0`."]
+                        },
+                        "Bob": {
+                            "errors": [r#"the value must match the pattern of "^[a-z]+$"."#]
+                        },
+                        "charlie": {
+                            "errors": [
+                                "the length of the value must be `<= 5`.",
+                                "the number must be `>= 0`."
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn keys_with_message_and_option_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(keys(min_length = 2), message = "key is too short.")]
+        names: Option<BTreeMap<String, String>>,
+    }
+
+    assert!(TestStruct { names: None }.validate().is_ok());
+
+    let s = TestStruct {
+        names: Some(BTreeMap::from([("a".to_owned(), "b".to_owned())])),
+    };
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "names": {
+                    "errors": [],
+                    "properties": {
+                        "a": {
+                            "errors": ["key is too short."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[derive(Debug, Serialize, Validate, JsonSchema)]
+struct TestSchemaStruct {
+    #[validate(keys(pattern = r"^[a-z]+$"), values(minimum = 0, maximum = 100))]
+    scores: IndexMap<String, i32>,
+    #[validate]
+    items: BTreeMap<String, Item>,
+}
+
+#[test]
+fn keys_and_values_json_schema() {
+    assert_eq!(
+        TestSchemaStruct::json_schema()["properties"],
+        json!({
+            "scores": {
+                "type": "object",
+                "additionalProperties": { "type": "integer", "minimum": 0, "maximum": 100 },
+                "propertyNames": { "pattern": r"^[a-z]+$" }
+            },
+            "items": {
+                "type": "object",
+                "additionalProperties": Item::json_schema()
+            }
+        })
+    );
+}
+
+#[test]
+fn keys_and_values_schema_same_errors_as_derive() {
+    let schema = Schema::new(&TestSchemaStruct::json_schema()).unwrap();
+
+    let s = TestSchemaStruct {
+        scores: IndexMap::from([
+            ("alice".to_owned(), 101),
+            ("Bob".to_owned(), 1),
+            ("Carol".to_owned(), -1),
+        ]),
+        items: BTreeMap::from([("a".to_owned(), Item { count: -1 })]),
+    };
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&serde_json::to_value(&s).unwrap())
+                .unwrap_err()
+        )
+        .unwrap(),
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+    );
+}
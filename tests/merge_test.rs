@@ -0,0 +1,162 @@
+use serde_json::json;
+use serde_valid::validation::{Error, Errors, MergeError, ObjectErrors};
+use serde_valid::Validate;
+
+#[test]
+fn merge_object_errors() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        #[validate]
+        inner: TestInnerStruct,
+    }
+
+    #[derive(Validate)]
+    struct TestInnerStruct {
+        #[validate(min_length = 4)]
+        name: String,
+    }
+
+    fn business_rule(_s: &TestStruct) -> Result<(), Errors> {
+        let mut inner = indexmap::IndexMap::new();
+        inner.insert(
+            "name".into(),
+            Errors::NewType(vec![Error::Custom("name is already used.".to_owned())]),
+        );
+        let mut properties = indexmap::IndexMap::new();
+        properties.insert(
+            "inner".into(),
+            Errors::Object(ObjectErrors::new(vec![], inner)),
+        );
+        properties.insert(
+            "other".into(),
+            Errors::NewType(vec![Error::Custom("other error.".to_owned())]),
+        );
+        Err(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("struct error.".to_owned())],
+            properties,
+        )))
+    }
+
+    let s = TestStruct {
+        val: 11,
+        inner: TestInnerStruct {
+            name: "abc".to_owned(),
+        },
+    };
+
+    let mut errors = s.validate().unwrap_err();
+    errors.merge(business_rule(&s).unwrap_err()).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": ["struct error."],
+            "properties": {
+                "val": {
+                    "errors": ["the number must be `<= 10`."]
+                },
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": [
+                                "the length of the value must be `>= 4`.",
+                                "name is already used."
+                            ]
+                        }
+                    }
+                },
+                "other": {
+                    "errors": ["other error."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn merge_new_type_into_object_errors() {
+    let mut errors = Errors::NewType(vec![Error::Custom("error1.".to_owned())]);
+    errors
+        .merge(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("error2.".to_owned())],
+            indexmap::IndexMap::new(),
+        )))
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": ["error1.", "error2."],
+            "properties": {}
+        })
+    );
+}
+
+#[test]
+fn merge_conflict_errors() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        val: Vec<TestInnerStruct>,
+    }
+
+    #[derive(Validate)]
+    struct TestInnerStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let s = TestStruct {
+        val: vec![TestInnerStruct { val: 11 }],
+    };
+
+    let mut properties = indexmap::IndexMap::new();
+    properties.insert(
+        "val".into(),
+        Errors::Object(ObjectErrors::new(vec![], indexmap::IndexMap::new())),
+    );
+    properties.insert(
+        "other".into(),
+        Errors::NewType(vec![Error::Custom("other error.".to_owned())]),
+    );
+
+    let mut errors = s.validate().unwrap_err();
+    assert_eq!(
+        errors.merge(Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("struct error.".to_owned())],
+            properties
+        ))),
+        Err(MergeError {
+            instance_location: "/val".to_owned()
+        })
+    );
+
+    // The errors before and after the conflict are kept.
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": ["struct error."],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["the number must be `<= 10`."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "other": {
+                    "errors": ["other error."]
+                }
+            }
+        })
+    );
+}
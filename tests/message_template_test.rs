@@ -0,0 +1,253 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn message_template_with_params() {
+    #[derive(Deserialize, Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10, message = "must be at most {maximum}.")]
+        val: i32,
+        #[validate(max_length = 4, message = "{field} is too long (max {max_length}).")]
+        #[serde(rename = "userName")]
+        name: String,
+        #[validate(enumerate(1, 2, 3), message = "{field} must be one of [{enumerate}].")]
+        level: i32,
+        #[validate(pattern = r"^\d+$", message = "{ field } must match {pattern}.")]
+        code: String,
+    }
+
+    let s = TestStruct {
+        val: 11,
+        name: "abcde".to_owned(),
+        level: 4,
+        code: "abc".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["must be at most 10."]
+                },
+                "userName": {
+                    "errors": ["userName is too long (max 4)."]
+                },
+                "level": {
+                    "errors": ["level must be one of [1, 2, 3]."]
+                },
+                "code": {
+                    "errors": ["code must match ^\\d+$."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_escape_braces() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = 1, message = "{{field}} must not be empty.")]
+        val: String,
+        #[validate(min_items = 2, message = "{{{min_items}}} items are required.")]
+        items: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: "".to_owned(),
+        items: vec![],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["{field} must not be empty."]
+                },
+                "items": {
+                    "errors": ["{2} items are required."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_field_only_for_compositions() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            any_of(minimum = 10, maximum = 0),
+            message = "{field} is out of range."
+        )]
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct { val: 5 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["val is out of range."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn message_template_of_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            max_length = 2,
+            message = "{field} items must be at most {max_length} long."
+        )]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec!["a".to_owned(), "abc".to_owned()],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["val items must be at most 2 long."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_field_is_error_key() {
+    #[derive(Deserialize, Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 10, message = "{field} is too large.")]
+            #[serde(rename = "maxValue")]
+            max_value: i32,
+            #[validate(max_length = 2, message = "{field} has a long item.")]
+            #[serde(rename = "itemNames")]
+            item_names: Vec<String>,
+        },
+    }
+
+    let s = TestEnum::Named {
+        max_value: 11,
+        item_names: vec!["abc".to_owned()],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "maxValue": {
+                    "errors": ["maxValue is too large."]
+                },
+                "itemNames": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["itemNames has a long item."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_of_contains() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            contains(minimum = 10),
+            min_contains = 2,
+            message = "{field} needs {min_contains} large values."
+        )]
+        min_val: Vec<i32>,
+        #[validate(
+            contains(minimum = 10),
+            max_contains = 1,
+            message = "{field} allows {max_contains} large value."
+        )]
+        max_val: Vec<i32>,
+        #[validate(
+            contains(minimum = 10),
+            min_contains = 2,
+            max_contains = 3,
+            message = "{field} needs {min_contains} to {max_contains} large values."
+        )]
+        range_val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        min_val: vec![10],
+        max_val: vec![10, 11],
+        range_val: vec![10, 11, 12, 13],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "min_val": {
+                    "errors": ["min_val needs 2 large values."]
+                },
+                "max_val": {
+                    "errors": ["max_val allows 1 large value."]
+                },
+                "range_val": {
+                    "errors": ["range_val needs 2 to 3 large values."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_has_all_params_fields() {
+    let template = include_str!("../derive/src/validate/common/message_template.rs");
+    let params_sources = [
+        include_str!("../src/error/params.rs"),
+        include_str!("../src/error/generic/enumerate.rs"),
+        include_str!("../src/error/generic/const_value.rs"),
+        include_str!("../src/error/generic/eq_field.rs"),
+    ];
+
+    let params_fields = params_sources
+        .iter()
+        .flat_map(|source| source.lines())
+        .filter_map(|line| line.trim().strip_prefix("pub ")?.split_once(':'))
+        .map(|(name, _)| name)
+        .filter(|name| name.chars().all(|c| c.is_ascii_lowercase() || c == '_'))
+        .collect::<Vec<_>>();
+
+    assert!(params_fields.contains(&"min_contains"));
+    for name in params_fields {
+        assert!(
+            template.contains(&format!("&[\"{name}\"]")),
+            "`{{{name}}}` is not a placeholder of the message template."
+        );
+    }
+}
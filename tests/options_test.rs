@@ -0,0 +1,271 @@
+use serde_json::json;
+use serde_valid::validation::{Error, Group, ValidateOptions};
+use serde_valid::Validate;
+use std::cell::Cell;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(minimum = 0)]
+    #[validate(maximum = 10)]
+    val1: i32,
+    #[validate(min_length = 4)]
+    #[validate(pattern = r"^\d+$")]
+    val2: String,
+    #[validate]
+    items: Vec<TestItem>,
+}
+
+#[derive(Validate)]
+struct TestItem {
+    #[validate(minimum = 0)]
+    val: i32,
+}
+
+fn invalid_struct() -> TestStruct {
+    TestStruct {
+        val1: -1,
+        val2: "abc".to_owned(),
+        items: vec![TestItem { val: -1 }, TestItem { val: -2 }],
+    }
+}
+
+#[test]
+fn validate_with_default_options_is_same_as_validate() {
+    let s = invalid_struct();
+
+    assert_eq!(s.validate().unwrap_err().count(), 5);
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        serde_json::to_value(
+            s.validate_with_options(ValidateOptions::default())
+                .unwrap_err()
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn validate_with_fail_fast() {
+    let s = invalid_struct();
+
+    assert_eq!(
+        serde_json::to_value(
+            s.validate_with_options(ValidateOptions::fail_fast())
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["the number must be `>= 0`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn validate_with_max_errors() {
+    let s = invalid_struct();
+
+    assert_eq!(
+        serde_json::to_value(
+            s.validate_with_options(ValidateOptions::max_errors(3))
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["the number must be `>= 0`."]
+                },
+                "val2": {
+                    "errors": [
+                        "the length of the value must be `>= 4`.",
+                        "the value must match the pattern of \"^\\d+$\"."
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn validate_with_max_errors_limits_nested_items() {
+    let s = TestStruct {
+        val1: 0,
+        val2: "1234".to_owned(),
+        items: vec![
+            TestItem { val: -1 },
+            TestItem { val: -2 },
+            TestItem { val: -3 },
+        ],
+    };
+
+    assert_eq!(
+        serde_json::to_value(
+            s.validate_with_options(ValidateOptions::max_errors(2))
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["the number must be `>= 0`."]
+                                }
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["the number must be `>= 0`."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn is_valid() {
+    assert!(!invalid_struct().is_valid());
+    assert!(TestStruct {
+        val1: 0,
+        val2: "1234".to_owned(),
+        items: vec![TestItem { val: 1 }],
+    }
+    .is_valid());
+}
+
+thread_local! {
+    static CALLED: Cell<usize> = Cell::new(0);
+}
+
+fn count_called(_: &i32) -> Result<(), Error> {
+    CALLED.with(|called| called.set(called.get() + 1));
+    Err(Error::Custom("called.".to_owned()))
+}
+
+#[derive(Validate)]
+struct TestCalled {
+    #[validate(custom(count_called))]
+    val: i32,
+}
+
+#[test]
+fn is_valid_vec_stops_at_first_error() {
+    let s = vec![
+        TestCalled { val: 1 },
+        TestCalled { val: 2 },
+        TestCalled { val: 3 },
+    ];
+
+    CALLED.with(|called| called.set(0));
+    assert!(!s.is_valid());
+    assert_eq!(CALLED.with(|called| called.get()), 1);
+
+    CALLED.with(|called| called.set(0));
+    assert_eq!(s.validate().unwrap_err().count(), 3);
+    assert_eq!(CALLED.with(|called| called.get()), 3);
+}
+
+#[test]
+fn is_valid_does_not_create_errors() {
+    use serde_valid::validation::{PropertiesErrors, Validity};
+
+    let mut validity = Validity::default();
+    assert!(validity.is_valid());
+
+    validity.push_error("val", || unreachable!("the error is not created."));
+    assert!(!validity.is_valid());
+}
+
+#[test]
+fn is_valid_nested_enum() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+            #[validate]
+            item: Box<TestCalled>,
+        },
+        NewType(#[validate] Vec<TestCalled>),
+    }
+
+    let s = TestEnum::Named {
+        val: 11,
+        item: Box::new(TestCalled { val: 1 }),
+    };
+    CALLED.with(|called| called.set(0));
+    assert!(!s.is_valid());
+    assert_eq!(CALLED.with(|called| called.get()), 0);
+
+    let s = TestEnum::NewType(vec![TestCalled { val: 1 }, TestCalled { val: 2 }]);
+    CALLED.with(|called| called.set(0));
+    assert!(!s.is_valid());
+    assert_eq!(CALLED.with(|called| called.get()), 1);
+    assert_eq!(s.validate().unwrap_err().count(), 2);
+}
+
+#[test]
+fn validate_array_and_option_with_max_errors() {
+    let s = [
+        TestItem { val: -1 },
+        TestItem { val: -2 },
+        TestItem { val: -3 },
+    ];
+    assert_eq!(
+        s.validate_with_options(ValidateOptions::max_errors(2))
+            .unwrap_err()
+            .count(),
+        2
+    );
+
+    let s = Some(invalid_struct());
+    assert_eq!(
+        s.validate_with_options(ValidateOptions::max_errors(2))
+            .unwrap_err()
+            .count(),
+        2
+    );
+    assert!(None::<TestStruct>.is_valid());
+}
+
+#[test]
+fn validate_group_with_options() {
+    #[derive(Validate)]
+    struct TestGroup {
+        #[validate(minimum = 0, groups(update))]
+        #[validate(maximum = 10, groups(update))]
+        val: i32,
+        #[validate(min_length = 4, groups(update))]
+        name: String,
+    }
+
+    let s = TestGroup {
+        val: -1,
+        name: "abc".to_owned(),
+    };
+
+    assert!(s.is_valid());
+    assert_eq!(
+        s.validate_group_with_options(Group::new("update"), ValidateOptions::fail_fast())
+            .unwrap_err()
+            .count(),
+        1
+    );
+}
@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::{JsonSchema, Validate};
+
+fn check_ext(value: &serde_json::Value) -> Result<(), serde_valid::validation::Error> {
+    if value.is_string() {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "the extension must be a string.".to_owned(),
+        ))
+    }
+}
+
+#[test]
+fn pattern_properties_custom_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern_properties("^x-" = custom(check_ext)))]
+        section: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let s = TestStruct {
+        section: json!({ "x-name": "ext", "name": 1 })
+            .as_object()
+            .unwrap()
+            .clone(),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn pattern_properties_custom_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern_properties("^x-" = custom(check_ext)))]
+        section: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let s = TestStruct {
+        section: json!({ "x-name": 1, "name": 1 })
+            .as_object()
+            .unwrap()
+            .clone(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "section": {
+                    "errors": [],
+                    "properties": {
+                        "x-name": {
+                            "errors": ["the extension must be a string."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn pattern_properties_multiple_patterns_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern_properties("^min_" = minimum = 0, "^max_" = maximum = 10))]
+        limits: HashMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        limits: HashMap::from([
+            ("min_a".to_owned(), -1),
+            ("max_a".to_owned(), 11),
+            ("other".to_owned(), 100),
+        ]),
+    };
+
+    let mut pointers = s
+        .validate()
+        .unwrap_err()
+        .iter_flat()
+        .map(|(pointer, error)| (pointer.to_string(), error.to_string()))
+        .collect::<Vec<_>>();
+    pointers.sort();
+    assert_eq!(
+        pointers,
+        vec![
+            (
+                "/limits/max_a".to_owned(),
+                "the number must be `<= 10`.".to_owned()
+            ),
+            (
+                "/limits/min_a".to_owned(),
+                "the number must be `>= 0`.".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn additional_properties_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(additional_properties = false, allowed_keys("a", "b"))]
+        section: HashMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        section: HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn additional_properties_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(additional_properties = false, allowed_keys("a", "b"))]
+        section: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let s = TestStruct {
+        section: json!({ "a": 1, "c": 3 }).as_object().unwrap().clone(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "section": {
+                    "errors": [],
+                    "properties": {
+                        "c": {
+                            "errors": ["the value is not allowed."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn additional_properties_allows_pattern_properties() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern_properties("^x-" = max_length = 3))]
+        #[validate(
+            additional_properties = false,
+            allowed_keys("name"),
+            message = "unknown key."
+        )]
+        section: HashMap<String, String>,
+    }
+
+    let s = TestStruct {
+        section: HashMap::from([
+            ("name".to_owned(), "serde_valid".to_owned()),
+            ("x-ext".to_owned(), "ext".to_owned()),
+        ]),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        section: HashMap::from([
+            ("x-ext".to_owned(), "long".to_owned()),
+            ("nick".to_owned(), "sv".to_owned()),
+        ]),
+    };
+    let mut pointers = s
+        .validate()
+        .unwrap_err()
+        .iter_flat()
+        .map(|(pointer, error)| (pointer.to_string(), error.to_string()))
+        .collect::<Vec<_>>();
+    pointers.sort();
+    assert_eq!(
+        pointers,
+        vec![
+            ("/section/nick".to_owned(), "unknown key.".to_owned()),
+            (
+                "/section/x-ext".to_owned(),
+                "the length of the value must be `<= 3`.".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn additional_properties_without_allowed_keys_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(additional_properties = false)]
+        section: Option<HashMap<String, i32>>,
+    }
+
+    assert!(TestStruct { section: None }.validate().is_ok());
+    assert!(TestStruct {
+        section: Some(HashMap::new())
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        section: Some(HashMap::from([("a".to_owned(), 1)]))
+    }
+    .validate()
+    .is_err());
+}
+
+#[derive(Debug, Serialize, Validate, JsonSchema)]
+struct TestSchemaStruct {
+    #[validate(pattern_properties("^x-" = max_length = 3))]
+    #[validate(additional_properties = false, allowed_keys("name"))]
+    section: HashMap<String, String>,
+}
+
+#[test]
+fn pattern_properties_json_schema() {
+    assert_eq!(
+        TestSchemaStruct::json_schema()["properties"],
+        json!({
+            "section": {
+                "type": "object",
+                "patternProperties": {
+                    "^x-": { "type": "string", "maxLength": 3 }
+                },
+                "properties": {
+                    "name": { "type": "string" }
+                },
+                "additionalProperties": false
+            }
+        })
+    );
+}
+
+#[test]
+fn pattern_properties_schema_same_errors_as_derive() {
+    let schema = Schema::new(&TestSchemaStruct::json_schema()).unwrap();
+
+    let s = TestSchemaStruct {
+        section: HashMap::from([("x-ext".to_owned(), "long".to_owned())]),
+    };
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&serde_json::to_value(&s).unwrap())
+                .unwrap_err()
+        )
+        .unwrap(),
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+    );
+
+    let s = TestSchemaStruct {
+        section: HashMap::from([("nick".to_owned(), "sv".to_owned())]),
+    };
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&serde_json::to_value(&s).unwrap())
+                .unwrap_err()
+        )
+        .unwrap(),
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+    );
+}
@@ -0,0 +1,170 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::Schema;
+use serde_valid::{JsonSchema, Validate};
+
+#[test]
+fn prefix_items_tuple_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(prefix_items(0(max_length = 5), 1(minimum = 1)))]
+        val: (String, u32),
+    }
+
+    let s = TestStruct {
+        val: ("abc".to_owned(), 1),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn prefix_items_tuple_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(prefix_items(0(max_length = 5), 1(minimum = 1)))]
+        val: (String, u32),
+    }
+
+    let s = TestStruct {
+        val: ("abcdef".to_owned(), 0),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["the length of the value must be `<= 5`."]
+                        },
+                        "1": {
+                            "errors": ["the number must be `>= 1`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn prefix_items_multiple_validations_is_err() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(prefix_items(1(minimum = 1, maximum = 2)), message = "out of range.")]
+        Option<(String, i32, bool)>,
+    );
+
+    assert!(TestStruct(None).validate().is_ok());
+    assert!(TestStruct(Some(("a".to_owned(), 2, true)))
+        .validate()
+        .is_ok());
+
+    let errors = TestStruct(Some(("a".to_owned(), 3, true)))
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        errors
+            .iter_flat()
+            .map(|(pointer, error)| (pointer, error.to_string()))
+            .collect::<Vec<_>>(),
+        vec![("/1".to_owned(), "out of range.".to_owned())]
+    );
+}
+
+#[test]
+fn prefix_items_vec_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(prefix_items(0(enumerate("GET", "POST")), 2(pattern = "^/")))]
+        #[validate(min_items = 1)]
+        val: Vec<String>,
+    }
+
+    assert!(TestStruct {
+        val: vec!["GET".to_owned()]
+    }
+    .validate()
+    .is_ok());
+
+    let s = TestStruct {
+        val: vec!["PUT".to_owned(), "a".to_owned(), "b".to_owned()],
+    };
+    assert_eq!(
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [r#"the value must be in [GET, POST]."#]
+                        },
+                        "2": {
+                            "errors": [r#"the value must match the pattern of "^/"."#]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[derive(Debug, Serialize, Validate, JsonSchema)]
+struct TestSchemaStruct {
+    #[validate(prefix_items(0(max_length = 5), 1(minimum = 1)))]
+    pair: (String, u32),
+    #[validate(prefix_items(1(maximum = 10)))]
+    vals: Vec<i32>,
+}
+
+#[test]
+fn prefix_items_json_schema() {
+    assert_eq!(
+        TestSchemaStruct::json_schema()["properties"],
+        json!({
+            "pair": {
+                "type": "array",
+                "prefixItems": [
+                    { "type": "string", "maxLength": 5 },
+                    { "type": "integer", "minimum": 1 }
+                ],
+                "items": false,
+                "minItems": 2,
+                "maxItems": 2
+            },
+            "vals": {
+                "type": "array",
+                "items": { "type": "integer" },
+                "prefixItems": [
+                    { "type": "integer" },
+                    { "type": "integer", "maximum": 10 }
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+fn prefix_items_schema_same_errors_as_derive() {
+    let schema = Schema::new(&TestSchemaStruct::json_schema()).unwrap();
+
+    let s = TestSchemaStruct {
+        pair: ("abcdef".to_owned(), 0),
+        vals: vec![100, 100],
+    };
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&serde_json::to_value(&s).unwrap())
+                .unwrap_err()
+        )
+        .unwrap(),
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+    );
+}
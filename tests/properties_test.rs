@@ -184,7 +184,11 @@ fn properties_custom_err_message_fn() {
     #[derive(Deserialize, Validate)]
     struct TestStruct {
         #[validate(min_properties = 3, message_fn(min_custom_error_message))]
-        #[validate(max_properties = 1, message_fn(max_custom_error_message))]
+        #[validate(
+            max_properties = 1,
+            allow_contradiction,
+            message_fn(max_custom_error_message)
+        )]
         val: serde_json::Map<String, serde_json::Value>,
     }
 
@@ -218,7 +222,11 @@ fn properties_custom_err_message() {
     #[derive(Deserialize, Validate)]
     struct TestStruct {
         #[validate(min_properties = 3, message = "this is min custom message.")]
-        #[validate(max_properties = 1, message = "this is max custom message.")]
+        #[validate(
+            max_properties = 1,
+            allow_contradiction,
+            message = "this is max custom message."
+        )]
         val: serde_json::Map<String, serde_json::Value>,
     }
 
@@ -297,7 +297,7 @@ fn range_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(minimum = 5, message_fn(custom_min_error_message))]
-        #[validate(maximum = 3, message_fn(custom_max_error_message))]
+        #[validate(maximum = 3, allow_contradiction, message_fn(custom_max_error_message))]
         val: i32,
     }
 
@@ -325,7 +325,11 @@ fn range_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(minimum = 5, message = "this is min custom message.")]
-        #[validate(maximum = 3, message = "this is max custom message.")]
+        #[validate(
+            maximum = 3,
+            allow_contradiction,
+            message = "this is max custom message."
+        )]
         val: i32,
     }
 
@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use serde_json::json;
 use serde_valid::Validate;
 
@@ -112,3 +113,151 @@ fn rule_enum_is_ok() {
     let s4 = TestEnum::NoField;
     assert!(s4.validate().is_ok());
 }
+
+fn sample_range_rule(start: &i32, end: &i32) -> Result<(), serde_valid::validation::Error> {
+    if start <= end {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "`end` must be greater than or equal to `start`.".to_owned(),
+        ))
+    }
+}
+
+#[test]
+fn rule_struct_self_is_err() {
+    fn check_struct(s: &TestStruct) -> Result<(), serde_valid::validation::Error> {
+        sample_range_rule(&s.start, &s.end)
+    }
+
+    #[derive(Validate)]
+    #[rule(check_struct(self))]
+    struct TestStruct {
+        start: i32,
+        end: i32,
+    }
+
+    assert!(TestStruct { start: 1, end: 2 }.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(TestStruct { start: 2, end: 1 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": ["`end` must be greater than or equal to `start`."],
+            "properties": {}
+        })
+    );
+}
+
+#[test]
+fn rule_struct_named_fields_path_is_err() {
+    #[derive(Deserialize, Validate)]
+    #[rule(sample_range_rule(start, end), path = "end")]
+    struct TestStruct {
+        start: i32,
+        #[serde(rename = "endAt")]
+        end: i32,
+    }
+
+    let s = TestStruct { start: 2, end: 1 };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "endAt": {
+                    "errors": ["`end` must be greater than or equal to `start`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn rule_struct_unnamed_fields_path_is_err() {
+    #[derive(Validate)]
+    #[rule(sample_range_rule(0, 1), path = "1")]
+    struct TestStruct(i32, i32);
+
+    assert_eq!(
+        serde_json::to_value(TestStruct(2, 1).validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "1": {
+                    "errors": ["`end` must be greater than or equal to `start`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn rule_enum_self_is_err() {
+    fn check_enum(value: &TestEnum) -> Result<(), serde_valid::validation::Error> {
+        match value {
+            TestEnum::Range { start, end } => sample_range_rule(start, end),
+            TestEnum::Single(value) => sample_range_rule(&0, value),
+            TestEnum::Empty => Ok(()),
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(check_enum(self))]
+    enum TestEnum {
+        Range {
+            #[validate(minimum = 0)]
+            start: i32,
+            end: i32,
+        },
+        Single(i32),
+        Empty,
+    }
+
+    assert!(TestEnum::Range { start: 1, end: 2 }.validate().is_ok());
+    assert!(TestEnum::Single(1).validate().is_ok());
+    assert!(TestEnum::Empty.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(TestEnum::Range { start: 2, end: 1 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": ["`end` must be greater than or equal to `start`."],
+            "properties": {}
+        })
+    );
+    // The rule on the enum is checked after the variant is valid.
+    assert_eq!(
+        serde_json::to_value(
+            TestEnum::Range { start: -1, end: -2 }
+                .validate()
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "start": {
+                    "errors": ["the number must be `>= 0`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn rule_enum_variant_path_is_err() {
+    #[derive(Validate)]
+    enum TestEnum {
+        #[rule(sample_range_rule(start, end), path = "end")]
+        Range { start: i32, end: i32 },
+    }
+
+    assert_eq!(
+        serde_json::to_value(TestEnum::Range { start: 2, end: 1 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {
+                    "errors": ["`end` must be greater than or equal to `start`."]
+                }
+            }
+        })
+    );
+}
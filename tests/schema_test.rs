@@ -0,0 +1,182 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::json::{Schema, SchemaError};
+use serde_valid::{JsonSchema, Validate};
+
+#[test]
+fn schema_is_ok() {
+    let schema = Schema::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 1, "pattern": "^[a-z]+$" },
+            "tags": {
+                "type": "array",
+                "items": { "type": "string", "enum": ["a", "b"] },
+                "uniqueItems": true
+            },
+            "age": { "type": ["integer", "null"], "minimum": 0 }
+        },
+        "required": ["name"],
+        "additionalProperties": false
+    }))
+    .unwrap();
+
+    assert!(schema
+        .validate(&json!({ "name": "serde", "tags": ["a", "b"], "age": null }))
+        .is_ok());
+}
+
+#[test]
+fn schema_err_message() {
+    let schema = Schema::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "maxLength": 3 },
+            "scores": {
+                "type": "array",
+                "maxItems": 2,
+                "items": { "maximum": 10 }
+            },
+            "child": {
+                "type": "object",
+                "properties": {
+                    "flag": { "type": "boolean" }
+                }
+            }
+        },
+        "required": ["id", "name"],
+        "additionalProperties": false,
+        "maxProperties": 3
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&json!({
+                    "name": "serde",
+                    "scores": [1, 11, 12],
+                    "child": { "flag": 1 },
+                    "extra": true
+                }))
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": ["the size of the properties must be `<= 3`."],
+            "properties": {
+                "id": {
+                    "errors": ["the property is required."]
+                },
+                "name": {
+                    "errors": ["the length of the value must be `<= 3`."]
+                },
+                "scores": {
+                    "errors": ["the length of the items must be `<= 2`."],
+                    "items": {
+                        "1": {
+                            "errors": ["the number must be `<= 10`."]
+                        },
+                        "2": {
+                            "errors": ["the number must be `<= 10`."]
+                        }
+                    }
+                },
+                "child": {
+                    "errors": [],
+                    "properties": {
+                        "flag": {
+                            "errors": ["the type of the value must be `boolean`."]
+                        }
+                    }
+                },
+                "extra": {
+                    "errors": ["the value is not allowed."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_enum_err_message() {
+    let schema = Schema::new(&json!({ "enum": [1, "a", null, [true]] })).unwrap();
+
+    assert_eq!(
+        serde_json::to_string(&schema.validate(&json!(2)).unwrap_err()).unwrap(),
+        serde_json::to_string(&json!({
+            "errors": ["the value must be in [1, a, null, [true]]."]
+        }))
+        .unwrap()
+    );
+}
+
+#[test]
+fn schema_enum_and_const_compare_numbers_by_value() {
+    let schema = Schema::new(&json!({
+        "type": "object",
+        "properties": {
+            "enum": { "enum": [1, [2], { "a": 3 }] },
+            "const": { "const": { "a": [1, 2.5] } },
+            "unique": { "uniqueItems": true }
+        }
+    }))
+    .unwrap();
+
+    assert!(schema
+        .validate(&json!({ "enum": 1.0, "const": { "a": [1.0, 2.5] }, "unique": [1, 2] }))
+        .is_ok());
+    assert!(schema.validate(&json!({ "enum": [2.0] })).is_ok());
+    assert!(schema.validate(&json!({ "enum": { "a": 3.0 } })).is_ok());
+    assert!(schema.validate(&json!({ "enum": 1.5 })).is_err());
+    assert!(schema
+        .validate(&json!({ "const": { "a": [1, 2] } }))
+        .is_err());
+    assert!(schema.validate(&json!({ "unique": [1, 1.0] })).is_err());
+}
+
+#[test]
+fn schema_same_errors_as_derive() {
+    #[derive(Serialize, Validate, JsonSchema)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        #[validate(max_items = 2)]
+        val: Vec<i32>,
+        #[validate(min_length = 4)]
+        name: String,
+    }
+
+    let s = TestStruct {
+        val: vec![1, 11, 12],
+        name: "abc".to_owned(),
+    };
+    let schema = Schema::new(&TestStruct::json_schema()).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(
+            &schema
+                .validate(&serde_json::to_value(&s).unwrap())
+                .unwrap_err()
+        )
+        .unwrap(),
+        serde_json::to_value(&s.validate().unwrap_err()).unwrap()
+    );
+}
+
+#[test]
+fn schema_invalid_pattern() {
+    assert!(matches!(
+        Schema::new(&json!({ "pattern": "(" })),
+        Err(SchemaError::InvalidPattern { .. })
+    ));
+}
+
+#[test]
+fn schema_unsupported_keyword() {
+    assert_eq!(
+        Schema::new(&json!({ "items": { "$ref": "#/$defs/item" } }))
+            .unwrap_err()
+            .to_string(),
+        "the keyword of \"#/items/$ref\" is not supported."
+    );
+}
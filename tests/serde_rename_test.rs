@@ -81,6 +81,34 @@ fn serde_rename_deserialize_is_err() {
     );
 }
 
+#[test]
+fn serde_default_is_not_rename() {
+    fn default_val() -> i32 {
+        123
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        #[serde(default = "default_val")]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({})).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
 #[test]
 fn serde_rename_enume_is_ok() {
     #[derive(Debug, Validate, Deserialize)]